@@ -29,6 +29,9 @@
 //! }
 //! ```
 
+use crate::render;
+use crate::utils::{normalize_whitespace, split_sentences, text_direction};
+use scraper::{node::Node, ElementRef, Html};
 use serde::{Deserialize, Serialize};
 
 /// Represents a successfully parsed article with extracted content and metadata.
@@ -68,6 +71,21 @@ pub struct Article {
     /// fallback, the first `<h1>` tag in the document is used.
     pub title: Option<String>,
 
+    /// The title derived from metadata and the `<title>` tag.
+    ///
+    /// This usually matches `title`, but keeps the metadata value available when
+    /// [`ReadabilityOptions::prefer_heading_title`](crate::ReadabilityOptions::prefer_heading_title)
+    /// replaced the title with the on-page headline.
+    #[serde(default)]
+    pub meta_title: Option<String>,
+
+    /// Text of the page's `<title>` tag, before any cleaning.
+    ///
+    /// Keeps the site name and separators that were stripped from `title`, which helps
+    /// when debugging the title heuristics or building a title externally.
+    #[serde(default)]
+    pub raw_title: Option<String>,
+
     /// Cleaned HTML content of the article.
     ///
     /// This contains the main article content with:
@@ -106,6 +124,13 @@ pub struct Article {
     /// separated by commas.
     pub byline: Option<String>,
 
+    /// Photo of the author, as an absolute URL when a base URL was given
+    ///
+    /// Taken from an image inside the page element the byline was read from, or the
+    /// author box around it; bylines from metadata or the title have none. Icons and
+    /// sprites are skipped.
+    pub author_image: Option<String>,
+
     /// The image is extracted from various sources, checking JSON-LD structured data first
     /// (supporting simple URLs, ImageObject with `url` property, and arrays of images).
     /// If not found, Open Graph meta tags are checked in priority order: `og:image:secure_url`,
@@ -115,6 +140,12 @@ pub struct Article {
     /// The first valid URL encountered in this priority order is used.
     pub image: Option<String>,
 
+    /// Alt text for [`image`](Self::image).
+    ///
+    /// Read from `og:image:alt`, falling back to the `caption` of a JSON-LD ImageObject.
+    /// Only set when an image was found.
+    pub image_alt: Option<String>,
+
     /// Text direction hint: "ltr" (left-to-right), "rtl" (right-to-left), or "auto".
     ///
     /// Extracted from the `dir` attribute on the `<html>` element.
@@ -129,16 +160,110 @@ pub struct Article {
     /// Language code of the content (e.g., "en", "es", "fr").
     ///
     /// Extracted from the `lang` attribute on the `<html>` element or
-    /// `Content-Language` meta tag.
+    /// `Content-Language` meta tag, falling back to the language part of `og:locale`.
     pub lang: Option<String>,
 
     /// Publication or modification timestamp.
     ///
-    /// The publication time is extracted from the JSON-LD `datePublished` field or the
-    /// `article:published_time` meta tag. The format varies depending on the source but
-    /// is typically ISO 8601.
+    /// The publication time is extracted from the JSON-LD `datePublished` field, then
+    /// `itemprop="datePublished"` microdata, then the `article:published_time` meta tag.
+    /// The format varies depending on the source but is typically ISO 8601.
     pub published_time: Option<String>,
 
+    /// Whether `published_time` was inferred from the URL rather than read from metadata.
+    ///
+    /// When no date metadata is present and a base URL was provided, a date encoded in
+    /// the URL path (for example `/2015/05/12/slug`) is used as a last resort. Such dates
+    /// are date-only and should be treated as low confidence.
+    #[serde(default)]
+    pub published_time_inferred: bool,
+
+    /// Heuristic confidence in the extraction, from `0.0` to `1.0`.
+    ///
+    /// Higher when the chosen content clearly outscored competing candidates, has few
+    /// links, comes with a byline and date, and was found on the strict first attempt.
+    /// Content taken from a content selector or a JSON-LD body is not scored, and its
+    /// score margin counts as neutral.
+    #[serde(default)]
+    pub confidence: f32,
+
+    /// Pull quotes found in the content, in document order.
+    ///
+    /// Pull quotes repeat a sentence from the body for emphasis. They are removed from
+    /// `content` and `text_content` so the text isn't duplicated, and listed here.
+    #[serde(default)]
+    pub pull_quotes: Vec<String>,
+
+    /// HTML of the article's own summary box, such as "Key points" or "TL;DR".
+    ///
+    /// Detected by a summary, key points or TL;DR class or id, or by a heading with
+    /// such a title followed by a list. Taken from the cleaned content, so the box also
+    /// stays in `content`; when present it is usually a better summary than `excerpt`.
+    pub summary_block: Option<String>,
+
+    /// Outbound links from the content, in document order and without duplicates.
+    ///
+    /// Only filled when
+    /// [`ReadabilityOptions::collect_links`](crate::ReadabilityOptions::collect_links) is
+    /// set. In-page fragment links and `javascript:` links are skipped.
+    #[serde(default)]
+    pub links: Vec<LinkRef>,
+
+    /// CSS paths of the kept blocks in the source document, in content order.
+    ///
+    /// Only filled when
+    /// [`ReadabilityOptions::collect_source_paths`](crate::ReadabilityOptions::collect_source_paths)
+    /// is set. Each path, such as `html > body:nth-child(2) > article:nth-child(1) > p:nth-child(3)`,
+    /// selects the block on the page the HTML came from.
+    #[serde(default)]
+    pub source_paths: Vec<String>,
+
+    /// Footnotes referenced from the content, numbered in order of first reference.
+    ///
+    /// Only filled when
+    /// [`ReadabilityOptions::markdown_footnotes`](crate::ReadabilityOptions::markdown_footnotes)
+    /// is set. [`to_markdown`](Self::to_markdown) uses them to write `[^n]` footnotes.
+    #[serde(default)]
+    pub footnotes: Vec<Footnote>,
+
+    /// Tags and keywords describing the article, without duplicates.
+    ///
+    /// Collected from `article:tag` meta tags, then the comma-separated `keywords` meta
+    /// tag, then the JSON-LD `keywords` field. Tags are trimmed and compared ignoring case;
+    /// the first spelling seen is kept.
+    #[serde(default)]
+    pub tags: Vec<String>,
+
+    /// Recipe from a JSON-LD `Recipe` block, when the page has one.
+    pub recipe: Option<Recipe>,
+
+    /// Place the article is about, from the JSON-LD `contentLocation`, or else
+    /// `locationCreated`.
+    ///
+    /// Given as a place name, such as `Port Townsend, Washington`. For a `Place` object
+    /// without a name, the parts of its address are joined with commas.
+    pub location: Option<String>,
+
+    /// Human-readable "updated" label as shown on the page, such as `Updated 3:45pm`.
+    ///
+    /// Taken from the first short element whose class or id has the word "updated", or
+    /// whose text starts with "Updated". Unlike `published_time` this is display text,
+    /// not a machine-readable timestamp.
+    pub updated_label: Option<String>,
+
+    /// Accessible name of the extracted content region.
+    ///
+    /// Taken from the `aria-labelledby` (resolved to the referenced elements' text) or
+    /// `aria-label` of the chosen content element or its nearest labelled ancestor.
+    /// Used as the title when the page has no other title.
+    pub region_label: Option<String>,
+
+    /// Content of the page's `<meta name="robots">` tag, such as `noindex, nofollow`.
+    ///
+    /// Lets crawlers skip storing pages that ask not to be indexed. Several robots tags
+    /// are joined with commas.
+    pub robots: Option<String>,
+
     /// Raw HTML content before final post-processing.
     ///
     /// This is the extracted content before the final cleaning steps,
@@ -150,4 +275,1010 @@ impl Article {
     pub fn new() -> Self {
         Self::default()
     }
+
+    /// Compares this article with a newer version of the same page.
+    ///
+    /// Reports which of the title, byline and publication time changed, along with a
+    /// paragraph-level diff of the plain text content. Paragraphs are the non-empty lines
+    /// of `text_content`, matched with a longest-common-subsequence pass and grouped into
+    /// runs of unchanged, removed and added paragraphs.
+    ///
+    /// ```rust
+    /// use readabilityrs::{Article, ParagraphChange};
+    ///
+    /// let old = Article {
+    ///     text_content: Some("First.\nSecond.".to_string()),
+    ///     ..Article::default()
+    /// };
+    /// let new = Article {
+    ///     text_content: Some("First.\nSecond, revised.".to_string()),
+    ///     ..Article::default()
+    /// };
+    ///
+    /// let diff = old.diff(&new);
+    /// assert_eq!(diff.paragraphs[0], ParagraphChange::Unchanged(vec!["First.".to_string()]));
+    /// ```
+    pub fn diff(&self, other: &Article) -> ArticleDiff {
+        let old_paragraphs = split_paragraphs(self.text_content.as_deref());
+        let new_paragraphs = split_paragraphs(other.text_content.as_deref());
+
+        ArticleDiff {
+            title: FieldChange::between(&self.title, &other.title),
+            byline: FieldChange::between(&self.byline, &other.byline),
+            published_time: FieldChange::between(&self.published_time, &other.published_time),
+            paragraphs: diff_paragraphs(&old_paragraphs, &new_paragraphs),
+        }
+    }
+
+    /// Renders the article content as plain text with a blank line between blocks.
+    ///
+    /// Unlike `text_content`, block boundaries are kept, and each `<hr>` becomes a `---`
+    /// line so scene breaks stay visible. Inline `<q>` quotes get the quotation marks of
+    /// the article's `lang`, as browsers add them, and ruby annotations are written as
+    /// `漢字(かんじ)`. Returns an empty string when there is no content.
+    ///
+    /// ```rust
+    /// use readabilityrs::Article;
+    ///
+    /// let article = Article {
+    ///     content: Some("<p>Before.</p><hr><p>After.</p>".to_string()),
+    ///     ..Article::default()
+    /// };
+    /// assert_eq!(article.to_text(), "Before.\n\n---\n\nAfter.");
+    /// ```
+    pub fn to_text(&self) -> String {
+        self.to_text_with_separator(render::DEFAULT_HR_SEPARATOR)
+    }
+
+    /// Renders the article content as plain text, using `separator` for each `<hr>`.
+    ///
+    /// An empty separator marks the break with a pair of blank lines instead.
+    pub fn to_text_with_separator(&self, separator: &str) -> String {
+        self.content
+            .as_deref()
+            .map(|content| render::html_to_text(content, separator, self.lang.as_deref()))
+            .unwrap_or_default()
+    }
+
+    /// Renders the article content as Markdown.
+    ///
+    /// Headings, paragraphs, lists, links, images, emphasis, code and block quotes are
+    /// translated, and `<mark>` highlights become `==text==`; other markup is reduced to
+    /// its text. When [`footnotes`](Self::footnotes) were collected, references become
+    /// `[^n]` and the notes are listed as definitions at the end. Text that Markdown
+    /// would read as markup, raw HTML included, is backslash-escaped, and characters
+    /// that would cut a link or image URL short are percent-encoded. Returns `None`
+    /// when there is no content.
+    ///
+    /// ```rust
+    /// use readabilityrs::Article;
+    ///
+    /// let article = Article {
+    ///     content: Some(r#"<h2>Setup</h2><p>See <a href="https://example.com">the docs</a>.</p>"#.to_string()),
+    ///     ..Article::default()
+    /// };
+    /// assert_eq!(
+    ///     article.to_markdown().unwrap(),
+    ///     "## Setup\n\nSee [the docs](https://example.com)."
+    /// );
+    /// ```
+    pub fn to_markdown(&self) -> Option<String> {
+        self.content.as_deref().map(|content| {
+            render::html_to_markup(
+                content,
+                &render::Markdown,
+                &self.footnotes,
+                self.lang.as_deref(),
+            )
+        })
+    }
+
+    /// Renders the article content as AsciiDoc.
+    ///
+    /// Uses the same traversal as [`to_markdown`](Self::to_markdown). An `<h1>` becomes
+    /// a level-1 section (`==`), so the output can be included under a document title.
+    /// Returns `None` when there is no content.
+    pub fn to_asciidoc(&self) -> Option<String> {
+        self.content.as_deref().map(|content| {
+            render::html_to_markup(content, &render::AsciiDoc, &[], self.lang.as_deref())
+        })
+    }
+
+    /// Renders the article as an EPUB 3 chapter in XHTML.
+    ///
+    /// The output is a complete, well-formed XML document with a single `<body>` whose
+    /// chapter section opens with the title as `<h1>`; a content heading repeating the
+    /// title is removed. Scripts, embeds and presentational attributes are dropped, and
+    /// images point at `images/<file name>` for the caller to package alongside; see
+    /// [`to_epub_chapter_with_images`](Self::to_epub_chapter_with_images) for the list.
+    /// Returns `None` when there is no content.
+    ///
+    /// ```rust
+    /// use readabilityrs::Article;
+    ///
+    /// let article = Article {
+    ///     title: Some("Field notes".to_string()),
+    ///     content: Some(r#"<p class="lead">Rain again.<br><img src="https://example.com/a/rain.jpg?w=800"></p>"#.to_string()),
+    ///     ..Article::default()
+    /// };
+    /// let chapter = article.to_epub_chapter().unwrap();
+    /// assert!(chapter.contains("<h1>Field notes</h1>"));
+    /// assert!(chapter.contains(r#"<p>Rain again.<br /><img src="images/rain.jpg" /></p>"#));
+    /// ```
+    pub fn to_epub_chapter(&self) -> Option<String> {
+        self.to_epub_chapter_with_images()
+            .map(|(chapter, _)| chapter)
+    }
+
+    /// Renders the article as an EPUB 3 chapter, also listing the images to package.
+    ///
+    /// Same chapter as [`to_epub_chapter`](Self::to_epub_chapter). Each listed pair holds
+    /// an image source from the content and the path the chapter uses for it. Sources
+    /// whose file names clash get a numbered suffix, such as `images/photo-2.jpg`, so
+    /// every path is unique; `data:` URIs stay inline and are not listed.
+    ///
+    /// ```rust
+    /// use readabilityrs::Article;
+    ///
+    /// let article = Article {
+    ///     content: Some(r#"<p><img src="https://a.example.com/photo.jpg"><img src="https://b.example.com/photo.jpg"></p>"#.to_string()),
+    ///     ..Article::default()
+    /// };
+    /// let (chapter, images) = article.to_epub_chapter_with_images().unwrap();
+    /// assert_eq!(images[1].1, "images/photo-2.jpg");
+    /// assert!(chapter.contains(r#"<img src="images/photo-2.jpg" />"#));
+    /// ```
+    pub fn to_epub_chapter_with_images(&self) -> Option<(String, Vec<(String, String)>)> {
+        let content = self.content.as_deref()?;
+        let content = match self.title.as_deref() {
+            Some(title) => crate::cleaner::remove_title_heading(content, title),
+            None => content.to_string(),
+        };
+        Some(render::html_to_epub_chapter(
+            &content,
+            self.title.as_deref(),
+            self.lang.as_deref(),
+            self.dir.as_deref(),
+        ))
+    }
+
+    /// Builds a schema.org `Article` JSON-LD object from the extracted metadata.
+    ///
+    /// The inverse of JSON-LD extraction, for feeding the cleaned article into systems
+    /// that read structured data. `headline`, `author` (a `Person`), `datePublished`,
+    /// `image`, `publisher` (an `Organization` named after `site_name`) and `inLanguage`
+    /// are filled from the matching fields; missing fields are left out. The result is
+    /// the JSON text, ready for a `<script type="application/ld+json">` tag: `<`, `>` and
+    /// `&` are written as `\u003c`, `\u003e` and `\u0026`, so page text such as
+    /// `</script>` cannot close the tag. A leading "By" is dropped from the author name.
+    ///
+    /// ```rust
+    /// use readabilityrs::Article;
+    ///
+    /// let article = Article {
+    ///     title: Some("Council approves transit plan".to_string()),
+    ///     byline: Some("Jane Doe".to_string()),
+    ///     ..Article::default()
+    /// };
+    /// let json: serde_json::Value = serde_json::from_str(&article.to_json_ld()).unwrap();
+    /// assert_eq!(json["headline"], "Council approves transit plan");
+    /// assert_eq!(json["author"]["name"], "Jane Doe");
+    /// ```
+    pub fn to_json_ld(&self) -> String {
+        use serde_json::{json, Value};
+
+        let mut object = serde_json::Map::new();
+        object.insert("@context".into(), json!("https://schema.org"));
+        object.insert("@type".into(), json!("Article"));
+
+        let mut insert = |key: &str, value: Option<Value>| {
+            if let Some(value) = value {
+                object.insert(key.into(), value);
+            }
+        };
+        let present = |value: &Option<String>| {
+            value
+                .as_deref()
+                .map(str::trim)
+                .filter(|value| !value.is_empty())
+                .map(str::to_string)
+        };
+
+        insert("headline", present(&self.title).map(Value::String));
+        insert(
+            "author",
+            present(&self.byline)
+                .map(|byline| crate::utils::strip_by_prefix(&byline).to_string())
+                .filter(|name| !name.is_empty())
+                .map(|name| json!({ "@type": "Person", "name": name })),
+        );
+        insert(
+            "datePublished",
+            present(&self.published_time).map(Value::String),
+        );
+        insert("image", present(&self.image).map(Value::String));
+        insert(
+            "publisher",
+            present(&self.site_name).map(|name| json!({ "@type": "Organization", "name": name })),
+        );
+        insert("inLanguage", present(&self.lang).map(Value::String));
+
+        Value::Object(object)
+            .to_string()
+            .replace('<', "\\u003c")
+            .replace('>', "\\u003e")
+            .replace('&', "\\u0026")
+    }
+
+    /// Returns the first `sentences` complete sentences of the text content.
+    ///
+    /// Unlike `excerpt`, which comes from metadata or the first paragraph, the lead is
+    /// cut at sentence boundaries, which suits summary cards better than a character
+    /// limit. Returns `None` when `sentences` is zero or there is no text content; when
+    /// the text has fewer sentences, all of them are returned.
+    ///
+    /// ```rust
+    /// use readabilityrs::Article;
+    ///
+    /// let article = Article {
+    ///     text_content: Some("First point. Second point!\nThird point?".to_string()),
+    ///     ..Article::default()
+    /// };
+    /// assert_eq!(article.lead(2).as_deref(), Some("First point. Second point!"));
+    /// ```
+    pub fn lead(&self, sentences: usize) -> Option<String> {
+        if sentences == 0 {
+            return None;
+        }
+
+        let text = self.text_content.as_deref()?;
+        let lead = split_sentences(text)
+            .into_iter()
+            .take(sentences)
+            .collect::<Vec<_>>()
+            .join(" ");
+        if lead.is_empty() {
+            None
+        } else {
+            Some(lead)
+        }
+    }
+
+    /// Splits the article content into sections at its top-level headings.
+    ///
+    /// The top level is the highest heading level (`<h1>` before `<h2>`, and so on) found
+    /// among the content blocks, after descending through single wrapper elements. Each
+    /// section holds one such heading and the blocks up to the next heading of the same
+    /// or a higher level; lower-level headings stay inside their section. Content before
+    /// the first heading becomes an untitled intro section.
+    ///
+    /// Returns an empty list when the article has no content.
+    pub fn sections(&self) -> Vec<Section> {
+        let Some(content) = self.content.as_deref() else {
+            return Vec::new();
+        };
+
+        let fragment = Html::parse_fragment(content);
+        let mut container = fragment.root_element();
+        while let Some(only_child) = single_wrapper_child(container) {
+            container = only_child;
+        }
+
+        let top_level = container
+            .children()
+            .filter_map(ElementRef::wrap)
+            .filter_map(heading_level)
+            .min();
+
+        let mut sections = Vec::new();
+        let mut current = Section::default();
+        for child in container.children() {
+            if let Some(element) = ElementRef::wrap(child) {
+                let level = heading_level(element);
+                if level.is_some() && level == top_level {
+                    if !current.is_empty() {
+                        sections.push(current);
+                    }
+                    current = Section {
+                        heading: Some(
+                            normalize_whitespace(&element.text().collect::<String>())
+                                .trim()
+                                .to_string(),
+                        ),
+                        level,
+                        content: String::new(),
+                    };
+                    continue;
+                }
+                current.content.push_str(&element.html());
+            } else if let Node::Text(text) = child.value() {
+                current.content.push_str(&escape_text(&text.text));
+            }
+        }
+        if !current.is_empty() {
+            sections.push(current);
+        }
+
+        for section in &mut sections {
+            section.content = section.content.trim().to_string();
+        }
+        sections
+    }
+
+    /// Compares the article's metadata with an expected result.
+    ///
+    /// Title, byline, excerpt and site name are compared after collapsing runs of
+    /// whitespace, the same check the Mozilla test-suite harness uses. Returns one
+    /// [`FieldMismatch`] per differing field, so an empty list means the article matches.
+    pub fn matches_expected(&self, expected: &ExpectedMetadata) -> Vec<FieldMismatch> {
+        [
+            ("title", &self.title, &expected.title),
+            ("byline", &self.byline, &expected.byline),
+            ("excerpt", &self.excerpt, &expected.excerpt),
+            ("site_name", &self.site_name, &expected.site_name),
+        ]
+        .into_iter()
+        .filter(|(_, actual, expected)| !whitespace_insensitive_eq(actual, expected))
+        .map(|(field, actual, expected)| FieldMismatch {
+            field: field.to_string(),
+            expected: expected.clone(),
+            actual: actual.clone(),
+        })
+        .collect()
+    }
+
+    /// Byte lengths of the content, text content and excerpt.
+    ///
+    /// Missing fields count as zero bytes.
+    pub fn byte_sizes(&self) -> ArticleSizes {
+        let len = |field: &Option<String>| field.as_ref().map_or(0, String::len);
+        ArticleSizes {
+            content: len(&self.content),
+            text_content: len(&self.text_content),
+            excerpt: len(&self.excerpt),
+        }
+    }
+
+    /// Lists the article's headings as a table of contents, in document order.
+    ///
+    /// Each entry carries its own text direction so mixed-script documents can render
+    /// Arabic or Hebrew headings right-to-left next to left-to-right ones. Headings with
+    /// no text are skipped.
+    pub fn toc(&self) -> Vec<TocEntry> {
+        let Some(content) = self.content.as_deref() else {
+            return Vec::new();
+        };
+
+        let fragment = Html::parse_fragment(content);
+        fragment
+            .root_element()
+            .descendants()
+            .filter_map(ElementRef::wrap)
+            .filter_map(|element| {
+                let level = heading_level(element)?;
+                let text = normalize_whitespace(&element.text().collect::<String>())
+                    .trim()
+                    .to_string();
+                if text.is_empty() {
+                    return None;
+                }
+                Some(TocEntry {
+                    level,
+                    id: element.value().attr("id").map(str::to_string),
+                    dir: text_direction(&text).to_string(),
+                    text,
+                })
+            })
+            .collect()
+    }
+}
+
+/// Sizes in bytes of an article's main output fields.
+///
+/// Produced by [`Article::byte_sizes`].
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, Default)]
+pub struct ArticleSizes {
+    /// Byte length of [`Article::content`].
+    pub content: usize,
+
+    /// Byte length of [`Article::text_content`].
+    pub text_content: usize,
+
+    /// Byte length of [`Article::excerpt`].
+    pub excerpt: usize,
+}
+
+impl ArticleSizes {
+    /// Combined size of all three fields.
+    pub fn total(&self) -> usize {
+        self.content + self.text_content + self.excerpt
+    }
+}
+
+/// Structured difference between two versions of an [`Article`].
+///
+/// Produced by [`Article::diff`].
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Default)]
+pub struct ArticleDiff {
+    /// Title change, if the title differs.
+    pub title: Option<FieldChange>,
+
+    /// Byline change, if the byline differs.
+    pub byline: Option<FieldChange>,
+
+    /// Publication time change, if the timestamp differs.
+    pub published_time: Option<FieldChange>,
+
+    /// Runs of unchanged, removed and added paragraphs, in reading order.
+    pub paragraphs: Vec<ParagraphChange>,
+}
+
+impl ArticleDiff {
+    /// Returns `true` when neither the tracked fields nor the paragraphs changed.
+    pub fn is_empty(&self) -> bool {
+        self.title.is_none()
+            && self.byline.is_none()
+            && self.published_time.is_none()
+            && self
+                .paragraphs
+                .iter()
+                .all(|change| matches!(change, ParagraphChange::Unchanged(_)))
+    }
+}
+
+/// Old and new values of a changed metadata field.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct FieldChange {
+    /// Value in the original article.
+    pub old: Option<String>,
+
+    /// Value in the compared article.
+    pub new: Option<String>,
+}
+
+impl FieldChange {
+    fn between(old: &Option<String>, new: &Option<String>) -> Option<Self> {
+        if old == new {
+            return None;
+        }
+        Some(Self {
+            old: old.clone(),
+            new: new.clone(),
+        })
+    }
+}
+
+/// Expected metadata for a test page, in the format of Mozilla's
+/// `expected-metadata.json` fixtures.
+///
+/// Checked with [`Article::matches_expected`].
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct ExpectedMetadata {
+    /// Expected article title.
+    pub title: Option<String>,
+
+    /// Expected byline.
+    pub byline: Option<String>,
+
+    /// Expected text direction.
+    pub dir: Option<String>,
+
+    /// Expected language.
+    pub lang: Option<String>,
+
+    /// Expected excerpt.
+    pub excerpt: Option<String>,
+
+    /// Expected site name.
+    pub site_name: Option<String>,
+
+    /// Expected publication time.
+    pub published_time: Option<String>,
+
+    /// Whether the page is expected to be readerable.
+    #[serde(default)]
+    pub readerable: bool,
+}
+
+/// A metadata field that differs from its expected value.
+///
+/// Produced by [`Article::matches_expected`].
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct FieldMismatch {
+    /// Name of the field, such as `"title"` or `"site_name"`.
+    pub field: String,
+
+    /// Value from the expectation.
+    pub expected: Option<String>,
+
+    /// Value extracted from the article.
+    pub actual: Option<String>,
+}
+
+fn whitespace_insensitive_eq(actual: &Option<String>, expected: &Option<String>) -> bool {
+    match (actual, expected) {
+        (None, None) => true,
+        (Some(actual), Some(expected)) => actual.split_whitespace().eq(expected.split_whitespace()),
+        _ => false,
+    }
+}
+
+/// A run of consecutive paragraphs sharing the same diff status.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub enum ParagraphChange {
+    /// Paragraphs present in both versions.
+    Unchanged(Vec<String>),
+
+    /// Paragraphs only present in the compared article.
+    Added(Vec<String>),
+
+    /// Paragraphs only present in the original article.
+    Removed(Vec<String>),
+}
+
+fn split_paragraphs(text: Option<&str>) -> Vec<&str> {
+    text.map(|text| {
+        text.lines()
+            .map(str::trim)
+            .filter(|line| !line.is_empty())
+            .collect()
+    })
+    .unwrap_or_default()
+}
+
+fn diff_paragraphs(old: &[&str], new: &[&str]) -> Vec<ParagraphChange> {
+    // lcs[i][j] holds the LCS length of old[i..] and new[j..]
+    let mut lcs = vec![vec![0usize; new.len() + 1]; old.len() + 1];
+    for i in (0..old.len()).rev() {
+        for j in (0..new.len()).rev() {
+            lcs[i][j] = if old[i] == new[j] {
+                lcs[i + 1][j + 1] + 1
+            } else {
+                lcs[i + 1][j].max(lcs[i][j + 1])
+            };
+        }
+    }
+
+    let mut changes = Vec::new();
+    let (mut i, mut j) = (0, 0);
+    while i < old.len() || j < new.len() {
+        if i < old.len() && j < new.len() && old[i] == new[j] {
+            push_change(
+                &mut changes,
+                ParagraphChange::Unchanged(vec![old[i].to_string()]),
+            );
+            i += 1;
+            j += 1;
+        } else if i < old.len() && (j == new.len() || lcs[i + 1][j] >= lcs[i][j + 1]) {
+            push_change(
+                &mut changes,
+                ParagraphChange::Removed(vec![old[i].to_string()]),
+            );
+            i += 1;
+        } else {
+            push_change(
+                &mut changes,
+                ParagraphChange::Added(vec![new[j].to_string()]),
+            );
+            j += 1;
+        }
+    }
+    changes
+}
+
+fn push_change(changes: &mut Vec<ParagraphChange>, change: ParagraphChange) {
+    match (changes.last_mut(), change) {
+        (Some(ParagraphChange::Unchanged(run)), ParagraphChange::Unchanged(next))
+        | (Some(ParagraphChange::Added(run)), ParagraphChange::Added(next))
+        | (Some(ParagraphChange::Removed(run)), ParagraphChange::Removed(next)) => {
+            run.extend(next);
+        }
+        (_, change) => changes.push(change),
+    }
+}
+
+/// An outbound link found in the article content.
+///
+/// Collected into [`Article::links`] when
+/// [`ReadabilityOptions::collect_links`](crate::ReadabilityOptions::collect_links) is set.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Default)]
+pub struct LinkRef {
+    /// Link target, resolved against the base URL when one was given.
+    pub href: String,
+
+    /// Whitespace-normalized anchor text.
+    pub text: String,
+}
+
+/// A footnote referenced from the article content.
+///
+/// Collected into [`Article::footnotes`] when
+/// [`ReadabilityOptions::markdown_footnotes`](crate::ReadabilityOptions::markdown_footnotes)
+/// is set.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Default)]
+pub struct Footnote {
+    /// Id of the note that references link to, without the `#`.
+    pub id: String,
+
+    /// Footnote number, counting from 1 in order of first reference.
+    pub number: usize,
+
+    /// Whitespace-normalized note text, without back-reference links.
+    pub text: String,
+}
+
+/// Recipe details from a JSON-LD `Recipe` block.
+///
+/// Recipe pages often surround the recipe with ads and stories, so the structured data
+/// is the most reliable copy. Stored in [`Article::recipe`].
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Default)]
+pub struct Recipe {
+    /// Recipe name.
+    pub name: Option<String>,
+
+    /// Ingredients, one per entry, from `recipeIngredient`.
+    pub ingredients: Vec<String>,
+
+    /// Instruction steps in order. `HowToStep` and `HowToSection` entries are flattened
+    /// to their text.
+    pub instructions: Vec<String>,
+
+    /// Preparation time as an ISO 8601 duration, such as `PT15M`.
+    pub prep_time: Option<String>,
+
+    /// Cooking time as an ISO 8601 duration.
+    pub cook_time: Option<String>,
+
+    /// Total time as an ISO 8601 duration.
+    pub total_time: Option<String>,
+}
+
+/// A slice of article content introduced by a top-level heading.
+///
+/// Produced by [`Article::sections`].
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Default)]
+pub struct Section {
+    /// Text of the heading that opens this section, or `None` for the intro section.
+    pub heading: Option<String>,
+
+    /// Heading level (1 for `<h1>` through 6 for `<h6>`), or `None` for the intro section.
+    pub level: Option<u8>,
+
+    /// HTML of the blocks following the heading, up to the next top-level heading.
+    pub content: String,
+}
+
+/// One heading in an article's table of contents.
+///
+/// Produced by [`Article::toc`].
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Default)]
+pub struct TocEntry {
+    /// Heading level (1 for `<h1>` through 6 for `<h6>`).
+    pub level: u8,
+
+    /// Whitespace-normalized heading text.
+    pub text: String,
+
+    /// The heading's `id` attribute, usable as a link target.
+    pub id: Option<String>,
+
+    /// Text direction of the heading: "rtl" when its first letter is Arabic or Hebrew,
+    /// "ltr" for other letters, or "auto" when it has none.
+    pub dir: String,
+}
+
+impl Section {
+    fn is_empty(&self) -> bool {
+        self.heading.is_none() && self.content.trim().is_empty()
+    }
+}
+
+fn heading_level(element: ElementRef) -> Option<u8> {
+    match element.value().name() {
+        "h1" => Some(1),
+        "h2" => Some(2),
+        "h3" => Some(3),
+        "h4" => Some(4),
+        "h5" => Some(5),
+        "h6" => Some(6),
+        _ => None,
+    }
+}
+
+/// Returns the only child element when `element` merely wraps it.
+fn single_wrapper_child(element: ElementRef) -> Option<ElementRef> {
+    let mut only_child = None;
+    for child in element.children() {
+        match child.value() {
+            Node::Element(_) => {
+                if only_child.is_some() {
+                    return None;
+                }
+                only_child = ElementRef::wrap(child);
+            }
+            Node::Text(text) if !text.trim().is_empty() => return None,
+            _ => {}
+        }
+    }
+    only_child.filter(|child| heading_level(*child).is_none())
+}
+
+fn escape_text(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn article(title: &str, text: &str) -> Article {
+        Article {
+            title: Some(title.to_string()),
+            text_content: Some(text.to_string()),
+            ..Article::default()
+        }
+    }
+
+    #[test]
+    fn test_diff_minor_edit() {
+        let old = article(
+            "Council approves budget",
+            "The council met on Tuesday.\nThe budget passed 7-2.\nResidents reacted.",
+        );
+        let new = article(
+            "Council approves budget",
+            "The council met on Tuesday.\nThe budget passed 6-3.\nResidents reacted.",
+        );
+
+        let diff = old.diff(&new);
+        assert!(diff.title.is_none());
+        assert!(diff.byline.is_none());
+        assert_eq!(
+            diff.paragraphs,
+            vec![
+                ParagraphChange::Unchanged(vec!["The council met on Tuesday.".to_string()]),
+                ParagraphChange::Removed(vec!["The budget passed 7-2.".to_string()]),
+                ParagraphChange::Added(vec!["The budget passed 6-3.".to_string()]),
+                ParagraphChange::Unchanged(vec!["Residents reacted.".to_string()]),
+            ]
+        );
+        assert!(!diff.is_empty());
+    }
+
+    #[test]
+    fn test_diff_field_changes() {
+        let old = article("Draft headline", "Body.");
+        let mut new = article("Final headline", "Body.");
+        new.byline = Some("Jane Doe".to_string());
+
+        let diff = old.diff(&new);
+        assert_eq!(
+            diff.title,
+            Some(FieldChange {
+                old: Some("Draft headline".to_string()),
+                new: Some("Final headline".to_string()),
+            })
+        );
+        assert_eq!(diff.byline.unwrap().new.as_deref(), Some("Jane Doe"));
+        assert!(old.diff(&old).is_empty());
+    }
+
+    #[test]
+    fn test_epub_chapter_is_well_formed_xml() {
+        let article = Article {
+            title: Some("Notes & Queries".to_string()),
+            lang: Some("en".to_string()),
+            content: Some(
+                r#"<article><h1>Notes &amp; Queries</h1>
+                <p style="color: red" data-pos="1">Fish &amp; chips&nbsp;&mdash; <b>cheap</b><br>and <a href="https://example.com/?a=1&amp;b=2" onclick="track()">good</a>.</p>
+                <figure><picture><source srcset="/img/fish.webp"><img src="/img/fish.jpg?w=640" alt="A plate of &quot;fish&quot;"></picture>
+                <figcaption>Lunch</figcaption></figure>
+                <script>alert("<p>")</script>
+                <table><tr><td>1 < 2</td></tr></table></article>"#
+                    .to_string(),
+            ),
+            ..Article::default()
+        };
+
+        let chapter = article.to_epub_chapter().unwrap();
+        let options = roxmltree::ParsingOptions {
+            allow_dtd: true,
+            ..Default::default()
+        };
+        let document = roxmltree::Document::parse_with_options(&chapter, options)
+            .unwrap_or_else(|e| panic!("{e}\n{chapter}"));
+
+        let bodies = document
+            .descendants()
+            .filter(|node| node.tag_name().name() == "body")
+            .count();
+        assert_eq!(bodies, 1);
+        let headings: Vec<_> = document
+            .descendants()
+            .filter(|node| node.tag_name().name() == "h1")
+            .map(|node| node.text().unwrap_or_default())
+            .collect();
+        assert_eq!(headings, vec!["Notes & Queries"]);
+        assert!(
+            chapter.contains(r#"<img alt="A plate of &quot;fish&quot;" src="images/fish.jpg" />"#)
+        );
+        assert!(!chapter.contains("<script"));
+        assert!(!chapter.contains("style="));
+        assert!(!chapter.contains("onclick"));
+        assert_eq!(Article::default().to_epub_chapter(), None);
+    }
+
+    #[test]
+    fn test_lead_returns_requested_sentences() {
+        let article = Article {
+            text_content: Some(
+                "\n  The council approved the budget on Tuesday. Dr. Lee called it fair.\n\nCritics disagreed. The vote was 7-2."
+                    .to_string(),
+            ),
+            ..Article::default()
+        };
+
+        assert_eq!(
+            article.lead(2).as_deref(),
+            Some("The council approved the budget on Tuesday. Dr. Lee called it fair.")
+        );
+        assert_eq!(article.lead(0), None);
+        assert_eq!(Article::default().lead(2), None);
+    }
+
+    #[test]
+    fn test_sections_split_at_h2() {
+        let article = Article {
+            content: Some(
+                r#"<div id="readability-page-1"><article>
+                <p>Intro paragraph.</p>
+                <h2>First</h2><p>One.</p><h3>Detail</h3><p>One more.</p>
+                <h2>Second</h2><p>Two.</p>
+                <h2>Third</h2><ul><li>Three.</li></ul>
+                </article></div>"#
+                    .to_string(),
+            ),
+            ..Article::default()
+        };
+
+        let sections = article.sections();
+        assert_eq!(sections.len(), 4);
+
+        assert_eq!(sections[0].heading, None);
+        assert_eq!(sections[0].content, "<p>Intro paragraph.</p>");
+
+        assert_eq!(sections[1].heading.as_deref(), Some("First"));
+        assert_eq!(sections[1].level, Some(2));
+        assert_eq!(
+            sections[1].content,
+            "<p>One.</p><h3>Detail</h3><p>One more.</p>"
+        );
+
+        assert_eq!(sections[2].heading.as_deref(), Some("Second"));
+        assert_eq!(sections[2].content, "<p>Two.</p>");
+        assert_eq!(sections[3].heading.as_deref(), Some("Third"));
+        assert_eq!(sections[3].content, "<ul><li>Three.</li></ul>");
+    }
+
+    #[test]
+    fn test_toc_entry_direction() {
+        let article = Article {
+            content: Some(
+                r#"<div><h2 id="intro">مقدمة</h2><p>نص.</p>
+                <h3>1. Background</h3><p>Text.</p></div>"#
+                    .to_string(),
+            ),
+            ..Article::default()
+        };
+
+        let toc = article.toc();
+        assert_eq!(toc.len(), 2);
+        assert_eq!(toc[0].text, "مقدمة");
+        assert_eq!(toc[0].id.as_deref(), Some("intro"));
+        assert_eq!(toc[0].dir, "rtl");
+        assert_eq!(toc[1].level, 3);
+        assert_eq!(toc[1].dir, "ltr");
+    }
+
+    #[test]
+    fn test_byte_sizes() {
+        let article = Article {
+            content: Some("<p>Café</p>".to_string()),
+            text_content: Some("Café".to_string()),
+            excerpt: None,
+            ..Article::default()
+        };
+
+        let sizes = article.byte_sizes();
+        assert_eq!(sizes.content, 12);
+        assert_eq!(sizes.text_content, 5);
+        assert_eq!(sizes.excerpt, 0);
+        assert_eq!(sizes.total(), 17);
+    }
+
+    #[test]
+    fn test_to_json_ld() {
+        let article = Article {
+            title: Some("Council approves \"transit\" plan".to_string()),
+            byline: Some("Jane Doe".to_string()),
+            published_time: Some("2024-03-05T10:00:00Z".to_string()),
+            image: Some("https://example.com/lead.jpg".to_string()),
+            site_name: Some("Example News".to_string()),
+            lang: Some("en-GB".to_string()),
+            ..Article::default()
+        };
+
+        let json: serde_json::Value = serde_json::from_str(&article.to_json_ld()).unwrap();
+        assert_eq!(
+            json,
+            serde_json::json!({
+                "@context": "https://schema.org",
+                "@type": "Article",
+                "headline": "Council approves \"transit\" plan",
+                "author": { "@type": "Person", "name": "Jane Doe" },
+                "datePublished": "2024-03-05T10:00:00Z",
+                "image": "https://example.com/lead.jpg",
+                "publisher": { "@type": "Organization", "name": "Example News" },
+                "inLanguage": "en-GB"
+            })
+        );
+
+        // Page text cannot close the script tag, and the "By" credit is not part of the name
+        let article = Article {
+            title: Some("Fish & chips</script><script>alert(1)</script>".to_string()),
+            byline: Some("By Jane Doe".to_string()),
+            ..Article::default()
+        };
+        let json_ld = article.to_json_ld();
+        assert!(!json_ld.contains('<') && !json_ld.contains('>') && !json_ld.contains('&'));
+        let json: serde_json::Value = serde_json::from_str(&json_ld).unwrap();
+        assert_eq!(
+            json["headline"],
+            "Fish & chips</script><script>alert(1)</script>"
+        );
+        assert_eq!(json["author"]["name"], "Jane Doe");
+
+        // Missing and blank fields are left out
+        let article = Article {
+            title: Some("Untitled notes".to_string()),
+            byline: Some("  ".to_string()),
+            ..Article::default()
+        };
+        let json: serde_json::Value = serde_json::from_str(&article.to_json_ld()).unwrap();
+        assert_eq!(json.as_object().unwrap().len(), 3);
+        assert!(json.get("author").is_none());
+    }
+
+    #[test]
+    fn test_matches_expected() {
+        let article = Article {
+            title: Some("Harbour  reopens".to_string()),
+            byline: Some("By Jane Doe".to_string()),
+            excerpt: Some("Ships are back.".to_string()),
+            ..Article::default()
+        };
+        let expected: ExpectedMetadata = serde_json::from_str(
+            r#"{
+                "title": "Harbour reopens",
+                "byline": "By Jane Doe",
+                "excerpt": "Ships are back after the storm.",
+                "siteName": null,
+                "readerable": true
+            }"#,
+        )
+        .unwrap();
+
+        let mismatches = article.matches_expected(&expected);
+        assert_eq!(
+            mismatches,
+            vec![FieldMismatch {
+                field: "excerpt".to_string(),
+                expected: Some("Ships are back after the storm.".to_string()),
+                actual: Some("Ships are back.".to_string()),
+            }]
+        );
+    }
 }
@@ -2,24 +2,31 @@
 
 use crate::constants::{DIV_TO_P_ELEMS, REGEXPS};
 use crate::error::Result;
+use crate::options::{IframeFallback, ReadabilityOptions};
+use crate::stats::{ParseStats, RemovalReason, RemovedBlock};
 use kuchikikiki::{traits::*, NodeData, NodeRef};
 use once_cell::sync::Lazy;
 use regex::{Captures, Regex};
 use scraper::{ElementRef, Html, Selector};
+use std::collections::HashSet;
 
 /// Clean and post-process extracted article content (light version)
 ///
 /// This function:
 /// - Fixes relative URLs to absolute
 /// - Removes nav-like sections
-pub fn clean_article_content_light(html: &str, base_url: Option<&str>) -> Result<String> {
+pub fn clean_article_content_light(
+    html: &str,
+    base_url: Option<&str>,
+    stats: &mut ParseStats,
+) -> Result<String> {
     let mut result = html.to_string();
 
     if let Some(base) = base_url {
-        result = fix_relative_urls_in_html(&result, base);
+        result = fix_relative_urls_in_html(&result, base, stats);
     }
 
-    result = remove_nav_like_sections(&result);
+    result = remove_nav_like_sections(&result, stats);
 
     Ok(result)
 }
@@ -31,25 +38,97 @@ pub fn clean_article_content_light(html: &str, base_url: Option<&str>) -> Result
 /// - Fixes relative URLs to absolute
 /// - Cleans up empty elements
 /// - Normalizes whitespace
-pub fn clean_article_content(html: &str, base_url: Option<&str>) -> Result<String> {
-    let mut result = clean_article_content_light(html, base_url)?;
-    result = remove_conditionally(&result);
+pub fn clean_article_content(
+    html: &str,
+    base_url: Option<&str>,
+    options: &ReadabilityOptions,
+    stats: &mut ParseStats,
+) -> Result<String> {
+    let mut result = clean_article_content_light(html, base_url, stats)?;
+    result = remove_conditionally(&result, options, stats);
+    if !options.preserve_aria {
+        result = strip_aria_attributes(&result);
+    }
     Ok(result)
 }
 
-/// Fix relative URLs in HTML string using regex
-fn fix_relative_urls_in_html(html: &str, _base_url: &str) -> String {
-    // For now, just return as-is
-    // TODO: Implement proper URL fixing without re-parsing the entire tree
-    html.to_string()
+/// Fix relative URLs in the content's attributes
+///
+/// Resolves `href`, `src`, `poster` and `srcset` values against the base URL.
+/// Absolute URLs, in-page fragments and `data:`/`javascript:` values are left alone, as
+/// is text that merely looks like an attribute, such as markup shown in `<pre>`.
+fn fix_relative_urls_in_html(html: &str, base_url: &str, stats: &mut ParseStats) -> String {
+    let Ok(base) = url::Url::parse(base_url) else {
+        return html.to_string();
+    };
+
+    let resolve = |value: &str, count: &mut usize| -> String {
+        let trimmed = value.trim();
+        let lower = trimmed.to_ascii_lowercase();
+        if trimmed.is_empty()
+            || trimmed.starts_with('#')
+            || lower.starts_with("data:")
+            || lower.starts_with("javascript:")
+            || url::Url::parse(trimmed).is_ok()
+        {
+            return value.to_string();
+        }
+        match base.join(trimmed) {
+            Ok(resolved) => {
+                *count += 1;
+                resolved.to_string()
+            }
+            Err(_) => value.to_string(),
+        }
+    };
+
+    let (root, children_only) = parse_content_root(html);
+    let mut count = 0;
+    for node in root.descendants() {
+        let Some(element) = node.as_element() else {
+            continue;
+        };
+        let mut attrs = element.attributes.borrow_mut();
+        for name in ["href", "src", "poster", "srcset"] {
+            let Some(value) = attrs.get(name).map(str::to_string) else {
+                continue;
+            };
+            let before = count;
+            let resolved = if name == "srcset" {
+                crate::utils::parse_srcset(&value)
+                    .into_iter()
+                    .map(|(url, descriptor)| {
+                        let url = resolve(url, &mut count);
+                        if descriptor.is_empty() {
+                            url
+                        } else {
+                            format!("{url} {descriptor}")
+                        }
+                    })
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            } else {
+                resolve(&value, &mut count)
+            };
+            if count > before {
+                attrs.insert(name, resolved);
+            }
+        }
+    }
+
+    if count == 0 {
+        return html.to_string();
+    }
+    stats.links_absolutized += count;
+    serialize_node(&root, children_only)
 }
 
 /// Remove nav-like sections using lightweight regex patterns.
-fn remove_nav_like_sections(html: &str) -> String {
+fn remove_nav_like_sections(html: &str, stats: &mut ParseStats) -> String {
     static NAV_REGEX: Lazy<Regex> =
         Lazy::new(|| Regex::new(r"(?is)<nav\b[^>]*?>.*?</nav>").unwrap());
 
-    let mut result = NAV_REGEX.replace_all(html, "").to_string();
+    let mut result = remove_recorded(html, &NAV_REGEX, "nav", stats);
 
     let tags = ["div", "section", "ul", "ol"];
     let keywords = ["nav", "navbar", "menu", "breadcrumbs", "sidebar", "widget"];
@@ -60,24 +139,1052 @@ fn remove_nav_like_sections(html: &str) -> String {
                 r#"(?is)<{tag}\b[^>]*?class="[^"]*?{keyword}[^"]*?"[^>]*?>.*?</{tag}>"#
             );
             let re = Regex::new(&class_pattern).unwrap();
-            result = re.replace_all(&result, "").to_string();
+            result = remove_recorded(&result, &re, tag, stats);
+
+            let id_pattern = format!(
+                r#"(?is)<{tag}\b[^>]*?id="[^"]*?{keyword}[^"]*?"[^>]*?>.*?</{tag}>"#
+            );
+            let re = Regex::new(&id_pattern).unwrap();
+            result = remove_recorded(&result, &re, tag, stats);
+        }
+    }
+
+    result
+}
+
+/// Remove every match of `re`, recording each as a navigation-like block
+fn remove_recorded(html: &str, re: &Regex, tag: &str, stats: &mut ParseStats) -> String {
+    re.replace_all(html, |caps: &Captures| {
+        stats.record_removed(|| {
+            removed_block_from_html(&caps[0], tag, RemovalReason::NavigationLike)
+        });
+        String::new()
+    })
+    .to_string()
+}
+
+/// Describe a removed block from its serialized HTML
+fn removed_block_from_html(block: &str, tag: &str, reason: RemovalReason) -> RemovedBlock {
+    static CLASS_REGEX: Lazy<Regex> =
+        Lazy::new(|| Regex::new(r#"(?is)^<[^>]*?\sclass\s*=\s*"([^"]*)""#).unwrap());
+    static ID_REGEX: Lazy<Regex> =
+        Lazy::new(|| Regex::new(r#"(?is)^<[^>]*?\sid\s*=\s*"([^"]*)""#).unwrap());
+
+    let attr = |re: &Regex| re.captures(block).map(|caps| caps[1].to_string());
+    RemovedBlock {
+        tag: tag.to_ascii_lowercase(),
+        class: attr(&CLASS_REGEX),
+        id: attr(&ID_REGEX),
+        reason,
+        text_length: Html::parse_fragment(block)
+            .root_element()
+            .text()
+            .collect::<String>()
+            .trim()
+            .len(),
+    }
+}
+
+/// Describe a removed block from its DOM node
+fn removed_block_from_node(node: &NodeRef, reason: RemovalReason) -> RemovedBlock {
+    let (tag, class, id) = match node.as_element() {
+        Some(element) => {
+            let attrs = element.attributes.borrow();
+            (
+                element.name.local.as_ref().to_ascii_lowercase(),
+                attrs.get("class").map(str::to_string),
+                attrs.get("id").map(str::to_string),
+            )
+        }
+        None => (String::new(), None, None),
+    };
+    RemovedBlock {
+        tag,
+        class,
+        id,
+        reason,
+        text_length: node.text_contents().trim().len(),
+    }
+}
+
+/// Remove `<img>` elements with `data:` URI sources larger than `max_bytes`
+///
+/// Small inline images (icons, spacers) are kept. When `keep_alt` is set, a dropped
+/// image is replaced by its alt text instead of being removed entirely.
+pub fn drop_data_uri_images(html: &str, max_bytes: usize, keep_alt: bool) -> String {
+    static IMG_REGEX: Lazy<Regex> = Lazy::new(|| Regex::new(r"(?is)<img\b[^>]*>").unwrap());
+    static SRC_REGEX: Lazy<Regex> =
+        Lazy::new(|| Regex::new(r#"(?is)\ssrc\s*=\s*(?:"(data:[^"]*)"|'(data:[^']*)')"#).unwrap());
+    static ALT_REGEX: Lazy<Regex> =
+        Lazy::new(|| Regex::new(r#"(?is)\salt\s*=\s*(?:"([^"]*)"|'([^']*)')"#).unwrap());
+
+    IMG_REGEX
+        .replace_all(html, |caps: &Captures| {
+            let tag = &caps[0];
+            let src_len = SRC_REGEX
+                .captures(tag)
+                .and_then(|src| src.get(1).or_else(|| src.get(2)))
+                .map(|src| src.as_str().len());
+
+            match src_len {
+                Some(len) if len > max_bytes => {
+                    if keep_alt {
+                        ALT_REGEX
+                            .captures(tag)
+                            .and_then(|alt| alt.get(1).or_else(|| alt.get(2)))
+                            .map(|alt| {
+                                escape_html_text(
+                                    crate::utils::unescape_html_entities(alt.as_str()).trim(),
+                                )
+                            })
+                            .unwrap_or_default()
+                    } else {
+                        String::new()
+                    }
+                }
+                _ => tag.to_string(),
+            }
+        })
+        .to_string()
+}
+
+/// Escape text for use in HTML content or a quoted attribute value
+fn escape_html_text(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
+
+/// Keep only the first `max_images` images of the content
+///
+/// An image whose `src` is `lead_image` is kept regardless and does not count toward
+/// the limit. `<figure>` and `<picture>` elements emptied of images are removed too.
+pub fn limit_images(html: &str, max_images: usize, lead_image: Option<&str>) -> String {
+    let (root, children_only) = parse_content_root(html);
+    let images: Vec<NodeRef> = match root.select("img") {
+        Ok(images) => images.map(|img| img.as_node().clone()).collect(),
+        Err(_) => return html.to_string(),
+    };
+    if images.len() <= max_images {
+        return html.to_string();
+    }
+
+    let mut kept = 0;
+    for image in images {
+        let is_lead = lead_image.is_some_and(|lead| {
+            image.as_element().is_some_and(|element| {
+                element
+                    .attributes
+                    .borrow()
+                    .get("src")
+                    .is_some_and(|src| src.trim() == lead.trim())
+            })
+        });
+        if is_lead {
+            continue;
+        }
+        if kept < max_images {
+            kept += 1;
+            continue;
+        }
+
+        let container = image
+            .ancestors()
+            .take_while(|ancestor| *ancestor != root)
+            .filter(|ancestor| {
+                node_has_tag(ancestor, "figure") || node_has_tag(ancestor, "picture")
+            })
+            .last();
+        image.detach();
+        if let Some(container) = container {
+            if count_descendants(&container, "img") == 0 {
+                container.detach();
+            }
+        }
+    }
+
+    serialize_node(&root, children_only)
+}
+
+/// Remove ruby annotations, keeping only the base text
+///
+/// `<rt>`, `<rtc>` and `<rp>` are removed, and `<ruby>` and `<rb>` are replaced by
+/// their children.
+pub fn strip_ruby_annotations(html: &str) -> String {
+    let (root, children_only) = parse_content_root(html);
+    let annotations: Vec<NodeRef> = match root.select("rt, rtc, rp") {
+        Ok(matches) => matches.map(|node| node.as_node().clone()).collect(),
+        Err(_) => return html.to_string(),
+    };
+    if annotations.is_empty() {
+        return html.to_string();
+    }
+    for annotation in annotations {
+        annotation.detach();
+    }
+
+    let wrappers: Vec<NodeRef> = match root.select("ruby, rb") {
+        Ok(matches) => matches.map(|node| node.as_node().clone()).collect(),
+        Err(_) => Vec::new(),
+    };
+    for wrapper in wrappers {
+        for child in wrapper.children().collect::<Vec<_>>() {
+            wrapper.insert_before(child);
+        }
+        wrapper.detach();
+    }
+
+    serialize_node(&root, children_only)
+}
+
+/// Cap runs of consecutive `<br>` elements at `max_brs`
+///
+/// Breaks separated only by whitespace count as one run; the extra ones are removed.
+/// Breaks inside `<pre>` are kept.
+pub fn collapse_brs(html: &str, max_brs: usize) -> String {
+    let (root, children_only) = parse_content_root(html);
+    let brs: Vec<NodeRef> = match root.select("br") {
+        Ok(brs) => brs.map(|br| br.as_node().clone()).collect(),
+        Err(_) => return html.to_string(),
+    };
+
+    let mut removed = false;
+    for br in brs {
+        if br
+            .ancestors()
+            .any(|ancestor| node_has_tag(&ancestor, "pre"))
+        {
+            continue;
+        }
+        let preceding = br
+            .preceding_siblings()
+            .filter(|sibling| {
+                sibling
+                    .as_text()
+                    .is_none_or(|text| !text.borrow().trim().is_empty())
+            })
+            .take_while(|sibling| node_has_tag(sibling, "br"))
+            .count();
+        if preceding >= max_brs {
+            br.detach();
+            removed = true;
+        }
+    }
+
+    if !removed {
+        return html.to_string();
+    }
+    serialize_node(&root, children_only)
+}
+
+/// Pull pull quotes out of the flowing text of extracted content
+///
+/// A pull quote is an element whose class contains `pullquote` or `pull-quote`, or an
+/// `<aside>` wrapping a `<blockquote>`, and whose text is repeated elsewhere in the
+/// content. Such elements are removed and their text is returned alongside the
+/// remaining HTML, in document order and without duplicates.
+pub fn extract_pull_quotes(html: &str) -> (String, Vec<String>) {
+    // Most content has no candidate at all; skip the parse for it
+    static CANDIDATE_HINT: Lazy<Regex> =
+        Lazy::new(|| Regex::new(r"(?i)pull-?quote|<aside\b").unwrap());
+    if !CANDIDATE_HINT.is_match(html) {
+        return (html.to_string(), Vec::new());
+    }
+
+    let (root, children_only) = parse_content_root(html);
+
+    let candidates: Vec<NodeRef> = root
+        .descendants()
+        .filter(is_pull_quote_candidate)
+        .filter(|node| !has_ancestor(node, is_pull_quote_candidate))
+        .collect();
+    if candidates.is_empty() {
+        return (html.to_string(), Vec::new());
+    }
+
+    let body_text: String = root
+        .descendants()
+        .text_nodes()
+        .filter(|text| {
+            let node = text.as_node();
+            !candidates
+                .iter()
+                .any(|candidate| node.ancestors().any(|ancestor| ancestor == *candidate))
+        })
+        .map(|text| text.borrow().clone())
+        .collect::<Vec<_>>()
+        .join(" ");
+    let body_key = text_match_key(&body_text);
+
+    let mut quotes: Vec<String> = Vec::new();
+    for candidate in candidates {
+        let text = crate::utils::normalize_whitespace(&candidate.text_contents())
+            .trim()
+            .to_string();
+        let key = text_match_key(&text);
+        if key.is_empty() || !body_key.contains(&key) {
+            continue;
+        }
+
+        candidate.detach();
+        if !quotes.contains(&text) {
+            quotes.push(text);
+        }
+    }
+
+    if quotes.is_empty() {
+        return (html.to_string(), quotes);
+    }
+
+    (serialize_node(&root, children_only), quotes)
+}
+
+/// Move floated callouts that interrupt the text to the end of their container
+///
+/// A `<div>`, `<section>` or `<aside>` floated with an inline `float: left|right` style,
+/// or whose class names a float (`float-right`, `pull-left`, ...) or a callout box, sits
+/// beside the text on the page but in the middle of it in the source. When text follows
+/// it in the same container, it is moved after that text so the reading order matches
+/// the visual article.
+pub fn move_floated_blocks(html: &str) -> String {
+    let (root, children_only) = parse_content_root(html);
+
+    let floated: Vec<NodeRef> = root
+        .descendants()
+        .filter(is_floated_block)
+        .filter(|node| !has_ancestor(node, is_floated_block))
+        .filter(|node| {
+            node.following_siblings()
+                .any(|sibling| !sibling.text_contents().trim().is_empty())
+        })
+        .collect();
+    if floated.is_empty() {
+        return html.to_string();
+    }
+
+    for node in floated {
+        if let Some(parent) = node.parent() {
+            node.detach();
+            parent.append(node);
+        }
+    }
+
+    serialize_node(&root, children_only)
+}
+
+fn is_floated_block(node: &NodeRef) -> bool {
+    static FLOAT_STYLE: Lazy<Regex> =
+        Lazy::new(|| Regex::new(r"(?i)(^|;)\s*float\s*:\s*(left|right)").unwrap());
+    static FLOAT_CLASS: Lazy<Regex> = Lazy::new(|| {
+        Regex::new(r"(?i)float-?(left|right|start|end)|pull-?(left|right)|callout|boxout|infobox")
+            .unwrap()
+    });
+
+    let Some(element) = node.as_element() else {
+        return false;
+    };
+    if !["div", "section", "aside"].contains(&element.name.local.as_ref()) {
+        return false;
+    }
+    if node.text_contents().trim().is_empty() {
+        return false;
+    }
+
+    let attrs = element.attributes.borrow();
+    attrs
+        .get("style")
+        .is_some_and(|style| FLOAT_STYLE.is_match(style))
+        || attrs
+            .get("class")
+            .is_some_and(|class| FLOAT_CLASS.is_match(class))
+}
+
+/// Find an explicit summary box, such as "Key points" or "TL;DR", in extracted content
+///
+/// The first element whose class or id names a summary, key points or TL;DR box is
+/// returned as HTML. Failing that, a heading titled "Key points", "Summary", "In brief"
+/// and the like is returned together with the list or paragraph that follows it. A
+/// candidate holding more than half of the content's text is the article itself and
+/// is ignored. The content is not modified.
+pub fn find_summary_block(html: &str) -> Option<String> {
+    // Every class, id or heading that can match names one of these words; without any
+    // of them, skip the parse
+    static CANDIDATE_HINT: Lazy<Regex> = Lazy::new(|| {
+        Regex::new(r"(?i)summary|takeaways|points|facts|tl([-;\s]|&nbsp;)*dr|brief|short|glance")
+            .unwrap()
+    });
+    if !CANDIDATE_HINT.is_match(html) {
+        return None;
+    }
+
+    let (root, _) = parse_content_root(html);
+    let text_length =
+        |node: &NodeRef| crate::utils::normalize_whitespace(node.text_contents().trim()).len();
+    let total_length = text_length(&root);
+    let fits = |node: &NodeRef| {
+        let length = text_length(node);
+        length > 0 && length * 2 <= total_length
+    };
+
+    if let Some(block) = root
+        .descendants()
+        .find(|node| *node != root && is_summary_container(node) && fits(node))
+    {
+        return Some(serialize_node(&block, false));
+    }
+
+    for heading in root.descendants() {
+        if !["h2", "h3", "h4", "h5", "h6"]
+            .iter()
+            .any(|tag| node_has_tag(&heading, tag))
+        {
+            continue;
+        }
+        let title = crate::utils::normalize_whitespace(&heading.text_contents());
+        if !REGEXPS.summary_heading.is_match(title.trim()) {
+            continue;
+        }
+        let Some(body) = heading
+            .following_siblings()
+            .find(|sibling| sibling.as_element().is_some())
+        else {
+            continue;
+        };
+        if ["ul", "ol", "p"].iter().any(|tag| node_has_tag(&body, tag)) && fits(&body) {
+            return Some(format!(
+                "{}{}",
+                serialize_node(&heading, false),
+                serialize_node(&body, false)
+            ));
+        }
+    }
+
+    None
+}
+
+/// Whether an element's class or id marks it as a summary box
+fn is_summary_container(node: &NodeRef) -> bool {
+    let Some(element) = node.as_element() else {
+        return false;
+    };
+    if node_has_tag(node, "summary") {
+        return false;
+    }
+    let attrs = element.attributes.borrow();
+    ["class", "id"]
+        .iter()
+        .filter_map(|name| attrs.get(*name))
+        .any(|value| REGEXPS.summary_block.is_match(value))
+}
+
+/// Folder that [`prepare_epub_content`] points image sources at
+const EPUB_IMAGE_DIR: &str = "images";
+
+/// Prepare article content for an EPUB chapter
+///
+/// Scripts, styles, iframes and similar are dropped, along with presentational
+/// attributes (`class`, `style`, `data-*`, event handlers and `srcset`), and a
+/// `<picture>` falls back to its `<img>`. Images point at `images/<file name>`, with a
+/// numbered suffix when two sources share a file name; `data:` URIs are kept as they
+/// are. Returns the content and each rewritten source with its new path, in order.
+pub(crate) fn prepare_epub_content(html: &str) -> (String, Vec<(String, String)>) {
+    const DROPPED: &[&str] = &[
+        "script", "style", "noscript", "template", "iframe", "object", "embed", "source", "link",
+        "meta",
+    ];
+
+    let (root, children_only) = parse_content_root(html);
+    let dropped: Vec<NodeRef> = root
+        .descendants()
+        .filter(|node| DROPPED.iter().any(|tag| node_has_tag(node, tag)))
+        .collect();
+    for node in dropped {
+        node.detach();
+    }
+    let pictures: Vec<NodeRef> = root
+        .descendants()
+        .filter(|node| node_has_tag(node, "picture"))
+        .collect();
+    for picture in pictures {
+        for child in picture.children().collect::<Vec<_>>() {
+            picture.insert_before(child);
+        }
+        picture.detach();
+    }
+
+    let mut images: Vec<(String, String)> = Vec::new();
+    for node in root.descendants() {
+        let Some(element) = node.as_element() else {
+            continue;
+        };
+        let mut attrs = element.attributes.borrow_mut();
+        attrs.map.retain(|name, _| {
+            let lower = name.local.as_ref().to_ascii_lowercase();
+            !(matches!(lower.as_str(), "class" | "style" | "srcset" | "sizes")
+                || lower.starts_with("data-")
+                || (lower.starts_with("on") && lower != "open"))
+        });
+        if node_has_tag(&node, "img") {
+            if let Some(src) = attrs.get("src").map(str::to_string) {
+                attrs.insert("src", epub_image_path(&src, &mut images));
+            }
+        }
+    }
+
+    (serialize_node(&root, children_only), images)
+}
+
+/// Relative path of an image inside the EPUB package, unique among `images`
+fn epub_image_path(src: &str, images: &mut Vec<(String, String)>) -> String {
+    let src = src.trim();
+    if src.starts_with("data:") {
+        return src.to_string();
+    }
+    if let Some((_, path)) = images.iter().find(|(url, _)| url == src) {
+        return path.clone();
+    }
+
+    let path = src.split(['?', '#']).next().unwrap_or_default();
+    let file_name = path.rsplit('/').next().unwrap_or_default();
+    if file_name.is_empty() {
+        return src.to_string();
+    }
+    let (stem, extension) = match file_name.rsplit_once('.') {
+        Some((stem, extension)) if !stem.is_empty() => (stem, format!(".{extension}")),
+        _ => (file_name, String::new()),
+    };
+
+    let mut candidate = format!("{EPUB_IMAGE_DIR}/{file_name}");
+    let mut suffix = 2;
+    while images.iter().any(|(_, path)| *path == candidate) {
+        candidate = format!("{EPUB_IMAGE_DIR}/{stem}-{suffix}{extension}");
+        suffix += 1;
+    }
+    images.push((src.to_string(), candidate.clone()));
+    candidate
+}
+
+/// Remove the element holding the byline from extracted content
+///
+/// Only an element marked as a byline (a `byline`/`author` class or id, `rel="author"`
+/// or an `author` itemprop) whose text matches `byline`, ignoring a leading "By", is
+/// removed. Content without such an element is returned unchanged.
+pub fn remove_byline_element(html: &str, byline: &str) -> String {
+    let byline_key = byline_match_key(byline);
+    if byline_key.is_empty() {
+        return html.to_string();
+    }
+
+    let (root, children_only) = parse_content_root(html);
+    let byline_node = root.descendants().find(|node| {
+        is_marked_byline_element(node) && byline_match_key(&node.text_contents()) == byline_key
+    });
+
+    match byline_node {
+        Some(node) => {
+            node.detach();
+            serialize_node(&root, children_only)
+        }
+        None => html.to_string(),
+    }
+}
+
+/// Blocks compared across pages by [`repeated_block_keys`] and [`remove_repeated_blocks`]
+const REPEATABLE_BLOCKS: &str = "p, li, h1, h2, h3, h4, h5, h6, blockquote, figcaption";
+
+/// Shortest block text, in characters, treated as possible boilerplate. Shorter blocks,
+/// such as "Share" or a date, repeat across pages without being boilerplate.
+const MIN_REPEATED_BLOCK_CHARS: usize = 25;
+
+/// Whitespace-normalized text of each block in the content, listed once
+///
+/// Blocks shorter than [`MIN_REPEATED_BLOCK_CHARS`] are left out.
+pub fn repeated_block_keys(html: &str) -> HashSet<String> {
+    let (root, _) = parse_content_root(html);
+    let Ok(blocks) = root.select(REPEATABLE_BLOCKS) else {
+        return HashSet::new();
+    };
+
+    blocks
+        .map(|block| block_key(block.as_node()))
+        .filter(|key| key.chars().count() >= MIN_REPEATED_BLOCK_CHARS)
+        .collect()
+}
+
+/// Remove the blocks whose text is one of `keys`, along with wrappers left empty
+pub fn remove_repeated_blocks(html: &str, keys: &HashSet<String>) -> String {
+    if keys.is_empty() {
+        return html.to_string();
+    }
+
+    let (root, children_only) = parse_content_root(html);
+    let Ok(blocks) = root.select(REPEATABLE_BLOCKS) else {
+        return html.to_string();
+    };
+    let repeated: Vec<NodeRef> = blocks
+        .map(|block| block.as_node().clone())
+        .filter(|node| keys.contains(&block_key(node)))
+        .collect();
+    if repeated.is_empty() {
+        return html.to_string();
+    }
+
+    for node in repeated {
+        let mut parent = node.parent();
+        node.detach();
+        while let Some(wrapper) = parent {
+            if wrapper == root || !is_empty_wrapper(&wrapper) {
+                break;
+            }
+            parent = wrapper.parent();
+            wrapper.detach();
+        }
+    }
+
+    serialize_node(&root, children_only)
+}
+
+fn block_key(node: &NodeRef) -> String {
+    crate::utils::normalize_whitespace(node.text_contents().trim())
+}
+
+/// Whether an element has neither text nor embedded media left
+fn is_empty_wrapper(node: &NodeRef) -> bool {
+    node.as_element().is_some()
+        && node.text_contents().trim().is_empty()
+        && [
+            "img", "picture", "video", "audio", "svg", "iframe", "object", "embed",
+        ]
+        .iter()
+        .all(|tag| count_descendants(node, tag) == 0)
+}
+
+/// Remove the first `<h1>` or `<h2>` whose text matches `title`
+///
+/// Text is compared by its lowercased words, ignoring punctuation. Content without a
+/// matching heading is returned unchanged.
+pub fn remove_title_heading(html: &str, title: &str) -> String {
+    let title_key = text_match_key(title);
+    if title_key.is_empty() {
+        return html.to_string();
+    }
+
+    let (root, children_only) = parse_content_root(html);
+    let heading = root.descendants().find(|node| {
+        (node_has_tag(node, "h1") || node_has_tag(node, "h2"))
+            && text_match_key(&node.text_contents()) == title_key
+    });
+
+    match heading {
+        Some(node) => {
+            node.detach();
+            serialize_node(&root, children_only)
+        }
+        None => html.to_string(),
+    }
+}
+
+/// Wrap content in an `<article>` with `lang` and `dir` attributes
+///
+/// A lone top-level `<div>` or `<article>` is replaced by the new element, keeping its
+/// attributes and children, so the content isn't nested in a second wrapper.
+pub fn wrap_in_article(html: &str, lang: Option<&str>, dir: Option<&str>) -> String {
+    let (root, children_only) = parse_content_root(html);
+    let Some(article) = kuchikikiki::parse_html()
+        .one("<article></article>")
+        .select_first("article")
+        .ok()
+        .map(|article| article.as_node().clone())
+    else {
+        return html.to_string();
+    };
+    article.detach();
+
+    let has_loose_text = root.children().any(|child| {
+        child
+            .as_text()
+            .is_some_and(|text| !text.borrow().trim().is_empty())
+    });
+    let elements: Vec<NodeRef> = root
+        .children()
+        .filter(|child| child.as_element().is_some())
+        .collect();
+
+    let wrapper = match elements.as_slice() {
+        [only]
+            if !has_loose_text && (node_has_tag(only, "div") || node_has_tag(only, "article")) =>
+        {
+            Some(only.clone())
+        }
+        _ => None,
+    };
+
+    let new_element = article.as_element().expect("article is an element");
+    let content_parent = match &wrapper {
+        Some(wrapper) => {
+            if let Some(element) = wrapper.as_element() {
+                new_element.attributes.borrow_mut().map = element.attributes.borrow().map.clone();
+            }
+            wrapper.clone()
+        }
+        None => root.clone(),
+    };
+
+    {
+        let mut attrs = new_element.attributes.borrow_mut();
+        if let Some(lang) = lang.map(str::trim).filter(|lang| !lang.is_empty()) {
+            attrs.insert("lang", lang.to_string());
+        }
+        if let Some(dir) = dir.map(str::trim).filter(|dir| !dir.is_empty()) {
+            attrs.insert("dir", dir.to_string());
+        }
+    }
+
+    let children: Vec<NodeRef> = content_parent.children().collect();
+    for child in children {
+        article.append(child);
+    }
+
+    match wrapper {
+        Some(wrapper) => {
+            wrapper.insert_before(article);
+            wrapper.detach();
+        }
+        None => root.append(article),
+    }
+
+    serialize_node(&root, children_only)
+}
+
+/// Remove a breadcrumb trail that leads the content
+///
+/// Follows the first text-bearing path from the top of the content and removes the
+/// outermost element that reads as a breadcrumb: two or more links split by separators,
+/// optionally ending with the current page as plain text. Content that doesn't start
+/// with such a trail is returned unchanged.
+pub fn remove_leading_breadcrumb(html: &str) -> String {
+    let (root, children_only) = parse_content_root(html);
+
+    let mut current = root.clone();
+    loop {
+        let Some(next) = current
+            .children()
+            .find(|child| !child.text_contents().trim().is_empty())
+        else {
+            return html.to_string();
+        };
+        if next.as_element().is_none() {
+            return html.to_string();
+        }
+        if looks_like_breadcrumb(&next) {
+            next.detach();
+            return serialize_node(&root, children_only);
+        }
+        current = next;
+    }
+}
+
+const BREADCRUMB_SEPARATORS: &[char] = &['›', '»', '/', '>', '→', '|'];
+
+fn looks_like_breadcrumb(node: &NodeRef) -> bool {
+    const MAX_TRAIL_LEN: usize = 200;
+    const MAX_CURRENT_PAGE_WORDS: usize = 12;
+
+    let text = node.text_contents();
+    if text.chars().count() > MAX_TRAIL_LEN || !text.contains(BREADCRUMB_SEPARATORS) {
+        return false;
+    }
+    const BLOCK_TAGS: &[&str] = &[
+        "p",
+        "div",
+        "section",
+        "article",
+        "h1",
+        "h2",
+        "h3",
+        "h4",
+        "h5",
+        "h6",
+        "table",
+        "blockquote",
+    ];
+    if node
+        .descendants()
+        .any(|child| BLOCK_TAGS.iter().any(|tag| node_has_tag(&child, tag)))
+    {
+        return false;
+    }
+
+    let link_texts: Vec<String> = match node.select("a") {
+        Ok(links) => links
+            .map(|link| crate::utils::normalize_whitespace(&link.as_node().text_contents()))
+            .map(|text| text.trim().to_string())
+            .filter(|text| !text.is_empty())
+            .collect(),
+        Err(_) => return false,
+    };
+    if link_texts.len() < 2 {
+        return false;
+    }
+
+    let segments: Vec<String> = text
+        .split(BREADCRUMB_SEPARATORS)
+        .map(|segment| {
+            crate::utils::normalize_whitespace(segment)
+                .trim()
+                .to_string()
+        })
+        .filter(|segment| !segment.is_empty())
+        .collect();
+    let last = segments.len().saturating_sub(1);
+    segments.iter().enumerate().all(|(index, segment)| {
+        link_texts.contains(segment)
+            || (index == last && segment.split_whitespace().count() <= MAX_CURRENT_PAGE_WORDS)
+    })
+}
+
+/// Fill in missing image `alt` attributes from captions
+///
+/// Uses the `<figcaption>` of the enclosing `<figure>`, or else the element right after
+/// the image (or after its single-child wrapper) when its class mentions "caption".
+pub fn caption_to_alt(html: &str) -> String {
+    let (root, children_only) = parse_content_root(html);
+    let images: Vec<NodeRef> = match root.select("img") {
+        Ok(images) => images.map(|img| img.as_node().clone()).collect(),
+        Err(_) => return html.to_string(),
+    };
+
+    let mut changed = false;
+    for image in images {
+        let Some(element) = image.as_element() else {
+            continue;
+        };
+        if element.attributes.borrow().contains("alt") {
+            continue;
+        }
+
+        let caption = image_caption(&image)
+            .map(|caption| {
+                crate::utils::normalize_whitespace(&caption)
+                    .trim()
+                    .to_string()
+            })
+            .filter(|caption| !caption.is_empty());
+        if let Some(caption) = caption {
+            element.attributes.borrow_mut().insert("alt", caption);
+            changed = true;
+        }
+    }
+
+    if changed {
+        serialize_node(&root, children_only)
+    } else {
+        html.to_string()
+    }
+}
+
+/// Apply an [`IframeFallback`] to the iframes in `html`.
+///
+/// Iframes whose `src` matches `allowed_video_regex`, or the built-in video allowlist
+/// when none is given, are left alone. The rest are removed, replaced with a link to
+/// their `src`, or kept, depending on `fallback`. Only `http` and `https` sources are
+/// turned into links.
+pub fn apply_iframe_fallback(
+    html: &str,
+    fallback: IframeFallback,
+    allowed_video_regex: Option<&Regex>,
+) -> String {
+    if fallback == IframeFallback::Keep {
+        return html.to_string();
+    }
+
+    let (root, children_only) = parse_content_root(html);
+    let iframes: Vec<NodeRef> = match root.select("iframe") {
+        Ok(iframes) => iframes.map(|iframe| iframe.as_node().clone()).collect(),
+        Err(_) => return html.to_string(),
+    };
+    if iframes.is_empty() {
+        return html.to_string();
+    }
+
+    for iframe in iframes {
+        let src = iframe
+            .as_element()
+            .and_then(|element| element.attributes.borrow().get("src").map(str::to_string))
+            .map(|src| src.trim().to_string())
+            .filter(|src| !src.is_empty());
+        let allowed = match (&src, allowed_video_regex) {
+            (Some(src), Some(regex)) => regex.is_match(src),
+            _ => node_has_allowed_video(&iframe),
+        };
+        if allowed {
+            continue;
+        }
+
+        let src = src.filter(|src| {
+            let lower = src.to_ascii_lowercase();
+            lower.starts_with("http://") || lower.starts_with("https://")
+        });
+        if let (IframeFallback::LinkPlaceholder, Some(src)) = (fallback, src) {
+            let link = kuchikikiki::parse_html()
+                .one("<a></a>")
+                .select_first("a")
+                .ok()
+                .map(|link| link.as_node().clone());
+            if let Some(link) = link {
+                link.detach();
+                if let Some(element) = link.as_element() {
+                    element.attributes.borrow_mut().insert("href", src.clone());
+                }
+                link.append(NodeRef::new_text(src));
+                iframe.insert_before(link);
+            }
+        }
+        iframe.detach();
+    }
+
+    serialize_node(&root, children_only)
+}
+
+fn image_caption(image: &NodeRef) -> Option<String> {
+    // Only the enclosing figure's own caption, not one of a nested figure
+    if let Some(figure) = image.ancestors().find(|node| node_has_tag(node, "figure")) {
+        return figure
+            .children()
+            .find(|child| node_has_tag(child, "figcaption"))
+            .map(|caption| caption.text_contents());
+    }
+
+    let mut anchor = image.clone();
+    while let Some(parent) = anchor.parent() {
+        let only_child = parent
+            .children()
+            .filter(|child| child.as_element().is_some())
+            .count()
+            == 1;
+        if !only_child || parent.as_document().is_some() || node_has_tag(&parent, "body") {
+            break;
+        }
+        anchor = parent;
+    }
+
+    // The caption must directly follow the image (or its only-child wrappers)
+    anchor
+        .following_siblings()
+        .find(|sibling| sibling.as_element().is_some())
+        .filter(|sibling| {
+            sibling.as_element().is_some_and(|element| {
+                element
+                    .attributes
+                    .borrow()
+                    .get("class")
+                    .is_some_and(|class| class.to_lowercase().contains("caption"))
+            })
+        })
+        .map(|caption| caption.text_contents())
+}
+
+fn is_marked_byline_element(node: &NodeRef) -> bool {
+    let Some(element) = node.as_element() else {
+        return false;
+    };
+
+    let attrs = element.attributes.borrow();
+    let match_string = format!(
+        "{} {}",
+        attrs.get("class").unwrap_or(""),
+        attrs.get("id").unwrap_or("")
+    );
+    attrs.get("rel") == Some("author")
+        || attrs
+            .get("itemprop")
+            .is_some_and(|itemprop| itemprop.contains("author"))
+        || REGEXPS.byline.is_match(&match_string)
+}
+
+/// Lowercased words of a byline without a leading "by"
+fn byline_match_key(text: &str) -> String {
+    let key = text_match_key(text);
+    key.strip_prefix("by ").unwrap_or(&key).to_string()
+}
+
+/// Parse extracted content, returning the node whose children hold it
+fn parse_content_root(html: &str) -> (NodeRef, bool) {
+    let document = kuchikikiki::parse_html().one(html);
+    let body_node = document
+        .select("body")
+        .ok()
+        .and_then(|mut iter| iter.next())
+        .map(|node| node.as_node().clone());
+    match body_node {
+        Some(body) => (body, true),
+        None => (document, false),
+    }
+}
 
-            let id_pattern = format!(
-                r#"(?is)<{tag}\b[^>]*?id="[^"]*?{keyword}[^"]*?"[^>]*?>.*?</{tag}>"#
-            );
-            let re = Regex::new(&id_pattern).unwrap();
-            result = re.replace_all(&result, "").to_string();
-        }
+fn is_pull_quote_candidate(node: &NodeRef) -> bool {
+    let Some(element) = node.as_element() else {
+        return false;
+    };
+
+    let class = element
+        .attributes
+        .borrow()
+        .get("class")
+        .unwrap_or("")
+        .to_ascii_lowercase();
+    if class.contains("pullquote") || class.contains("pull-quote") {
+        return true;
     }
 
-    result
+    node_has_tag(node, "aside")
+        && node
+            .select("blockquote")
+            .map(|mut blockquotes| blockquotes.next().is_some())
+            .unwrap_or(false)
+}
+
+/// Lowercased words of `text` joined by single spaces, ignoring punctuation
+fn text_match_key(text: &str) -> String {
+    text.split(|c: char| !c.is_alphanumeric())
+        .filter(|word| !word.is_empty())
+        .map(|word| word.to_lowercase())
+        .collect::<Vec<_>>()
+        .join(" ")
 }
 
-fn remove_conditionally(html: &str) -> String {
-    remove_conditionally_dom(html).unwrap_or_else(|| remove_conditionally_regex(html))
+fn remove_conditionally(
+    html: &str,
+    options: &ReadabilityOptions,
+    stats: &mut ParseStats,
+) -> String {
+    let mut dom_stats = ParseStats {
+        trace_removed_blocks: stats.trace_removed_blocks,
+        ..Default::default()
+    };
+    match remove_conditionally_dom(html, options, &mut dom_stats) {
+        Some(result) => {
+            stats.elements_removed += dom_stats.elements_removed;
+            stats.removed_blocks.append(&mut dom_stats.removed_blocks);
+            result
+        }
+        None => {
+            let result = remove_conditionally_regex(html, stats);
+            if options.keep_inline_svg {
+                sanitize_inline_svgs_html(&result)
+            } else {
+                result
+            }
+        }
+    }
 }
 
-fn remove_conditionally_dom(html: &str) -> Option<String> {
+fn remove_conditionally_dom(
+    html: &str,
+    options: &ReadabilityOptions,
+    stats: &mut ParseStats,
+) -> Option<String> {
     let document = kuchikikiki::parse_html().one(html);
     let body_node = document
         .select("body")
@@ -92,14 +1199,25 @@ fn remove_conditionally_dom(html: &str) -> Option<String> {
     };
     mark_data_tables(&target_node);
 
-    let cleanup_tags = ["form", "fieldset", "table", "ul", "ol", "div", "section"];
-    for tag in cleanup_tags {
-        clean_conditionally_tag(&target_node, tag);
+    for tag in CONDITIONALLY_CLEANED_TAGS {
+        clean_conditionally_tag(&target_node, tag, options, stats);
+    }
+    if !options.keep_table_columns {
+        remove_table_columns(&target_node);
+    }
+    trim_empty_table_edges(&target_node);
+
+    if options.keep_inline_svg {
+        sanitize_inline_svgs(&target_node);
     }
 
     Some(serialize_node(&target_node, children_only))
 }
 
+/// Blocks conditional cleaning considers for removal, in the order they are visited
+const CONDITIONALLY_CLEANED_TAGS: [&str; 7] =
+    ["form", "fieldset", "table", "ul", "ol", "div", "section"];
+
 fn serialize_node(node: &NodeRef, children_only: bool) -> String {
     let mut buffer = Vec::new();
 
@@ -117,20 +1235,30 @@ fn serialize_node(node: &NodeRef, children_only: bool) -> String {
     String::from_utf8(buffer).unwrap_or_else(|_| node.text_contents())
 }
 
-fn clean_conditionally_tag(root: &NodeRef, tag: &str) {
+fn clean_conditionally_tag(
+    root: &NodeRef,
+    tag: &str,
+    options: &ReadabilityOptions,
+    stats: &mut ParseStats,
+) {
     if let Ok(matches) = root.select(tag) {
         let nodes: Vec<_> = matches
             .map(|css_match| css_match.as_node().clone())
             .collect();
         for node in nodes {
-            if should_remove_dom_node(&node, tag) {
+            // Skip nodes already detached along with a removed ancestor
+            if !node.ancestors().any(|ancestor| ancestor == *root) {
+                continue;
+            }
+            if should_remove_dom_node(&node, tag, options) {
+                stats.record_removed(|| removed_block_from_node(&node, RemovalReason::Conditional));
                 node.detach();
             }
         }
     }
 }
 
-fn should_remove_dom_node(node: &NodeRef, tag: &str) -> bool {
+fn should_remove_dom_node(node: &NodeRef, tag: &str, options: &ReadabilityOptions) -> bool {
     let trimmed = node.text_contents().trim().to_string();
     if trimmed.len() > 600 {
         return false;
@@ -194,6 +1322,14 @@ fn should_remove_dom_node(node: &NodeRef, tag: &str) -> bool {
         }
     }
 
+    if node_has_playable_audio(node) {
+        return false;
+    }
+
+    if options.keep_inline_svg && count_large_svgs(node) > 0 {
+        return false;
+    }
+
     if REGEXPS.ad_words.is_match(trimmed.trim()) || REGEXPS.loading_words.is_match(trimmed.trim()) {
         return true;
     }
@@ -262,6 +1398,270 @@ fn should_remove_dom_node(node: &NodeRef, tag: &str) -> bool {
     should_remove
 }
 
+/// Smallest width or height (in CSS pixels) for an inline SVG to count as an illustration
+const MIN_INLINE_SVG_SIZE: f64 = 100.0;
+
+/// Count inline SVGs large enough to be illustrations rather than icons
+fn count_large_svgs(node: &NodeRef) -> usize {
+    node.select("svg")
+        .map(|svgs| svgs.filter(|svg| is_large_svg(svg.as_node())).count())
+        .unwrap_or(0)
+}
+
+fn is_large_svg(svg: &NodeRef) -> bool {
+    let Some(element) = svg.as_element() else {
+        return false;
+    };
+    let attrs = element.attributes.borrow();
+
+    let parse_length = |value: &str| -> Option<f64> {
+        let numeric: String = value
+            .trim()
+            .chars()
+            .take_while(|c| c.is_ascii_digit() || *c == '.')
+            .collect();
+        numeric.parse().ok()
+    };
+
+    let mut width = attrs.get("width").and_then(parse_length);
+    let mut height = attrs.get("height").and_then(parse_length);
+    if width.is_none() || height.is_none() {
+        if let Some(view_box) = attrs.get("viewBox").or_else(|| attrs.get("viewbox")) {
+            let parts: Vec<f64> = view_box
+                .split(|c: char| c.is_whitespace() || c == ',')
+                .filter_map(|part| part.parse().ok())
+                .collect();
+            if parts.len() == 4 {
+                width = width.or(Some(parts[2]));
+                height = height.or(Some(parts[3]));
+            }
+        }
+    }
+
+    width.unwrap_or(0.0).max(height.unwrap_or(0.0)) >= MIN_INLINE_SVG_SIZE
+}
+
+/// Elements kept inside inline SVGs; anything else is removed with its subtree.
+///
+/// Leaves out `script`, `foreignObject`, `style` and the animation elements, which can
+/// set `href` to a script URL after sanitizing.
+const SVG_ALLOWED_ELEMENTS: &[&str] = &[
+    "svg",
+    "g",
+    "defs",
+    "symbol",
+    "use",
+    "title",
+    "desc",
+    "a",
+    "path",
+    "rect",
+    "circle",
+    "ellipse",
+    "line",
+    "polyline",
+    "polygon",
+    "text",
+    "tspan",
+    "textpath",
+    "image",
+    "lineargradient",
+    "radialgradient",
+    "stop",
+    "clippath",
+    "mask",
+    "pattern",
+    "marker",
+    "filter",
+    "feblend",
+    "fecolormatrix",
+    "fecomposite",
+    "feflood",
+    "fegaussianblur",
+    "femerge",
+    "femergenode",
+    "feoffset",
+];
+
+/// Attributes kept on inline SVG elements, compared case-insensitively
+const SVG_ALLOWED_ATTRIBUTES: &[&str] = &[
+    "id",
+    "class",
+    "lang",
+    "role",
+    "aria-label",
+    "aria-hidden",
+    "xmlns",
+    "version",
+    "width",
+    "height",
+    "x",
+    "y",
+    "x1",
+    "y1",
+    "x2",
+    "y2",
+    "cx",
+    "cy",
+    "r",
+    "rx",
+    "ry",
+    "fx",
+    "fy",
+    "d",
+    "points",
+    "viewbox",
+    "preserveaspectratio",
+    "transform",
+    "href",
+    "fill",
+    "fill-opacity",
+    "fill-rule",
+    "stroke",
+    "stroke-width",
+    "stroke-opacity",
+    "stroke-linecap",
+    "stroke-linejoin",
+    "stroke-dasharray",
+    "stroke-dashoffset",
+    "stroke-miterlimit",
+    "opacity",
+    "color",
+    "display",
+    "visibility",
+    "vector-effect",
+    "clip-path",
+    "clip-rule",
+    "mask",
+    "filter",
+    "marker-start",
+    "marker-mid",
+    "marker-end",
+    "font-family",
+    "font-size",
+    "font-weight",
+    "font-style",
+    "letter-spacing",
+    "word-spacing",
+    "text-anchor",
+    "dominant-baseline",
+    "dx",
+    "dy",
+    "rotate",
+    "textlength",
+    "startoffset",
+    "offset",
+    "stop-color",
+    "stop-opacity",
+    "gradientunits",
+    "gradienttransform",
+    "spreadmethod",
+    "patternunits",
+    "patterncontentunits",
+    "patterntransform",
+    "markerwidth",
+    "markerheight",
+    "refx",
+    "refy",
+    "orient",
+    "markerunits",
+    "clippathunits",
+    "maskunits",
+    "maskcontentunits",
+    "stddeviation",
+    "in",
+    "in2",
+    "result",
+    "mode",
+    "type",
+    "values",
+    "operator",
+    "flood-color",
+    "flood-opacity",
+];
+
+/// Reduce inline SVGs to an allowlist of drawing elements and attributes
+///
+/// `href` (including `xlink:href`) is kept only for fragments, relative URLs and
+/// `http`/`https` URLs; whitespace and control characters are ignored when reading the
+/// scheme, so `java&#9;script:` does not slip through.
+fn sanitize_inline_svgs(root: &NodeRef) {
+    let svgs: Vec<NodeRef> = match root.select("svg") {
+        Ok(matches) => matches.map(|svg| svg.as_node().clone()).collect(),
+        Err(_) => return,
+    };
+
+    for svg in svgs {
+        let disallowed: Vec<NodeRef> = svg
+            .descendants()
+            .filter(|node| {
+                node.as_element().is_some_and(|element| {
+                    let tag = element.name.local.as_ref().to_ascii_lowercase();
+                    !SVG_ALLOWED_ELEMENTS.contains(&tag.as_str())
+                })
+            })
+            .collect();
+        for node in disallowed {
+            node.detach();
+        }
+
+        for node in svg.inclusive_descendants() {
+            if let Some(element) = node.as_element() {
+                let mut attrs = element.attributes.borrow_mut();
+                attrs.map.retain(|name, attribute| {
+                    let local = name.local.as_ref().to_ascii_lowercase();
+                    if !SVG_ALLOWED_ATTRIBUTES.contains(&local.as_str()) {
+                        return false;
+                    }
+                    local != "href" || is_safe_svg_href(&attribute.value)
+                });
+            }
+        }
+    }
+}
+
+/// Sanitize the inline SVGs of an HTML fragment, as [`sanitize_inline_svgs`] does
+fn sanitize_inline_svgs_html(html: &str) -> String {
+    if !html.to_ascii_lowercase().contains("<svg") {
+        return html.to_string();
+    }
+    let (root, children_only) = parse_content_root(html);
+    sanitize_inline_svgs(&root);
+    serialize_node(&root, children_only)
+}
+
+fn is_safe_svg_href(value: &str) -> bool {
+    let compact: String = value
+        .chars()
+        .filter(|c| !c.is_whitespace() && !c.is_control())
+        .collect::<String>()
+        .to_ascii_lowercase();
+    let scheme_end = compact.find(|c| matches!(c, ':' | '/' | '?' | '#'));
+    match scheme_end {
+        Some(index) if compact[index..].starts_with(':') => {
+            compact.starts_with("http:") || compact.starts_with("https:")
+        }
+        _ => true,
+    }
+}
+
+/// Remove `aria-*` and `role` attributes from the content
+///
+/// Used when [`ReadabilityOptions::preserve_aria`] is off. Other attributes, including
+/// `rel` on links, are left alone.
+fn strip_aria_attributes(html: &str) -> String {
+    let (root, children_only) = parse_content_root(html);
+    for node in root.descendants() {
+        if let Some(element) = node.as_element() {
+            element.attributes.borrow_mut().map.retain(|name, _| {
+                let local = name.local.as_ref().to_ascii_lowercase();
+                local != "role" && !local.starts_with("aria-")
+            });
+        }
+    }
+    serialize_node(&root, children_only)
+}
+
 fn dom_link_density(node: &NodeRef, text_len: usize) -> f64 {
     if text_len == 0 {
         return 1.0;
@@ -388,6 +1788,108 @@ fn is_data_table(node: &NodeRef) -> bool {
     }
 }
 
+/// Remove `<colgroup>` and `<col>` elements from every table
+fn remove_table_columns(root: &NodeRef) {
+    let columns: Vec<NodeRef> = match root.select("colgroup, col") {
+        Ok(columns) => columns.map(|column| column.as_node().clone()).collect(),
+        Err(_) => return,
+    };
+    for column in columns {
+        column.detach();
+    }
+}
+
+/// Drop empty spacer rows and columns at the edges of kept data tables
+///
+/// Leading and trailing rows whose cells are all empty are removed, as are leading and
+/// trailing columns that are empty in every row. Empty cells in the middle of the data
+/// are kept. Columns are left alone when rows differ in length, a cell spans several
+/// columns, or the table has `<col>` elements, since positions can't be lined up.
+fn trim_empty_table_edges(root: &NodeRef) {
+    let tables: Vec<NodeRef> = match root.select("table") {
+        Ok(tables) => tables.map(|table| table.as_node().clone()).collect(),
+        Err(_) => return,
+    };
+
+    for table in tables.iter().filter(|table| is_data_table(table)) {
+        let Ok(trs) = table.select("tr") else {
+            continue;
+        };
+        let mut rows: Vec<(NodeRef, Vec<NodeRef>)> = trs
+            .map(|tr| tr.as_node().clone())
+            .filter(|tr| {
+                tr.ancestors()
+                    .find(|ancestor| node_has_tag(ancestor, "table"))
+                    .is_some_and(|owner| owner == *table)
+            })
+            .map(|tr| {
+                let cells = tr
+                    .children()
+                    .filter(|child| node_has_tag(child, "td") || node_has_tag(child, "th"))
+                    .collect();
+                (tr, cells)
+            })
+            .collect();
+
+        let empty_row = |row: &(NodeRef, Vec<NodeRef>)| row.1.iter().all(is_empty_cell);
+        while rows.first().is_some_and(empty_row) {
+            rows.remove(0).0.detach();
+        }
+        while rows.last().is_some_and(empty_row) {
+            if let Some((tr, _)) = rows.pop() {
+                tr.detach();
+            }
+        }
+
+        let width = rows.first().map_or(0, |(_, cells)| cells.len());
+        let aligned = width > 0
+            && count_descendants(table, "col") == 0
+            && rows
+                .iter()
+                .all(|(_, cells)| cells.len() == width && !cells.iter().any(spans_several_columns));
+        if !aligned {
+            continue;
+        }
+        let empty_column =
+            |index: usize| rows.iter().all(|(_, cells)| is_empty_cell(&cells[index]));
+        let start = (0..width).find(|&index| !empty_column(index));
+        let Some(start) = start else {
+            continue;
+        };
+        let end = (start..width)
+            .rfind(|&index| !empty_column(index))
+            .map_or(width, |index| index + 1);
+        for (_, cells) in &rows {
+            for (index, cell) in cells.iter().enumerate() {
+                if index < start || index >= end {
+                    cell.detach();
+                }
+            }
+        }
+    }
+}
+
+/// Whether a table cell has neither text nor embedded media
+fn is_empty_cell(cell: &NodeRef) -> bool {
+    cell.text_contents().trim().is_empty()
+        && ![
+            "img", "picture", "video", "audio", "svg", "iframe", "object", "embed", "input",
+        ]
+        .iter()
+        .any(|tag| count_descendants(cell, tag) > 0)
+}
+
+fn spans_several_columns(cell: &NodeRef) -> bool {
+    cell.as_element().is_some_and(|element| {
+        element
+            .attributes
+            .borrow()
+            .get("colspan")
+            .and_then(|span| span.trim().parse::<usize>().ok())
+            .is_some_and(|span| span > 1)
+    })
+}
+
 fn node_contains_data_table(node: &NodeRef) -> bool {
     if let Ok(tables) = node.select("table") {
         for table in tables {
@@ -446,6 +1948,42 @@ fn node_has_allowed_video(node: &NodeRef) -> bool {
     false
 }
 
+/// Whether the node is the smallest cleaned block around an `<audio>` player with a
+/// source to play
+///
+/// Larger blocks that happen to contain the player are judged on their own, so a
+/// link-heavy wrapper is not kept just because a player sits inside it.
+fn node_has_playable_audio(node: &NodeRef) -> bool {
+    let has_source = |element: &NodeRef| {
+        element
+            .as_element()
+            .and_then(|element| element.attributes.borrow().get("src").map(str::to_string))
+            .is_some_and(|src| !src.trim().is_empty())
+    };
+
+    let wraps_directly = |player: &NodeRef| {
+        player
+            .ancestors()
+            .find(|ancestor| {
+                CONDITIONALLY_CLEANED_TAGS
+                    .iter()
+                    .any(|tag| node_has_tag(ancestor, tag))
+            })
+            .is_some_and(|block| block == *node)
+    };
+
+    node.select("audio").is_ok_and(|mut players| {
+        players.any(|player| {
+            let player = player.as_node();
+            wraps_directly(player)
+                && (has_source(player)
+                    || player.select("source").is_ok_and(|mut sources| {
+                        sources.any(|source| has_source(source.as_node()))
+                    }))
+        })
+    })
+}
+
 fn build_textish_tags() -> Vec<&'static str> {
     let mut tags = vec!["span", "li", "td"];
     for tag in DIV_TO_P_ELEMS.iter() {
@@ -495,24 +2033,26 @@ fn get_dom_class_weight(node: &NodeRef) -> i32 {
     weight
 }
 
-fn remove_conditionally_regex(html: &str) -> String {
+fn remove_conditionally_regex(html: &str, stats: &mut ParseStats) -> String {
     let mut result = html.to_string();
     let cleanup_tags = ["table", "ul", "ol", "div", "section"];
 
     for tag in cleanup_tags {
-        result = remove_blocks_for_tag(&result, tag);
+        result = remove_blocks_for_tag(&result, tag, stats);
     }
 
     result
 }
 
-fn remove_blocks_for_tag(html: &str, tag: &str) -> String {
+fn remove_blocks_for_tag(html: &str, tag: &str, stats: &mut ParseStats) -> String {
     let pattern = format!(r"(?is)<{tag}\b[^>]*?>.*?</{tag}>");
     let re = Regex::new(&pattern).unwrap();
 
     re.replace_all(html, |caps: &Captures| {
         let block = caps.get(0).map(|m| m.as_str()).unwrap_or_default();
         if should_remove_block(block, tag) {
+            stats
+                .record_removed(|| removed_block_from_html(block, tag, RemovalReason::Conditional));
             String::new()
         } else {
             block.to_string()
@@ -786,7 +2326,8 @@ fn replace_brs_in_content(content: &str) -> String {
 ///
 /// This function implements Mozilla's _prepDocument functionality:
 /// - Remove script and style elements
-/// - Replace font tags with span
+/// - Unwrap `<font>` and `<big>` tags and turn `<center>` into a `<div>` with the same
+///   attributes
 /// - Unwrap noscript tags to reveal lazy-loaded images
 /// - Remove form elements
 ///
@@ -797,14 +2338,17 @@ pub fn prep_document(html: &str) -> String {
     let script_regex = regex::Regex::new(r"(?i)<script\b[^>]*>[\s\S]*?</script>").unwrap();
     html = script_regex.replace_all(&html, "").to_string();
 
-    let style_regex = regex::Regex::new(r"(?i)<style\b[^>]*>[\s\S]*?</style>").unwrap();
+    let style_regex = regex::Regex::new(r"(?i)<style\b[^>]*>([\s\S]*?)</style>").unwrap();
+    let hidden_selectors: Vec<String> = style_regex
+        .captures_iter(&html)
+        .flat_map(|caps| crate::dom_utils::hidden_selectors_from_stylesheet(&caps[1]))
+        .collect();
     html = style_regex.replace_all(&html, "").to_string();
+    if !hidden_selectors.is_empty() {
+        html = mark_stylesheet_hidden(&html, &hidden_selectors);
+    }
 
-    let font_open_regex = regex::Regex::new(r"<font\b").unwrap();
-    html = font_open_regex.replace_all(&html, "<span").to_string();
-
-    let font_close_regex = regex::Regex::new(r"</font>").unwrap();
-    html = font_close_regex.replace_all(&html, "</span>").to_string();
+    html = unwrap_presentational_tags(&html);
 
     let noscript_regex = regex::Regex::new(r"(?is)<noscript\b[^>]*>(.*?)</noscript>").unwrap();
     html = noscript_regex
@@ -824,10 +2368,187 @@ pub fn prep_document(html: &str) -> String {
     html
 }
 
+/// Unwrap `<font>` and `<big>`, keeping their content, and turn `<center>` into a `<div>`
+///
+/// The `<div>` keeps the attributes of the `<center>` it replaces, such as `id`, `class`,
+/// `dir` and `lang`.
+fn unwrap_presentational_tags(html: &str) -> String {
+    static TAG_REGEX: Lazy<Regex> = Lazy::new(|| Regex::new(r"(?i)<(font|big|center)\b").unwrap());
+    if !TAG_REGEX.is_match(html) {
+        return html.to_string();
+    }
+
+    let document = kuchikikiki::parse_html().one(html);
+    let Ok(matches) = document.select("font, big, center") else {
+        return html.to_string();
+    };
+    let nodes: Vec<NodeRef> = matches.map(|element| element.as_node().clone()).collect();
+    for node in nodes {
+        let children: Vec<NodeRef> = node.children().collect();
+        if node_has_tag(&node, "center") {
+            let Some(div) = kuchikikiki::parse_html()
+                .one("<div></div>")
+                .select_first("div")
+                .ok()
+                .map(|div| div.as_node().clone())
+            else {
+                continue;
+            };
+            div.detach();
+            if let (Some(new_element), Some(element)) = (div.as_element(), node.as_element()) {
+                new_element.attributes.borrow_mut().map = element.attributes.borrow().map.clone();
+            }
+            for child in children {
+                div.append(child);
+            }
+            node.insert_before(div);
+        } else {
+            for child in children {
+                node.insert_before(child);
+            }
+        }
+        node.detach();
+    }
+    document.to_string()
+}
+
+/// Blocks numbered by [`annotate_positions`]
+const POSITION_BLOCKS: &str = "p, h1, h2, h3, h4, h5, h6, li, pre, blockquote, figure, table";
+
+/// Add a `data-pos` attribute with the document-order index of each content block
+///
+/// Used for [`ReadabilityOptions::annotate_positions`]; the indices follow the source
+/// markup and are carried through extraction on the blocks that survive.
+pub fn annotate_positions(html: &str) -> String {
+    let document = kuchikikiki::parse_html().one(html);
+    let Ok(blocks) = document.select(POSITION_BLOCKS) else {
+        return html.to_string();
+    };
+    for (index, block) in blocks.enumerate() {
+        block
+            .attributes
+            .borrow_mut()
+            .insert("data-pos", index.to_string());
+    }
+    document.to_string()
+}
+
+/// CSS paths of the blocks numbered by [`annotate_positions`], indexed by `data-pos`
+///
+/// Each path runs from `html` down through `:nth-child` steps, such as
+/// `html > body:nth-child(2) > article:nth-child(1) > p:nth-child(3)`, so it can be passed
+/// to `querySelector` on the page the HTML came from.
+pub fn block_source_paths(html: &str) -> Vec<String> {
+    let document = kuchikikiki::parse_html().one(html);
+    let Ok(blocks) = document.select(POSITION_BLOCKS) else {
+        return Vec::new();
+    };
+    blocks.map(|block| dom_path(block.as_node())).collect()
+}
+
+fn dom_path(node: &NodeRef) -> String {
+    let mut steps: Vec<String> = node
+        .inclusive_ancestors()
+        .filter_map(|ancestor| {
+            let name = ancestor.as_element()?.name.local.to_string();
+            if ancestor
+                .parent()
+                .is_none_or(|parent| parent.as_element().is_none())
+            {
+                return Some(name);
+            }
+            let index = ancestor
+                .preceding_siblings()
+                .filter(|sibling| sibling.as_element().is_some())
+                .count()
+                + 1;
+            Some(format!("{name}:nth-child({index})"))
+        })
+        .collect();
+    steps.reverse();
+    steps.join(" > ")
+}
+
+/// The `data-pos` values left in `html`, in document order
+///
+/// With `strip`, the attributes are removed from the returned HTML.
+pub fn take_positions(html: &str, strip: bool) -> (String, Vec<usize>) {
+    let (root, children_only) = parse_content_root(html);
+    let Ok(blocks) = root.select("[data-pos]") else {
+        return (html.to_string(), Vec::new());
+    };
+    let mut positions = Vec::new();
+    for block in blocks {
+        let mut attributes = block.attributes.borrow_mut();
+        if let Some(pos) = attributes.get("data-pos").and_then(|pos| pos.parse().ok()) {
+            positions.push(pos);
+        }
+        if strip {
+            attributes.remove("data-pos");
+        }
+    }
+    if !strip {
+        return (html.to_string(), positions);
+    }
+
+    (serialize_node(&root, children_only), positions)
+}
+
+/// Add a `hidden` attribute to elements matched by stylesheet rules that hide them,
+/// so visibility checks see what inline styles alone would miss.
+fn mark_stylesheet_hidden(html: &str, selectors: &[String]) -> String {
+    let document = kuchikikiki::parse_html().one(html);
+    let mut changed = false;
+    for selector in selectors {
+        let Ok(matches) = document.select(selector) else {
+            continue;
+        };
+        for element in matches {
+            element
+                .attributes
+                .borrow_mut()
+                .insert("hidden", String::new());
+            changed = true;
+        }
+    }
+
+    if changed {
+        document.to_string()
+    } else {
+        html.to_string()
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_annotate_positions() {
+        let html = "<html><body><h1>Title</h1><div><p>One</p><ul><li>Two</li></ul></div><p>Three</p></body></html>";
+        let annotated = annotate_positions(html);
+        assert!(annotated.contains(r#"<h1 data-pos="0">"#));
+        assert!(annotated.contains(r#"<p data-pos="1">One"#));
+        assert!(annotated.contains(r#"<li data-pos="2">Two"#));
+        assert!(annotated.contains(r#"<p data-pos="3">Three"#));
+        assert!(annotated.contains("<div><p"));
+    }
+
+    #[test]
+    fn test_block_source_paths() {
+        let html =
+            "<html><body><h1>Title</h1><div><p>One</p><ul><li>Two</li></ul></div></body></html>";
+        let paths = block_source_paths(html);
+        assert_eq!(
+            paths,
+            vec![
+                "html > body:nth-child(2) > h1:nth-child(1)",
+                "html > body:nth-child(2) > div:nth-child(2) > p:nth-child(1)",
+                "html > body:nth-child(2) > div:nth-child(2) > ul:nth-child(2) > li:nth-child(1)",
+            ]
+        );
+    }
+
     #[test]
     fn test_remove_nav_like_sections() {
         let html = r#"
@@ -839,7 +2560,7 @@ mod tests {
             </div>
         "#;
 
-        let cleaned = remove_nav_like_sections(html);
+        let cleaned = remove_nav_like_sections(html, &mut ParseStats::default());
         assert!(cleaned.contains("<p>Main article text</p>"));
         assert!(!cleaned.contains("<nav"));
         assert!(!cleaned.contains("navbar"));
@@ -858,7 +2579,11 @@ mod tests {
             </article>
         "##;
 
-        let cleaned = remove_conditionally(html);
+        let cleaned = remove_conditionally(
+            html,
+            &ReadabilityOptions::default(),
+            &mut ParseStats::default(),
+        );
         assert!(!cleaned.contains("nav-table"));
         assert!(cleaned.contains("Main story starts here"));
     }
@@ -914,4 +2639,383 @@ mod tests {
         assert!(result.contains("<p>Text 1</p>"));
         assert!(result.contains("<p>Text 2</p>"));
     }
+
+    #[test]
+    fn test_drop_data_uri_images_above_threshold() {
+        let large = format!("data:image/png;base64,{}", "A".repeat(8000));
+        let html = format!(
+            r#"<p>Chart: <img src="{large}" alt="Quarterly revenue"> and icon <img src="data:image/gif;base64,R0lGODlh" alt=""></p>"#
+        );
+
+        let dropped = drop_data_uri_images(&html, 4096, false);
+        assert!(dropped.len() < 200);
+        assert!(!dropped.contains("Quarterly revenue"));
+        assert!(dropped.contains("R0lGODlh"));
+
+        let with_alt = drop_data_uri_images(&html, 4096, true);
+        assert!(with_alt.contains("Chart: Quarterly revenue and icon"));
+        assert!(!with_alt.contains(&large));
+
+        let html =
+            format!(r#"<p><img src="{large}" alt="<img src=x onerror=alert(1)> &amp; more"></p>"#);
+        let with_alt = drop_data_uri_images(&html, 4096, true);
+        assert_eq!(
+            with_alt,
+            "<p>&lt;img src=x onerror=alert(1)&gt; &amp; more</p>"
+        );
+    }
+
+    #[test]
+    fn test_prepare_epub_content_disambiguates_images() {
+        let html = r#"<div><img src="https://a.example.com/photo.jpg"><img src="https://b.example.com/photo.jpg?w=2"><img src="https://a.example.com/photo.jpg"><picture><source srcset="x.webp"><img src="data:image/gif;base64,R0lG" class="x"></picture></div>"#;
+        let (content, images) = prepare_epub_content(html);
+
+        assert_eq!(
+            images,
+            vec![
+                (
+                    "https://a.example.com/photo.jpg".to_string(),
+                    "images/photo.jpg".to_string()
+                ),
+                (
+                    "https://b.example.com/photo.jpg?w=2".to_string(),
+                    "images/photo-2.jpg".to_string()
+                ),
+            ]
+        );
+        assert_eq!(content.matches(r#"src="images/photo.jpg""#).count(), 2);
+        assert!(content.contains(r#"src="images/photo-2.jpg""#));
+        assert!(content.contains(r#"<img src="data:image/gif;base64,R0lG">"#));
+        assert!(!content.contains("picture"));
+        assert!(!content.contains("x.webp"));
+    }
+
+    #[test]
+    fn test_fix_relative_urls_only_touches_attributes() {
+        let html = r#"<div><p><a href="/news">News</a> <img src='img/a.png' srcset="img/a.png 1x, img/a@2x.png 2x"></p><pre>&lt;a href="/raw"&gt;</pre><p>Write src="x.png" in the tag.</p></div>"#;
+        let mut stats = ParseStats::default();
+        let fixed = fix_relative_urls_in_html(html, "https://example.com/blog/post", &mut stats);
+
+        assert!(fixed.contains(r#"<a href="https://example.com/news">"#));
+        assert!(fixed.contains(r#"src="https://example.com/blog/img/a.png""#));
+        assert!(fixed.contains("https://example.com/blog/img/a@2x.png 2x"));
+        assert!(fixed.contains(r#"<pre>&lt;a href="/raw"&gt;</pre>"#));
+        assert!(fixed.contains(r#"Write src="x.png" in the tag."#));
+        assert_eq!(stats.links_absolutized, 4);
+    }
+
+    #[test]
+    fn test_repeated_block_keys_skip_short_blocks_and_cells() {
+        let html = r#"<div><p>Share</p><p>Sign up for our morning newsletter today.</p><table><tr><td>Population of the harbour district in 2020</td></tr></table></div>"#;
+        let keys = repeated_block_keys(html);
+        assert_eq!(keys.len(), 1);
+        assert!(keys.contains("Sign up for our morning newsletter today."));
+    }
+
+    #[test]
+    fn test_iframe_link_placeholder_requires_http() {
+        let html = r#"<div><iframe src="javascript:alert(1)"></iframe><iframe src="HTTPS://maps.example.com/embed"></iframe></div>"#;
+        let result = apply_iframe_fallback(html, IframeFallback::LinkPlaceholder, None);
+        assert!(!result.contains("javascript:"));
+        assert!(!result.contains("<iframe"));
+        assert!(result.contains(r#"<a href="HTTPS://maps.example.com/embed">"#));
+    }
+
+    #[test]
+    fn test_keep_inline_svg_illustration() {
+        let html = r#"
+            <article>
+                <p>Rainfall has increased steadily over the past decade across the region.</p>
+                <div class="chart"><div class="inner"><svg width="600" height="400" viewBox="0 0 600 400" onload="alert(1)"><script src="track.js"></script><rect x="10" y="10" width="100" height="300" onclick="steal()"></rect></svg></div></div>
+                <div class="icon"><div><svg width="16" height="16"><path d="M0 0"></path></svg></div></div>
+            </article>
+        "#;
+
+        let default_cleaned = remove_conditionally(
+            html,
+            &ReadabilityOptions::default(),
+            &mut ParseStats::default(),
+        );
+        assert!(!default_cleaned.contains("<rect"));
+
+        let options = ReadabilityOptions::builder().keep_inline_svg(true).build();
+        let cleaned = remove_conditionally(html, &options, &mut ParseStats::default());
+        assert!(cleaned.contains("<rect"));
+        assert!(!cleaned.contains("<path"));
+        assert!(!cleaned.contains("alert"));
+        assert!(!cleaned.contains("track.js"));
+        assert!(!cleaned.contains("onclick"));
+        assert!(cleaned.contains("Rainfall has increased"));
+    }
+
+    #[test]
+    fn test_sanitize_inline_svgs_allowlist() {
+        let html = r##"<div><svg width="600" height="400"><foreignObject><div onclick="x()">form</div></foreignObject><a href="java&#9;script:alert(1)"><text>bad</text></a><a xlink:href="&#106;avascript:alert(2)"><text>worse</text></a><animate attributeName="href" to="javascript:alert(3)"></animate><set attributeName="href" to="javascript:alert(4)"></set><use href="#bar"></use><rect width="10" height="20" fill="red" style="x"></rect></svg></div>"##;
+
+        let cleaned = sanitize_inline_svgs_html(html);
+        assert!(!cleaned.to_lowercase().contains("foreignobject"));
+        assert!(!cleaned.contains("form"));
+        assert!(!cleaned.contains("<animate"));
+        assert!(!cleaned.contains("<set"));
+        assert!(!cleaned.contains("script:"));
+        assert!(!cleaned.contains("style="));
+        assert!(cleaned.contains(r##"<use href="#bar">"##));
+        assert!(cleaned.contains(r#"fill="red""#));
+        assert!(cleaned.contains("<text>bad</text>"));
+
+        assert!(is_safe_svg_href("https://example.com/chart.svg"));
+        assert!(is_safe_svg_href("images/chart.png"));
+        assert!(!is_safe_svg_href(" JAVA\nSCRIPT:alert(1)"));
+        assert!(!is_safe_svg_href(
+            "data:image/svg+xml,<svg onload=alert(1)>"
+        ));
+    }
+
+    #[test]
+    fn test_extract_pull_quotes() {
+        let html = r#"
+            <article>
+                <p>She told us that "every painting begins as a mistake I decide to keep," and laughed.</p>
+                <aside><blockquote>Every painting begins as a mistake I decide to keep.</blockquote></aside>
+                <p>Her process involves many layers of thin glaze applied over weeks.</p>
+                <div class="pull-quote">Many layers of thin glaze, applied over weeks</div>
+                <div class="pullquote">A quote that appears nowhere else in the story.</div>
+            </article>
+        "#;
+
+        let (cleaned, quotes) = extract_pull_quotes(html);
+        assert_eq!(
+            quotes,
+            vec![
+                "Every painting begins as a mistake I decide to keep.".to_string(),
+                "Many layers of thin glaze, applied over weeks".to_string(),
+            ]
+        );
+        assert!(!cleaned.contains("<aside>"));
+        assert!(!cleaned.contains("pull-quote"));
+        assert!(cleaned.contains("appears nowhere else"));
+        assert!(cleaned.contains("She told us"));
+    }
+
+    #[test]
+    fn test_find_summary_block_by_heading() {
+        let story = "<p>The council voted on Tuesday to approve a new transit plan, which will add bus lanes and extend light rail service.</p>".repeat(3);
+        let html = format!(
+            "<div><h3>Key points:</h3><ul><li>Bus lanes open next spring.</li><li>Fares stay the same.</li></ul>{story}</div>"
+        );
+        assert_eq!(
+            find_summary_block(&html).as_deref(),
+            Some("<h3>Key points:</h3><ul><li>Bus lanes open next spring.</li><li>Fares stay the same.</li></ul>")
+        );
+
+        // A summary class on the whole story is not a summary box
+        let html = format!(r#"<div class="entry-summary">{story}</div>"#);
+        assert_eq!(find_summary_block(&html), None);
+    }
+
+    #[test]
+    fn test_collapse_brs() {
+        let html = "<div><p>First line<br> <br>\n<br><br>Second line<br>Third line</p><pre>a<br><br><br>b</pre></div>";
+        assert_eq!(
+            collapse_brs(html, 1),
+            "<div><p>First line<br> \nSecond line<br>Third line</p><pre>a<br><br><br>b</pre></div>"
+        );
+        assert_eq!(collapse_brs(html, 2).matches("<br>").count(), 6);
+    }
+
+    #[test]
+    fn test_strip_ruby_annotations() {
+        let html = "<p><ruby>漢字<rp>(</rp><rt>かんじ</rt><rp>)</rp></ruby>と<ruby><rb>東</rb><rt>とう</rt>京<rt>きょう</rt></ruby></p>";
+        assert_eq!(strip_ruby_annotations(html), "<p>漢字と東京</p>");
+    }
+
+    #[test]
+    fn test_trim_empty_table_edge_columns() {
+        let html = r#"<table>
+            <thead><tr><th></th><th>City</th><th>Riders</th><th>Change</th><th>&nbsp;</th><th></th></tr></thead>
+            <tbody>
+                <tr><td></td><td>Leeds</td><td>1,200</td><td></td><td> </td><td></td></tr>
+                <tr><td></td><td>York</td><td>800</td><td>+4%</td><td></td><td></td></tr>
+                <tr><td></td><td>Hull</td><td>650</td><td>-2%</td><td></td><td></td></tr>
+                <tr><td> </td><td></td><td></td><td></td><td></td><td></td></tr>
+            </tbody>
+        </table>"#;
+
+        let cleaned = remove_conditionally(
+            html,
+            &ReadabilityOptions::default(),
+            &mut ParseStats::default(),
+        );
+        assert!(cleaned.contains("<tr><th>City</th><th>Riders</th><th>Change</th></tr>"));
+        // The empty cell in the middle of the data is kept
+        assert!(cleaned.contains("<tr><td>Leeds</td><td>1,200</td><td></td></tr>"));
+        assert!(cleaned.contains("<tr><td>Hull</td><td>650</td><td>-2%</td></tr>"));
+        assert_eq!(cleaned.matches("<tr>").count(), 4);
+    }
+
+    #[test]
+    fn test_link_rel_survives_cleaning() {
+        let html = r#"
+            <article>
+                <p role="note">Our partner offers a discount on
+                    <a href="https://shop.example.com" rel="nofollow noopener sponsored" class="aff" style="color: red" data-track="buy" onclick="track()" aria-label="Shop">the new headphones</a>
+                    for readers who sign up this week, along with free shipping on every order.</p>
+            </article>
+        "#;
+        let link_attrs = |html: &str| {
+            let fragment = Html::parse_fragment(html);
+            let link = fragment
+                .select(&Selector::parse("a").unwrap())
+                .next()
+                .unwrap();
+            let mut attrs: Vec<(String, String)> = link
+                .value()
+                .attrs()
+                .map(|(name, value)| (name.to_string(), value.to_string()))
+                .collect();
+            attrs.sort();
+            attrs
+        };
+        let rel = ("rel".to_string(), "nofollow noopener sponsored".to_string());
+
+        // Stripping aria attributes leaves rel as it was
+        let options = ReadabilityOptions::builder().preserve_aria(false).build();
+        let cleaned =
+            clean_article_content(html, None, &options, &mut ParseStats::default()).unwrap();
+        let attrs = link_attrs(&cleaned);
+        assert!(attrs.contains(&rel), "{attrs:?}");
+        assert!(attrs.iter().all(|(name, _)| name != "aria-label"));
+
+        // So does stripping presentational attributes for EPUB
+        let (epub, _) = prepare_epub_content(html);
+        let names: Vec<String> = link_attrs(&epub)
+            .into_iter()
+            .map(|(name, _)| name)
+            .collect();
+        assert_eq!(names, ["aria-label", "href", "rel"]);
+        assert!(link_attrs(&epub).contains(&rel));
+    }
+
+    #[test]
+    fn test_audio_exemption_covers_only_the_player_block() {
+        let player = r#"<div class="player"><audio src="https://example.com/ep12.mp3" controls></audio></div>"#;
+        let cleaned = remove_conditionally(
+            &format!("<article>{player}</article>"),
+            &ReadabilityOptions::default(),
+            &mut ParseStats::default(),
+        );
+        assert!(cleaned.contains("<audio"));
+
+        // A link list around the player is still removed
+        let html = format!(
+            r#"<article><div class="links"><p><a href="/a">Episode one</a> <a href="/b">Episode two</a> <a href="/c">Episode three</a></p>{player}</div></article>"#
+        );
+        let cleaned = remove_conditionally(
+            &html,
+            &ReadabilityOptions::default(),
+            &mut ParseStats::default(),
+        );
+        assert!(!cleaned.contains("Episode one"));
+    }
+
+    #[test]
+    fn test_preserve_aria_on_figure() {
+        let html = r#"
+            <article>
+                <figure aria-label="Reef photo" role="group" style="margin: 0">
+                    <img src="https://example.com/reef.jpg" alt="A reef">
+                    <figcaption>A reef near the island, photographed during the survey.</figcaption>
+                </figure>
+            </article>
+        "#;
+
+        let cleaned = remove_conditionally(
+            html,
+            &ReadabilityOptions::default(),
+            &mut ParseStats::default(),
+        );
+        assert!(cleaned.contains(r#"aria-label="Reef photo""#));
+        assert!(cleaned.contains(r#"role="group""#));
+
+        let options = ReadabilityOptions::builder().preserve_aria(false).build();
+        let cleaned =
+            clean_article_content(html, None, &options, &mut ParseStats::default()).unwrap();
+        assert!(!cleaned.contains("aria-label"));
+        assert!(!cleaned.contains("role="));
+    }
+
+    #[test]
+    fn test_remove_leading_breadcrumb() {
+        let html = r#"<div><p><a href="/">Home</a> › <a href="/news">News</a> › <a href="/news/world">World</a></p><p>The harbour reopened on Tuesday after the storm.</p></div>"#;
+        let cleaned = remove_leading_breadcrumb(html);
+        assert!(!cleaned.contains("Home"));
+        assert!(cleaned.contains("The harbour reopened"));
+
+        let html = r#"<div><ul class="trail"><li><a href="/">Home</a> /</li><li><a href="/recipes">Recipes</a> /</li><li>Tomato soup</li></ul><p>Soup weather is here.</p></div>"#;
+        let cleaned = remove_leading_breadcrumb(html);
+        assert!(!cleaned.contains("Recipes"));
+        assert!(cleaned.contains("Soup weather"));
+
+        // A first paragraph with a couple of links is prose, not a trail
+        let html = r#"<div><p>According to <a href="/a">the report</a> and <a href="/b">the survey</a>, prices rose 3/4 of a point.</p><p>More text.</p></div>"#;
+        assert_eq!(remove_leading_breadcrumb(html), html);
+    }
+
+    #[test]
+    fn test_caption_to_alt() {
+        let html = r#"<article><figure><img src="/reef.jpg"><figcaption>A reef near the  island.</figcaption></figure><figure><img src="/boat.jpg" alt="Boat"><figcaption>The survey boat.</figcaption></figure><p><a href="/map.png"><img src="/map.png"></a></p><p class="photo-caption">Map of the survey area.</p></article>"#;
+
+        let cleaned = Html::parse_fragment(&caption_to_alt(html));
+        let alt = |src: &str| {
+            let selector = Selector::parse(&format!(r#"img[src="{src}"]"#)).unwrap();
+            let image = cleaned.select(&selector).next().unwrap();
+            image.value().attr("alt").map(str::to_string)
+        };
+        assert_eq!(alt("/reef.jpg").as_deref(), Some("A reef near the island."));
+        assert_eq!(alt("/boat.jpg").as_deref(), Some("Boat"));
+        assert_eq!(alt("/map.png").as_deref(), Some("Map of the survey area."));
+
+        // A caption further down, or one belonging to a nested figure, is not used
+        let html = r#"<article><p><img src="/a.jpg"></p><p>Unrelated text.</p><p class="caption">Not for a.</p><figure><img src="/b.jpg"><figure><img src="/c.jpg"><figcaption>Only c.</figcaption></figure></figure></article>"#;
+        let cleaned = Html::parse_fragment(&caption_to_alt(html));
+        let alt = |src: &str| {
+            let selector = Selector::parse(&format!(r#"img[src="{src}"]"#)).unwrap();
+            let image = cleaned.select(&selector).next().unwrap();
+            image.value().attr("alt").map(str::to_string)
+        };
+        assert_eq!(alt("/a.jpg"), None);
+        assert_eq!(alt("/b.jpg"), None);
+        assert_eq!(alt("/c.jpg").as_deref(), Some("Only c."));
+    }
+
+    #[test]
+    fn test_remove_byline_element() {
+        let html = r#"<article><h1>Council approves budget</h1><p class="byline">By <a href="/jane">Jane Doe</a></p><p>Jane Doe said the budget was fair.</p></article>"#;
+
+        let cleaned = remove_byline_element(html, "Jane Doe");
+        assert!(!cleaned.contains("byline"));
+        assert!(cleaned.contains("Jane Doe said"));
+
+        // Unmarked elements and non-matching bylines are left alone
+        assert!(remove_byline_element(html, "John Smith").contains("byline"));
+        let unmarked = "<article><p>By Jane Doe</p></article>";
+        assert!(remove_byline_element(unmarked, "Jane Doe").contains("By Jane Doe"));
+    }
+
+    #[test]
+    fn test_prep_document_unwraps_presentational_tags() {
+        let html = r#"<body><center id="intro" dir="rtl"><p>Centered <font face="Arial > Helvetica" size="3">text</font> with <BIG>big</BIG> and <u>underlined</u> words.</p></center></body>"#;
+
+        let prepped = Html::parse_document(&prep_document(html));
+        let div = prepped
+            .select(&Selector::parse("body > div").unwrap())
+            .next()
+            .unwrap();
+        assert_eq!(div.value().attr("id"), Some("intro"));
+        assert_eq!(div.value().attr("dir"), Some("rtl"));
+        assert_eq!(
+            div.inner_html(),
+            "<p>Centered text with big and <u>underlined</u> words.</p>"
+        );
+    }
 }
@@ -37,6 +37,10 @@ pub struct RegexPatterns {
     pub json_ld_article_types: Regex,
     pub ad_words: Regex,
     pub loading_words: Regex,
+    pub sponsored: Regex,
+    pub sponsored_label: Regex,
+    pub summary_block: Regex,
+    pub summary_heading: Regex,
 }
 
 impl RegexPatterns {
@@ -78,6 +82,18 @@ impl RegexPatterns {
             loading_words: Regex::new(
                 r"(?iu)^((loading|正在加载|Загрузка|chargement|cargando)(…|\.\.\.)?)$"
             ).unwrap(),
+            sponsored: Regex::new(
+                r"(?i)sponsor|promoted|paid-?(post|partner|content)|native-?ad|advertorial|partner-?content|branded-?content"
+            ).unwrap(),
+            sponsored_label: Regex::new(
+                r"(?iu)^(sponsored|promoted|paid (partnership|post|content)|advertorial|partner content|branded content)(\s+(by|with)\s.*)?\s*:?$"
+            ).unwrap(),
+            summary_block: Regex::new(
+                r"(?i)(^|[-_\s])(summary|key-?points|key-?takeaways|takeaways|tl-?dr)([-_\s]|$)"
+            ).unwrap(),
+            summary_heading: Regex::new(
+                r"(?iu)^(key (points|takeaways|facts)|summary|tl;?\s?dr|in (brief|short)|at a glance)\s*:?$"
+            ).unwrap(),
         }
     }
 }
@@ -5,21 +5,44 @@ use crate::error::Result;
 use crate::options::ReadabilityOptions;
 use crate::{dom_utils, scoring};
 use scraper::{ElementRef, Html, Selector};
-use std::collections::HashMap;
+use std::collections::BTreeMap;
 
 /// Represents an extraction attempt
 #[derive(Debug, Clone)]
 struct Attempt {
-    content: String,
+    content: ExtractedContent,
     text_length: usize,
 }
 
+/// Content chosen by the extractor along with the signals behind the choice
+#[derive(Debug, Clone)]
+pub struct ExtractedContent {
+    /// Aggregated HTML of the top candidate and its included siblings
+    pub html: String,
+    /// Final score of the top candidate and the best score among candidates unrelated
+    /// to it (`0.0` if none), or `None` when the content was taken as is, from a
+    /// content selector or a JSON-LD body, without scoring
+    pub scores: Option<(f64, f64)>,
+    /// Whether the strict first attempt fell short and a looser one was used
+    pub used_fallback: bool,
+    /// Accessible name of the content region, from the top candidate or its nearest
+    /// labelled ancestor
+    pub region_label: Option<String>,
+}
+
 /// Main content extraction algorithm with retry logic
 ///
 /// Implements Mozilla's Readability algorithm with adaptive flag removal.
 /// If extraction fails with strict settings, retries with progressively
 /// looser criteria until content is found or all options are exhausted.
-pub fn grab_article(document: &Html, options: &ReadabilityOptions) -> Result<Option<String>> {
+pub fn grab_article(
+    document: &Html,
+    options: &ReadabilityOptions,
+) -> Result<Option<ExtractedContent>> {
+    if let Some(content) = extract_with_content_selectors(document, options) {
+        return Ok(Some(content));
+    }
+
     let mut attempts = Vec::new();
     let mut flags =
         ParseFlags::STRIP_UNLIKELYS | ParseFlags::WEIGHT_CLASSES | ParseFlags::CLEAN_CONDITIONALLY;
@@ -29,8 +52,9 @@ pub fn grab_article(document: &Html, options: &ReadabilityOptions) -> Result<Opt
     for attempt_num in 0..4 {
         let attempt_result = try_extract_with_flags(document, options, flags)?;
 
-        if let Some(content) = attempt_result {
-            let text_length = extract_text_length(&content);
+        if let Some(mut content) = attempt_result {
+            let text_length = extract_text_length(&content.html);
+            content.used_fallback = attempt_num > 0;
 
             // Check if we have enough content
             if text_length >= options.char_threshold {
@@ -55,21 +79,57 @@ pub fn grab_article(document: &Html, options: &ReadabilityOptions) -> Result<Opt
 
     // No successful extraction with threshold, return longest attempt
     if !attempts.is_empty() {
-        attempts.sort_by(|a, b| b.text_length.cmp(&a.text_length));
+        attempts.sort_by_key(|attempt| std::cmp::Reverse(attempt.text_length));
         if attempts[0].text_length > 0 {
-            return Ok(Some(attempts[0].content.clone()));
+            let mut content = attempts.swap_remove(0).content;
+            content.used_fallback = true;
+            return Ok(Some(content));
         }
     }
 
     Ok(None)
 }
 
+/// Use the first configured content selector that matches a long enough element
+///
+/// The match is trusted outright, so it is reported with a clear score margin.
+fn extract_with_content_selectors(
+    document: &Html,
+    options: &ReadabilityOptions,
+) -> Option<ExtractedContent> {
+    for selector in &options.content_selectors {
+        let Ok(parsed) = Selector::parse(selector) else {
+            if options.debug {
+                eprintln!("Skipping invalid content selector: {selector}");
+            }
+            continue;
+        };
+
+        let Some(element) = document.select(&parsed).find(|element| {
+            dom_utils::get_inner_text(*element, true).len() >= options.char_threshold
+        }) else {
+            continue;
+        };
+
+        let html =
+            crate::cleaner::replace_brs(&element_to_html(element, options.annotate_original_tags));
+        return Some(ExtractedContent {
+            html,
+            scores: None,
+            used_fallback: false,
+            region_label: region_label(document, element),
+        });
+    }
+
+    None
+}
+
 /// Try to extract article content with specific flags
 fn try_extract_with_flags(
     document: &Html,
     options: &ReadabilityOptions,
     flags: ParseFlags,
-) -> Result<Option<String>> {
+) -> Result<Option<ExtractedContent>> {
     let candidates = find_candidates(document, options, flags)?;
     if candidates.is_empty() {
         return Ok(None);
@@ -79,13 +139,42 @@ fn try_extract_with_flags(
     apply_link_density_penalty(document, &mut scored_candidates);
 
     if let Some(best) = find_best_candidate(document, &scored_candidates, options) {
-        let content = extract_article_content(document, best, &scored_candidates, options)?;
-        return Ok(Some(content));
+        let top_score = scored_candidates.get(&best).copied().unwrap_or(0.0);
+        if top_score < options.min_candidate_score {
+            if options.debug {
+                eprintln!(
+                    "Top candidate score {top_score:.1} is below the floor of {:.1}",
+                    options.min_candidate_score
+                );
+            }
+            return Ok(None);
+        }
+        let runner_up_score = find_runner_up_score(document, &best, &scored_candidates);
+        let region_label =
+            find_element_by_id(document, &best).and_then(|element| region_label(document, element));
+        let html = extract_article_content(document, best, &scored_candidates, options)?;
+        return Ok(Some(ExtractedContent {
+            html,
+            scores: Some((top_score, runner_up_score)),
+            used_fallback: false,
+            region_label,
+        }));
     }
 
     Ok(None)
 }
 
+/// Accessible name of the content region around `element`
+///
+/// Checks the element, then its ancestors up to `<body>`, and uses the first
+/// `aria-labelledby` or `aria-label` found.
+fn region_label(document: &Html, element: ElementRef) -> Option<String> {
+    std::iter::once(element)
+        .chain(element.ancestors().filter_map(ElementRef::wrap))
+        .take_while(|node| !matches!(node.value().name(), "body" | "html"))
+        .find_map(|node| dom_utils::get_accessible_name(document, node))
+}
+
 /// Extract plain text length from HTML content
 fn extract_text_length(html: &str) -> usize {
     let doc = Html::parse_fragment(html);
@@ -96,27 +185,20 @@ fn extract_text_length(html: &str) -> usize {
 /// Find all potential content candidates in the document
 fn find_candidates<'a>(
     document: &'a Html,
-    _options: &ReadabilityOptions,
+    options: &ReadabilityOptions,
     flags: ParseFlags,
 ) -> Result<Vec<ElementRef<'a>>> {
     let mut candidates = Vec::new();
 
-    let p_selector = Selector::parse("p").unwrap();
-    for p in document.select(&p_selector) {
+    for p in scoring::select_paragraph_like(document, options) {
         if !dom_utils::is_probably_visible(p) {
             continue;
         }
 
-        if flags.contains(ParseFlags::STRIP_UNLIKELYS) {
-            let class = p.value().attr("class").unwrap_or("");
-            let id = p.value().attr("id").unwrap_or("");
-            let match_string = format!("{class} {id}");
-
-            if REGEXPS.unlikely_candidates.is_match(&match_string)
-                && !REGEXPS.ok_maybe_its_a_candidate.is_match(&match_string)
-            {
-                continue;
-            }
+        if flags.contains(ParseFlags::STRIP_UNLIKELYS)
+            && (scoring::is_unlikely_candidate(p) || in_unlikely_template(p))
+        {
+            continue;
         }
 
         let text = dom_utils::get_inner_text(p, false);
@@ -134,16 +216,10 @@ fn find_candidates<'a>(
                 continue;
             }
 
-            if flags.contains(ParseFlags::STRIP_UNLIKELYS) {
-                let class = elem.value().attr("class").unwrap_or("");
-                let id = elem.value().attr("id").unwrap_or("");
-                let match_string = format!("{class} {id}");
-
-                if REGEXPS.unlikely_candidates.is_match(&match_string)
-                    && !REGEXPS.ok_maybe_its_a_candidate.is_match(&match_string)
-                {
-                    continue;
-                }
+            if flags.contains(ParseFlags::STRIP_UNLIKELYS)
+                && (scoring::is_unlikely_candidate(elem) || in_unlikely_template(elem))
+            {
+                continue;
             }
 
             let text = dom_utils::get_inner_text(elem, false);
@@ -156,18 +232,42 @@ fn find_candidates<'a>(
     Ok(candidates)
 }
 
+/// Whether the element sits in `<template>` content inside an unlikely container
+///
+/// The parser expands template content in place, so a deferred comment widget is
+/// pruned the same way as one rendered directly: when the template or any of its
+/// ancestors looks like comments, recirculation or similar.
+fn in_unlikely_template(element: ElementRef) -> bool {
+    let mut ancestors = element.ancestors().filter_map(ElementRef::wrap);
+    if !ancestors.any(|ancestor| ancestor.value().name() == "template") {
+        return false;
+    }
+
+    element
+        .ancestors()
+        .filter_map(ElementRef::wrap)
+        .any(scoring::is_unlikely_candidate)
+}
+
 /// Score all candidates and their ancestors
+///
+/// Scores are kept in a `BTreeMap` so that candidates with equal scores are ranked the
+/// same way on every parse.
 fn score_candidates<'a>(
     _document: &'a Html,
     candidates: Vec<ElementRef<'a>>,
     options: &ReadabilityOptions,
     flags: ParseFlags,
-) -> HashMap<String, f64> {
-    let mut scores: HashMap<String, f64> = HashMap::new();
+) -> BTreeMap<String, f64> {
+    let mut scores: BTreeMap<String, f64> = BTreeMap::new();
+    let sponsored_factor = 1.0 - options.sponsored_penalty.clamp(0.0, 1.0);
 
     for candidate in candidates {
-        let content_score =
+        let mut content_score =
             scoring::calculate_content_score(candidate, options.link_density_modifier);
+        if sponsored_factor < 1.0 && in_sponsored_block(candidate) {
+            content_score *= sponsored_factor;
+        }
 
         if content_score == 0.0 {
             continue;
@@ -208,8 +308,16 @@ fn score_candidates<'a>(
     scores
 }
 
+/// Whether the element or an ancestor below `<body>` looks like a sponsored block
+fn in_sponsored_block(element: ElementRef) -> bool {
+    std::iter::once(element)
+        .chain(element.ancestors().filter_map(ElementRef::wrap))
+        .take_while(|node| !matches!(node.value().name(), "body" | "html"))
+        .any(scoring::looks_sponsored)
+}
+
 /// Adjust candidate scores based on their actual link density.
-fn apply_link_density_penalty(document: &Html, scores: &mut HashMap<String, f64>) {
+fn apply_link_density_penalty(document: &Html, scores: &mut BTreeMap<String, f64>) {
     for (element_id, score) in scores.iter_mut() {
         if let Some(element) = find_element_by_id(document, element_id) {
             let penalty = (1.0 - dom_utils::get_link_density(element)).max(0.0);
@@ -221,7 +329,7 @@ fn apply_link_density_penalty(document: &Html, scores: &mut HashMap<String, f64>
 /// Find the best candidate based on scores, promoting parents when needed.
 fn find_best_candidate(
     document: &Html,
-    scores: &HashMap<String, f64>,
+    scores: &BTreeMap<String, f64>,
     options: &ReadabilityOptions,
 ) -> Option<String> {
     let mut sorted_scores: Vec<_> = scores.iter().collect();
@@ -282,6 +390,31 @@ fn find_best_candidate(
     Some(best_id)
 }
 
+/// Best score among candidates that are neither the chosen node, its ancestors nor its
+/// descendants, since those inherit score from the same paragraphs.
+fn find_runner_up_score(document: &Html, best_id: &str, scores: &BTreeMap<String, f64>) -> f64 {
+    let Some(best) = find_element_by_id(document, best_id) else {
+        return 0.0;
+    };
+
+    let mut related: std::collections::HashSet<String> = best
+        .descendants()
+        .filter_map(ElementRef::wrap)
+        .map(|element| get_element_id(&element))
+        .collect();
+    related.extend(
+        dom_utils::get_node_ancestors(best, None)
+            .iter()
+            .map(get_element_id),
+    );
+
+    scores
+        .iter()
+        .filter(|(id, _)| !related.contains(*id))
+        .map(|(_, score)| *score)
+        .fold(0.0, f64::max)
+}
+
 /// Promote parent nodes when the current candidate is the only child, mirroring Mozilla's logic.
 fn promote_single_child_parents(document: &Html, best_id: &str) -> Option<String> {
     let mut promoted_id = None;
@@ -375,7 +508,7 @@ fn promote_high_scoring_parents(
     document: &Html,
     best_id: &str,
     best_score: f64,
-    scores: &HashMap<String, f64>,
+    scores: &BTreeMap<String, f64>,
 ) -> Option<String> {
     let mut current = find_element_by_id(document, best_id)?;
     let mut last_score = best_score;
@@ -435,7 +568,7 @@ fn promote_high_scoring_parents(
 fn promote_dense_wrapper_child(
     document: &Html,
     best_id: &str,
-    scores: &HashMap<String, f64>,
+    scores: &BTreeMap<String, f64>,
     sorted_scores: &[(&String, &f64)],
 ) -> Option<String> {
     let best_elem = find_element_by_id(document, best_id)?;
@@ -626,11 +759,13 @@ fn promote_semantic_descendant(
 ///    - Score >= 20% of the best candidate's score, OR
 ///    - Are good paragraphs (low link density, decent text length)
 /// 4. Aggregate all content together
+///
+/// With `disable_sibling_aggregation` set, only the best candidate is returned.
 fn extract_article_content(
     document: &Html,
     best_candidate_id: String,
-    all_scores: &HashMap<String, f64>,
-    _options: &ReadabilityOptions,
+    all_scores: &BTreeMap<String, f64>,
+    options: &ReadabilityOptions,
 ) -> Result<String> {
     let Some(best_candidate) = find_element_by_id(document, &best_candidate_id) else {
         return Ok(String::new());
@@ -645,9 +780,12 @@ fn extract_article_content(
 
     let sibling_score_threshold = (best_score * 0.2).max(10.0);
     let mut article_content = Vec::new();
-    let Some(parent) = best_candidate.parent() else {
-        // No parent, just return the best candidate
-        let html = element_to_html(best_candidate);
+    let parent = best_candidate
+        .parent()
+        .filter(|_| !options.disable_sibling_aggregation);
+    let Some(parent) = parent else {
+        // No parent, or aggregation disabled: just return the best candidate
+        let html = element_to_html(best_candidate, options.annotate_original_tags);
         let html = crate::cleaner::replace_brs(&html);
         return Ok(html);
     };
@@ -662,6 +800,9 @@ fn extract_article_content(
 
         let should_include = if is_best_candidate {
             true
+        } else if options.sponsored_penalty > 0.0 && scoring::looks_sponsored(sibling) {
+            // Sponsored blocks next to the article are never appended to it
+            false
         } else {
             let sibling_score = all_scores.get(&sibling_id).copied().unwrap_or(0.0);
             let class_bonus = if !best_candidate_class.is_empty() {
@@ -679,7 +820,7 @@ fn extract_article_content(
 
             let weighted_sibling_score = sibling_score + class_bonus;
             if weighted_sibling_score >= sibling_score_threshold
-                || is_good_sibling_paragraph(sibling)
+                || is_good_sibling_paragraph(sibling, options)
             {
                 true
             } else {
@@ -688,7 +829,7 @@ fn extract_article_content(
         };
 
         if should_include {
-            let mut sibling_html = element_to_html(sibling);
+            let mut sibling_html = element_to_html(sibling, options.annotate_original_tags);
             sibling_html = crate::cleaner::replace_brs(&sibling_html);
 
             if !sibling_html.trim().is_empty() {
@@ -703,13 +844,12 @@ fn extract_article_content(
 /// Check if a sibling element is a "good paragraph" worth including
 ///
 /// A good paragraph is one that:
-/// - Is a P tag (or looks like a paragraph)
+/// - Is a P tag, or one of the `paragraph_like_tags`
 /// - Has reasonable text length (> 80 chars)
 /// - Has low link density (< 33%)
 /// - Looks like actual content, not navigation
-fn is_good_sibling_paragraph(element: ElementRef) -> bool {
-    let tag_name = element.value().name();
-    if tag_name != "p" {
+fn is_good_sibling_paragraph(element: ElementRef, options: &ReadabilityOptions) -> bool {
+    if !scoring::is_paragraph_like(element, options) {
         return false;
     }
 
@@ -719,13 +859,7 @@ fn is_good_sibling_paragraph(element: ElementRef) -> bool {
         return false;
     }
 
-    let class = element.value().attr("class").unwrap_or("");
-    let id = element.value().attr("id").unwrap_or("");
-    let match_string = format!("{class} {id}");
-
-    if REGEXPS.unlikely_candidates.is_match(&match_string)
-        && !REGEXPS.ok_maybe_its_a_candidate.is_match(&match_string)
-    {
+    if scoring::is_unlikely_candidate(element) {
         return false;
     }
 
@@ -897,7 +1031,10 @@ fn is_viable_best_candidate(element: ElementRef, score: f64) -> bool {
 ///
 /// Additionally, this function implements DIV→P transformation: DIVs without
 /// block-level children are converted to P tags to match Mozilla's behavior.
-fn element_to_html(element: ElementRef) -> String {
+///
+/// With `annotate_tags`, DIVs converted to P get `data-orig-tag="div"`; no other
+/// element is annotated.
+fn element_to_html(element: ElementRef, annotate_tags: bool) -> String {
     use scraper::node::Node;
     if !dom_utils::is_probably_visible(element) {
         return String::new();
@@ -918,6 +1055,9 @@ fn element_to_html(element: ElementRef) -> String {
     for (name, value) in elem_data.attrs.iter() {
         html.push_str(&format!(" {}=\"{}\"", name.local, value));
     }
+    if annotate_tags && tag_name != original_tag_name {
+        html.push_str(&format!(" data-orig-tag=\"{original_tag_name}\""));
+    }
 
     if is_void_element(tag_name) {
         html.push_str(" />");
@@ -930,7 +1070,7 @@ fn element_to_html(element: ElementRef) -> String {
         match child.value() {
             Node::Element(_) => {
                 if let Some(child_elem) = ElementRef::wrap(child) {
-                    let child_html = element_to_html(child_elem);
+                    let child_html = element_to_html(child_elem, annotate_tags);
                     if !child_html.is_empty() {
                         html.push_str(&child_html);
                     }
@@ -966,6 +1106,7 @@ fn find_element_by_id<'a>(document: &'a Html, id: &str) -> Option<ElementRef<'a>
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::readability::tests::article_paragraphs;
 
     #[test]
     fn test_grab_article_simple() {
@@ -991,10 +1132,36 @@ mod tests {
         let content = result.unwrap();
         assert!(content.is_some());
 
-        let content_html = content.unwrap();
+        let content_html = content.unwrap().html;
         assert!(content_html.contains("first paragraph"));
     }
 
+    #[test]
+    fn test_grab_article_inside_lazy_rendered_container() {
+        let html = r#"
+            <html>
+                <body>
+                    <div class="promo">Subscribe for more stories like this one.</div>
+                    <main style="content-visibility: auto; contain-intrinsic-size: auto 800px">
+                        <article>
+                            <p>The first paragraph of the story explains what happened at the harbour this morning.</p>
+                            <img src="harbour.jpg" loading="lazy" decoding="async" alt="The harbour">
+                            <p>The second paragraph adds detail from witnesses who watched the ships arrive at dawn.</p>
+                            <p>A third paragraph closes the story with comments from the port authority spokesperson.</p>
+                        </article>
+                    </main>
+                </body>
+            </html>
+        "#;
+
+        let document = Html::parse_document(html);
+        let options = ReadabilityOptions::builder().char_threshold(100).build();
+
+        let content = grab_article(&document, &options).unwrap().unwrap().html;
+        assert!(content.contains("witnesses who watched"));
+        assert!(content.contains("harbour.jpg"));
+    }
+
     #[test]
     fn test_grab_article_short_content() {
         let html = r#"
@@ -1037,10 +1204,10 @@ mod tests {
         let flags = ParseFlags::WEIGHT_CLASSES | ParseFlags::CLEAN_CONDITIONALLY;
 
         let candidates = find_candidates(&document, &options, flags).unwrap();
-        assert!(candidates.len() > 0);
+        assert!(!candidates.is_empty());
 
         let scores = score_candidates(&document, candidates, &options, flags);
-        assert!(scores.len() > 0);
+        assert!(!scores.is_empty());
     }
 
     #[test]
@@ -1071,7 +1238,7 @@ mod tests {
         let content = result.unwrap();
         assert!(content.is_some());
 
-        let content_html = content.unwrap();
+        let content_html = content.unwrap().html;
 
         assert!(content_html.contains("first paragraph"));
         assert!(content_html.contains("second paragraph"));
@@ -1079,4 +1246,164 @@ mod tests {
         // The fourth paragraph might not be included depending on scoring,
         // but we should have at least the first three
     }
+
+    #[test]
+    fn test_grab_article_reports_runner_up_score() {
+        let html = r#"
+            <html>
+                <body>
+                    <div id="story">
+                        <p>The council approved the new budget on Tuesday, after weeks of debate, several amendments, and a long public hearing.</p>
+                        <p>Funding for parks, libraries, and road repairs rises slightly, while the transit subsidy stays flat for another year.</p>
+                        <p>Officials said the plan balances competing needs, keeps reserves intact, and avoids raising property taxes this year.</p>
+                    </div>
+                    <div id="related">
+                        <p>In other news, the library will extend its weekend opening hours, starting next month.</p>
+                    </div>
+                </body>
+            </html>
+        "#;
+
+        let document = Html::parse_document(html);
+        let options = ReadabilityOptions::builder().char_threshold(100).build();
+
+        let content = grab_article(&document, &options).unwrap().unwrap();
+        assert!(!content.used_fallback);
+        let (top_score, runner_up_score) = content.scores.unwrap();
+        assert!(top_score > 0.0);
+        assert!(runner_up_score > 0.0);
+        assert!(runner_up_score < top_score);
+    }
+
+    #[test]
+    fn test_grab_article_uses_content_selector() {
+        let html = r#"
+            <html>
+                <body>
+                    <div class="story">
+                        <p>A long teaser paragraph that scoring might prefer, with commas, clauses, and plenty of words to read.</p>
+                        <p>Another teaser paragraph, also long enough, which keeps the scorer interested in this container.</p>
+                    </div>
+                    <div id="article-body">
+                        <span>The actual article text lives here, in a container the site always uses for its stories.</span>
+                    </div>
+                </body>
+            </html>
+        "#;
+
+        let document = Html::parse_document(html);
+        let options = ReadabilityOptions::builder()
+            .char_threshold(50)
+            .content_selectors(vec!["main >".to_string(), "#article-body".to_string()])
+            .build();
+
+        let content = grab_article(&document, &options).unwrap().unwrap();
+        assert!(content.html.contains("actual article text"));
+        assert!(!content.html.contains("teaser"));
+        assert!(content.scores.is_none());
+
+        // Matches that are too short fall back to scoring
+        let options = ReadabilityOptions::builder()
+            .char_threshold(500)
+            .content_selectors(vec!["#article-body".to_string()])
+            .build();
+        let content = grab_article(&document, &options).unwrap().unwrap();
+        assert!(content.html.contains("teaser"));
+    }
+
+    #[test]
+    fn test_min_candidate_score_rejects_weak_page() {
+        let html = r#"
+            <html><body>
+                <div class="listing">
+                    <p>Call us today for a free quote on roof repairs and gutter cleaning</p>
+                    <p>Open Monday to Friday from nine until five, weekends by appointment</p>
+                </div>
+            </body></html>
+        "#;
+        let document = Html::parse_document(html);
+
+        let options = ReadabilityOptions::builder().char_threshold(20).build();
+        let content = grab_article(&document, &options).unwrap().unwrap();
+        assert!(content.scores.unwrap().0 < 40.0);
+
+        let options = ReadabilityOptions::builder()
+            .char_threshold(20)
+            .min_candidate_score(40.0)
+            .build();
+        assert!(grab_article(&document, &options).unwrap().is_none());
+    }
+
+    #[test]
+    fn test_template_comments_do_not_outscore_article() {
+        let article = article_paragraphs(5);
+        let comment = "<p>Reader comment: I completely disagree with the premise of this piece, and frankly, the author ignores decades of research, several studies, and my experience.</p>".repeat(12);
+        let html = format!(
+            r#"<html><body>
+                <article class="story">{article}</article>
+                <div id="comments-root"><template><div class="comment-list">{comment}</div></template></div>
+            </body></html>"#
+        );
+
+        let document = Html::parse_document(&html);
+        let content = grab_article(&document, &ReadabilityOptions::default())
+            .unwrap()
+            .unwrap();
+        assert!(content.html.contains("council voted"));
+        assert!(!content.html.contains("Reader comment"));
+    }
+
+    #[test]
+    fn test_disable_sibling_aggregation() {
+        let html = r#"
+            <html>
+                <body>
+                    <div class="answer" id="accepted">
+                        <p>The accepted answer explains the problem in detail, with examples, caveats, and a link to the documentation.</p>
+                        <p>It goes on to show a working fix, step by step, and notes which versions of the library are affected.</p>
+                        <p>Finally, it suggests a test to catch the regression, and thanks the asker for the clear reproduction.</p>
+                        <p>A short appendix lists related issues, the commits that fixed them, and the release notes to read.</p>
+                        <p>The closing paragraph recaps the fix, repeats the caveats, and links back to the original question.</p>
+                    </div>
+                    <p>A neighbouring reply says thanks, this worked for me after upgrading, and asks a follow-up question.</p>
+                </body>
+            </html>
+        "#;
+        let document = Html::parse_document(html);
+
+        let options = ReadabilityOptions::builder().char_threshold(100).build();
+        let merged = grab_article(&document, &options).unwrap().unwrap();
+        assert!(merged.html.contains("accepted answer"));
+        assert!(merged.html.contains("neighbouring reply"));
+
+        let options = ReadabilityOptions::builder()
+            .char_threshold(100)
+            .disable_sibling_aggregation(true)
+            .build();
+        let alone = grab_article(&document, &options).unwrap().unwrap();
+        assert!(alone.html.contains("accepted answer"));
+        assert!(!alone.html.contains("neighbouring reply"));
+    }
+
+    #[test]
+    fn test_sponsored_block_loses_to_editorial() {
+        let editorial = article_paragraphs(5);
+        let partner = "<p>Discover how our premium travel cards, with rewards, lounge access, and no foreign fees, make every trip smoother, faster, calmer, and cheaper.</p>".repeat(6);
+        let html = format!(
+            r#"<html><body>
+                <div class="story">{editorial}</div>
+                <div class="feature-box"><span>Paid Partnership</span>{partner}</div>
+            </body></html>"#
+        );
+        let document = Html::parse_document(&html);
+
+        let options = ReadabilityOptions::default();
+        let unpenalized = grab_article(&document, &options).unwrap().unwrap();
+        assert!(unpenalized.html.contains("premium travel cards"));
+
+        let options = ReadabilityOptions::builder().sponsored_penalty(0.5).build();
+        let extracted = grab_article(&document, &options).unwrap().unwrap();
+        assert!(extracted.html.contains("transit plan"));
+        assert!(!extracted.html.contains("premium travel cards"));
+    }
 }
@@ -1,6 +1,8 @@
 //! DOM manipulation and traversal utilities.
 
 use crate::constants::{PHRASING_ELEMS, REGEXPS};
+use once_cell::sync::Lazy;
+use regex::Regex;
 use scraper::{ElementRef, Html, Selector};
 
 /// Get inner text of an element - cross browser compatibly.
@@ -85,6 +87,8 @@ pub fn is_phrasing_content(element: ElementRef) -> bool {
 /// Check if a node is probably visible.
 ///
 /// Checks CSS display/visibility, hidden attribute, and aria-hidden.
+/// Rendering hints such as `content-visibility: auto`, `loading="lazy"` and
+/// `decoding="async"` only defer work and never hide content, so they are ignored.
 ///
 /// # Arguments
 /// * `element` - The element to check
@@ -96,13 +100,7 @@ pub fn is_probably_visible(element: ElementRef) -> bool {
 
     while let Some(node) = current {
         if let Some(style) = node.value().attr("style") {
-            let style_lower = style.to_lowercase();
-            if style_lower.contains("display:none") || style_lower.contains("display: none") {
-                return false;
-            }
-            if style_lower.contains("visibility:hidden")
-                || style_lower.contains("visibility: hidden")
-            {
+            if style_hides_element(style) {
                 return false;
             }
         }
@@ -131,6 +129,118 @@ pub fn is_probably_visible(element: ElementRef) -> bool {
     true
 }
 
+/// Check whether an inline style declaration list hides the element.
+///
+/// Declarations are matched by exact property name, so properties that merely
+/// contain "visibility" (like `content-visibility`) are not mistaken for hiding.
+fn style_hides_element(style: &str) -> bool {
+    style.split(';').any(|declaration| {
+        let Some((property, value)) = declaration.split_once(':') else {
+            return false;
+        };
+        let property = property.trim().to_ascii_lowercase();
+        let value = value
+            .trim()
+            .trim_end_matches("!important")
+            .trim()
+            .to_ascii_lowercase();
+
+        match property.as_str() {
+            "display" => value == "none",
+            "visibility" => value == "hidden",
+            _ => false,
+        }
+    })
+}
+
+/// Collect the selectors of stylesheet rules that hide elements.
+///
+/// Only top-level rules whose declarations hide the element (as judged for inline
+/// styles) are considered, and only simple selectors are returned: a single class
+/// (`.hidden`), id (`#promo`) or attribute test (`[data-hidden]`,
+/// `[data-state="closed"]`). Rules inside at-rules such as `@media` are skipped, since
+/// they may not apply.
+pub fn hidden_selectors_from_stylesheet(css: &str) -> Vec<String> {
+    static COMMENT_REGEX: Lazy<Regex> = Lazy::new(|| Regex::new(r"(?s)/\*.*?\*/").unwrap());
+    static SIMPLE_SELECTOR_REGEX: Lazy<Regex> = Lazy::new(|| {
+        Regex::new(
+            r#"^(?:[.#][A-Za-z_][\w-]*|\[[A-Za-z_][\w-]*(?:=(?:"[^"]*"|'[^']*'|[\w-]+))?\])$"#,
+        )
+        .unwrap()
+    });
+
+    let css = COMMENT_REGEX.replace_all(css, "");
+    let mut selectors = Vec::new();
+    let mut depth = 0usize;
+    let mut prelude = String::new();
+    let mut block = String::new();
+    let mut skip_block = false;
+
+    for c in css.chars() {
+        match c {
+            '{' => {
+                if depth == 0 {
+                    skip_block = prelude.trim_start().starts_with('@');
+                    block.clear();
+                }
+                depth += 1;
+            }
+            '}' if depth > 0 => {
+                depth -= 1;
+                if depth == 0 {
+                    if !skip_block && style_hides_element(&block) {
+                        selectors.extend(
+                            prelude
+                                .split(',')
+                                .map(str::trim)
+                                .filter(|selector| SIMPLE_SELECTOR_REGEX.is_match(selector))
+                                .map(str::to_string),
+                        );
+                    }
+                    prelude.clear();
+                }
+            }
+            ';' if depth == 0 => prelude.clear(),
+            _ if depth == 0 => prelude.push(c),
+            _ if depth == 1 => block.push(c),
+            _ => {}
+        }
+    }
+
+    selectors
+}
+
+/// Get the accessible name an element gets from ARIA attributes.
+///
+/// `aria-labelledby` takes precedence and resolves to the text of the referenced
+/// elements, joined with spaces; `aria-label` is used otherwise. Returns `None` when
+/// neither yields any text.
+pub fn get_accessible_name(document: &Html, element: ElementRef) -> Option<String> {
+    let from_ids = element.value().attr("aria-labelledby").and_then(|ids| {
+        let parts: Vec<String> = ids
+            .split_whitespace()
+            .filter_map(|id| {
+                document
+                    .tree
+                    .nodes()
+                    .filter_map(ElementRef::wrap)
+                    .find(|candidate| candidate.value().id() == Some(id))
+            })
+            .map(|labelled_by| get_inner_text(labelled_by, true))
+            .filter(|text| !text.is_empty())
+            .collect();
+        (!parts.is_empty()).then(|| parts.join(" "))
+    });
+
+    from_ids.or_else(|| {
+        element
+            .value()
+            .attr("aria-label")
+            .map(|label| crate::utils::normalize_whitespace(label).trim().to_string())
+            .filter(|label| !label.is_empty())
+    })
+}
+
 /// Get the ancestors of a node up to a maximum depth.
 ///
 /// # Arguments
@@ -194,15 +304,12 @@ pub fn has_child_block_element(element: ElementRef) -> bool {
 /// # Returns
 /// The text direction if found
 pub fn get_article_direction(document: &Html) -> Option<String> {
-    if let Some(html_elem) = document.root_element().first_child() {
-        if let Some(node_ref) = ElementRef::wrap(html_elem) {
-            if node_ref.value().name() == "html" {
-                if let Some(dir) = node_ref.value().attr("dir") {
-                    let dir = dir.trim().to_lowercase();
-                    if dir == "ltr" || dir == "rtl" || dir == "auto" {
-                        return Some(dir);
-                    }
-                }
+    let root = document.root_element();
+    if root.value().name() == "html" {
+        if let Some(dir) = root.value().attr("dir") {
+            let dir = dir.trim().to_lowercase();
+            if dir == "ltr" || dir == "rtl" || dir == "auto" {
+                return Some(dir);
             }
         }
     }
@@ -251,4 +358,50 @@ mod tests {
         let visible = html.select(&visible_sel).next().unwrap();
         assert!(is_probably_visible(visible));
     }
+
+    #[test]
+    fn test_rendering_hints_are_not_hidden() {
+        let html = Html::parse_fragment(
+            r#"
+            <section style="content-visibility: auto; contain-intrinsic-size: 1000px">
+                <p id="deferred">Deferred rendering</p>
+            </section>
+            <div><img id="lazy" loading="lazy" decoding="async" src="a.jpg"></div>
+            <div style="display : none !important"><p id="hidden">Hidden</p></div>
+            <div style="visibility:hidden"><p id="invisible">Invisible</p></div>
+        "#,
+        );
+
+        let select = |css: &str| {
+            let selector = Selector::parse(css).unwrap();
+            html.select(&selector).next().unwrap()
+        };
+
+        assert!(is_probably_visible(select("#deferred")));
+        assert!(is_probably_visible(select("#lazy")));
+        assert!(!is_probably_visible(select("#hidden")));
+        assert!(!is_probably_visible(select("#invisible")));
+    }
+
+    #[test]
+    fn test_hidden_selectors_from_stylesheet() {
+        let css = r#"
+            /* .commented { display: none } */
+            @import url("print.css");
+            .hidden, .sr-only, #promo { display: none !important; }
+            [data-collapsed="true"] { visibility: hidden }
+            .visible { display: block }
+            div.compound > p { display: none }
+            @media (max-width: 600px) { .mobile-hidden { display: none } }
+        "#;
+        assert_eq!(
+            hidden_selectors_from_stylesheet(css),
+            vec![
+                ".hidden",
+                ".sr-only",
+                "#promo",
+                r#"[data-collapsed="true"]"#
+            ]
+        );
+    }
 }
@@ -107,6 +107,27 @@ pub enum ReadabilityError {
     #[error("Invalid document: {0}")]
     InvalidDocument(String),
 
+    /// Invalid CSS selector in the options.
+    ///
+    /// This error occurs when a user-provided selector, such as an entry of
+    /// `content_selectors`, cannot be parsed. Selectors are validated when passed to
+    /// [`Readability::new`](crate::Readability::new).
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use readabilityrs::{Readability, ReadabilityOptions, ReadabilityError};
+    ///
+    /// let options = ReadabilityOptions::builder()
+    ///     .content_selectors(vec!["div[&".to_string()])
+    ///     .build();
+    ///
+    /// let result = Readability::new("<html></html>", None, Some(options));
+    /// assert!(matches!(result, Err(ReadabilityError::InvalidSelector(_))));
+    /// ```
+    #[error("Invalid CSS selector: {0}")]
+    InvalidSelector(String),
+
     /// JSON-LD parsing error.
     ///
     /// This error occurs when JSON-LD structured data is present but cannot be parsed.
@@ -122,12 +122,19 @@ mod options;
 mod post_processor;
 mod readability;
 mod readerable;
+mod render;
 mod scoring;
+mod stats;
 mod utils;
 
 // Public exports
-pub use article::Article;
+pub use article::{
+    Article, ArticleDiff, ArticleSizes, ExpectedMetadata, FieldChange, FieldMismatch, Footnote,
+    LinkRef, ParagraphChange, Recipe, Section, TocEntry,
+};
 pub use error::{ReadabilityError, Result};
-pub use options::ReadabilityOptions;
+pub use options::{IframeFallback, ReadabilityOptions, Serializer};
 pub use readability::Readability;
-pub use readerable::{is_probably_readerable, ReaderableOptions};
+pub use readerable::{is_probably_readerable, is_probably_readerable_doc, ReaderableOptions};
+pub use scoring::is_unlikely_candidate;
+pub use stats::{ParseStats, RemovalReason, RemovedBlock};
@@ -1,5 +1,6 @@
 //! Metadata extraction from HTML documents (JSON-LD, meta tags, etc.).
 
+use crate::article::Recipe;
 use crate::constants::REGEXPS;
 use crate::utils;
 use once_cell::sync::Lazy;
@@ -13,20 +14,41 @@ use std::collections::HashMap;
 #[derive(Debug, Clone, Default)]
 pub struct Metadata {
     pub title: Option<String>,
+    /// Untouched text of the `<title>` tag
+    pub raw_title: Option<String>,
     pub byline: Option<String>,
     pub excerpt: Option<String>,
     pub site_name: Option<String>,
     pub published_time: Option<String>,
     pub lang: Option<String>,
     pub image: Option<String>,
+    pub image_alt: Option<String>,
+    /// Avatar from the element carrying the byline, unresolved
+    pub author_image: Option<String>,
+    /// Name of the first JSON-LD Organization block, a low-priority site name source
+    pub organization_name: Option<String>,
+    pub tags: Vec<String>,
+    pub updated_label: Option<String>,
+    pub robots: Option<String>,
+    pub recipe: Option<Recipe>,
+    /// Full text from the JSON-LD `articleBody`, untrimmed
+    pub article_body: Option<String>,
+    /// Place the article is about or was written in, from JSON-LD
+    pub location: Option<String>,
 }
 
 /// Extract JSON-LD structured data from document
 ///
 /// Looks for <script type="application/ld+json"> tags and parses them for article metadata.
 /// Supports Schema.org Article types.
+///
+/// When several blocks are present, precedence is deterministic: for each field the
+/// first Article-type block (in document order) that provides it wins. Non-article
+/// blocks are ignored, except that the first Organization name is kept as a
+/// last-resort site name.
 pub fn get_json_ld(document: &Html) -> Metadata {
     let mut metadata = Metadata::default();
+    let mut organization_name = None;
 
     let script_selector = Selector::parse("script[type='application/ld+json']").unwrap();
     let schema_regex = regex::Regex::new(r"^https?://schema\.org/?$").unwrap();
@@ -42,6 +64,13 @@ pub fn get_json_ld(document: &Html) -> Metadata {
             .trim();
 
         if let Ok(mut parsed) = serde_json::from_str::<Value>(content) {
+            if organization_name.is_none() {
+                organization_name = find_json_ld_organization_name(&parsed);
+            }
+            if metadata.recipe.is_none() {
+                metadata.recipe = find_json_ld_recipe(&parsed);
+            }
+
             if let Some(arr) = parsed.as_array() {
                 if let Some(article) = arr.iter().find(|item| {
                     if let Some(type_val) = item.get("@type") {
@@ -135,17 +164,14 @@ pub fn get_json_ld(document: &Html) -> Metadata {
 
             if metadata.byline.is_none() {
                 if let Some(author) = parsed.get("author") {
-                    if let Some(author_name) = author.get("name").and_then(|v| v.as_str()) {
-                        metadata.byline = Some(author_name.trim().to_string());
-                    } else if let Some(authors) = author.as_array() {
-                        let names: Vec<String> = authors
-                            .iter()
-                            .filter_map(|a| a.get("name").and_then(|n| n.as_str()))
-                            .map(|n| n.trim().to_string())
-                            .collect();
+                    if let Some(authors) = author.as_array() {
+                        let names: Vec<String> =
+                            authors.iter().filter_map(json_ld_author_name).collect();
                         if !names.is_empty() {
                             metadata.byline = Some(names.join(", "));
                         }
+                    } else {
+                        metadata.byline = json_ld_author_name(author);
                     }
                 }
             }
@@ -170,16 +196,172 @@ pub fn get_json_ld(document: &Html) -> Metadata {
                 }
             }
 
+            if metadata.article_body.is_none() {
+                if let Some(body) = parsed.get("articleBody").and_then(|v| v.as_str()) {
+                    if !body.trim().is_empty() {
+                        metadata.article_body = Some(body.to_string());
+                    }
+                }
+            }
+
+            if metadata.location.is_none() {
+                metadata.location = parsed
+                    .get("contentLocation")
+                    .and_then(json_ld_place_name)
+                    .or_else(|| parsed.get("locationCreated").and_then(json_ld_place_name));
+            }
+
             // Extract image from JSON-LD
             if metadata.image.is_none() {
                 metadata.image = extract_json_ld_image(&parsed);
+                metadata.image_alt = extract_json_ld_image_caption(&parsed);
+            }
+
+            if metadata.tags.is_empty() {
+                match parsed.get("keywords") {
+                    Some(Value::String(keywords)) => {
+                        push_tags(&mut metadata.tags, keywords.split(','));
+                    }
+                    Some(Value::Array(keywords)) => {
+                        push_tags(
+                            &mut metadata.tags,
+                            keywords.iter().filter_map(Value::as_str),
+                        );
+                    }
+                    _ => {}
+                }
             }
         }
     }
 
+    metadata.organization_name = organization_name;
+
     metadata
 }
 
+/// Append trimmed, non-empty tags, skipping any already present (ignoring case)
+fn push_tags<'a>(tags: &mut Vec<String>, candidates: impl IntoIterator<Item = &'a str>) {
+    for candidate in candidates {
+        let tag = utils::normalize_whitespace(candidate).trim().to_string();
+        if tag.is_empty()
+            || tags
+                .iter()
+                .any(|existing| existing.to_lowercase() == tag.to_lowercase())
+        {
+            continue;
+        }
+        tags.push(tag);
+    }
+}
+
+/// Find the name of an Organization node in a JSON-LD block
+///
+/// Looks at the block itself, top-level array items and `@graph` members.
+fn find_json_ld_organization_name(parsed: &Value) -> Option<String> {
+    let is_organization = |item: &Value| {
+        let type_matches =
+            |type_str: &str| type_str.ends_with("Organization") || type_str == "Corporation";
+        match item.get("@type") {
+            Some(Value::String(type_str)) => type_matches(type_str),
+            Some(Value::Array(types)) => types.iter().filter_map(|t| t.as_str()).any(type_matches),
+            _ => false,
+        }
+    };
+
+    let mut nodes: Vec<&Value> = vec![parsed];
+    if let Some(items) = parsed.as_array() {
+        nodes.extend(items);
+    }
+    if let Some(graph) = parsed.get("@graph").and_then(|g| g.as_array()) {
+        nodes.extend(graph);
+    }
+
+    nodes
+        .into_iter()
+        .filter(|node| is_organization(node))
+        .find_map(|node| node.get("name").and_then(|n| n.as_str()))
+        .map(|name| name.trim().to_string())
+        .filter(|name| !name.is_empty())
+}
+
+/// Find a Recipe node in a JSON-LD block
+///
+/// Looks at the block itself, top-level array items and `@graph` members.
+fn find_json_ld_recipe(parsed: &Value) -> Option<Recipe> {
+    let is_recipe = |item: &Value| match item.get("@type") {
+        Some(Value::String(type_str)) => type_str == "Recipe",
+        Some(Value::Array(types)) => types.iter().any(|t| t.as_str() == Some("Recipe")),
+        _ => false,
+    };
+
+    let mut nodes: Vec<&Value> = vec![parsed];
+    if let Some(items) = parsed.as_array() {
+        nodes.extend(items);
+    }
+    if let Some(graph) = parsed.get("@graph").and_then(|g| g.as_array()) {
+        nodes.extend(graph);
+    }
+
+    let node = nodes.into_iter().find(|node| is_recipe(node))?;
+    let text_field = |key: &str| {
+        node.get(key)
+            .and_then(|v| v.as_str())
+            .map(|v| utils::normalize_whitespace(v).trim().to_string())
+            .filter(|v| !v.is_empty())
+    };
+
+    let mut ingredients = Vec::new();
+    if let Some(value) = node
+        .get("recipeIngredient")
+        .or_else(|| node.get("ingredients"))
+    {
+        collect_recipe_texts(value, &mut ingredients);
+    }
+    let mut instructions = Vec::new();
+    if let Some(value) = node.get("recipeInstructions") {
+        collect_recipe_texts(value, &mut instructions);
+    }
+
+    Some(Recipe {
+        name: text_field("name"),
+        ingredients,
+        instructions,
+        prep_time: text_field("prepTime"),
+        cook_time: text_field("cookTime"),
+        total_time: text_field("totalTime"),
+    })
+}
+
+/// Flatten recipe ingredients or instructions into trimmed lines
+///
+/// Accepts plain strings (split on newlines), arrays, `HowToStep` objects with `text`
+/// (or `name`) and `HowToSection` objects with `itemListElement`.
+fn collect_recipe_texts(value: &Value, out: &mut Vec<String>) {
+    match value {
+        Value::String(text) => {
+            for line in text.lines() {
+                let line = utils::normalize_whitespace(line).trim().to_string();
+                if !line.is_empty() {
+                    out.push(line);
+                }
+            }
+        }
+        Value::Array(items) => {
+            for item in items {
+                collect_recipe_texts(item, out);
+            }
+        }
+        Value::Object(object) => {
+            if let Some(steps) = object.get("itemListElement") {
+                collect_recipe_texts(steps, out);
+            } else if let Some(text) = object.get("text").or_else(|| object.get("name")) {
+                collect_recipe_texts(text, out);
+            }
+        }
+        _ => {}
+    }
+}
+
 /// Extract image URL from JSON-LD data
 ///
 /// Handles various Schema.org image formats:
@@ -244,19 +426,89 @@ fn extract_json_ld_image(parsed: &Value) -> Option<String> {
     None
 }
 
+/// Extract the caption of the JSON-LD image, for an ImageObject or the first array entry
+fn extract_json_ld_image_caption(parsed: &Value) -> Option<String> {
+    let image = parsed.get("image")?;
+    let image = image
+        .as_array()
+        .and_then(|arr| arr.first())
+        .unwrap_or(image);
+    image
+        .get("caption")
+        .and_then(|v| v.as_str())
+        .map(str::trim)
+        .filter(|caption| !caption.is_empty())
+        .map(str::to_string)
+}
+
+/// Name of a JSON-LD `author` entry
+///
+/// The author may be a `Person` object with a `name` or, loosely but commonly, a bare
+/// string. Both forms get the same rules: the name is trimmed, and empty names and URLs
+/// (often given instead of a name) are skipped.
+fn json_ld_author_name(author: &Value) -> Option<String> {
+    let name = author
+        .get("name")
+        .and_then(Value::as_str)
+        .or_else(|| author.as_str())?
+        .trim();
+    (!name.is_empty() && !utils::is_url(name)).then(|| name.to_string())
+}
+
+/// Name of a JSON-LD place, given as a string or a `Place` object
+///
+/// A `Place` is named by its `name`, else by its `address`, which may itself be a string
+/// or a `PostalAddress` whose parts are joined with commas. For an array, the first
+/// entry that yields a name is used.
+fn json_ld_place_name(place: &Value) -> Option<String> {
+    let text = |value: &Value| {
+        value
+            .as_str()
+            .or_else(|| value.get("name").and_then(Value::as_str))
+            .map(|text| utils::normalize_whitespace(text).trim().to_string())
+            .filter(|text| !text.is_empty())
+    };
+
+    match place {
+        Value::String(_) => text(place),
+        Value::Array(places) => places.iter().find_map(json_ld_place_name),
+        Value::Object(_) => text(place).or_else(|| {
+            let address = place.get("address")?;
+            if address.is_string() {
+                return text(address);
+            }
+            let parts: Vec<String> = [
+                "streetAddress",
+                "addressLocality",
+                "addressRegion",
+                "addressCountry",
+            ]
+            .iter()
+            .filter_map(|key| address.get(*key).and_then(text))
+            .collect();
+            (!parts.is_empty()).then(|| parts.join(", "))
+        }),
+        _ => None,
+    }
+}
+
 /// Extract article metadata from meta tags
 ///
 /// Supports OpenGraph, Twitter Cards, Dublin Core, and standard meta tags.
 pub fn get_article_metadata(document: &Html, json_ld: Metadata) -> Metadata {
     let mut values: HashMap<String, String> = HashMap::new();
     let property_pattern = regex::Regex::new(
-        r"(?i)\s*(article|dc|dcterm|og|twitter)\s*:\s*(author|creator|description|published_time|title|site_name|image:url|image:secure_url|image$)\s*"
+        r"(?i)\s*(article|dc|dcterm|og|twitter)\s*:\s*(author|creator|description|published_time|title|site_name|image:url|image:secure_url|image:alt|image$)\s*"
     ).unwrap();
 
     let name_pattern = regex::Regex::new(
         r"(?i)^\s*(?:(?:article|dc|dcterm|og|twitter|parsely|weibo:(?:article|webpage))\s*[-\.:]\s*)?(author|author_name|creator|pub-date|description|title|site_name|image|thumbnail)\s*$"
     ).unwrap();
 
+    let mut article_tags = Vec::new();
+    let mut keywords = Vec::new();
+    let mut article_authors: Vec<&str> = Vec::new();
+
     let meta_selector = Selector::parse("meta").unwrap();
     for meta in document.select(&meta_selector) {
         let element_name = meta.value().attr("name");
@@ -270,6 +522,18 @@ pub fn get_article_metadata(document: &Html, json_ld: Metadata) -> Metadata {
         let content = content.unwrap();
         let mut matched_name: Option<String> = None;
 
+        // Tags repeat, so they are collected apart from the single-valued fields
+        if element_property.is_some_and(|property| {
+            property
+                .split_whitespace()
+                .any(|prop| prop.eq_ignore_ascii_case("article:tag"))
+        }) {
+            article_tags.push(content);
+        }
+        if element_name.is_some_and(|name| name.trim().eq_ignore_ascii_case("keywords")) {
+            keywords.extend(content.split(','));
+        }
+
         if let Some(property) = element_property {
             // Handle space-separated properties (e.g., "dc:creator twitter:site_name")
             // Split on whitespace and process each property separately
@@ -278,6 +542,17 @@ pub fn get_article_metadata(document: &Html, json_ld: Metadata) -> Metadata {
                     let key = prop[mat.start()..mat.end()]
                         .to_lowercase()
                         .replace(char::is_whitespace, "");
+                    // Co-authored pages repeat the author tag, once per author
+                    if key == "article:author" {
+                        let author = content.trim();
+                        if !utils::is_url(author)
+                            && !article_authors
+                                .iter()
+                                .any(|seen| seen.eq_ignore_ascii_case(author))
+                        {
+                            article_authors.push(author);
+                        }
+                    }
                     values.insert(key, content.trim().to_string());
                     matched_name = Some(property.to_string());
                 }
@@ -292,6 +567,25 @@ pub fn get_article_metadata(document: &Html, json_ld: Metadata) -> Metadata {
                         .replace(char::is_whitespace, "")
                         .replace('.', ":");
                     values.insert(normalized, content.trim().to_string());
+                    matched_name = Some(name.to_string());
+                }
+            }
+        }
+        // Microdata meta tags are the last source, under an "itemprop:" prefix
+        if matched_name.is_none() {
+            if let Some(itemprop) = meta.value().attr("itemprop") {
+                if !in_nested_microdata_item(meta) {
+                    for prop in itemprop.split_whitespace() {
+                        let key = prop.to_lowercase();
+                        if matches!(
+                            key.as_str(),
+                            "author" | "datepublished" | "description" | "name"
+                        ) {
+                            values
+                                .entry(format!("itemprop:{key}"))
+                                .or_insert_with(|| content.trim().to_string());
+                        }
+                    }
                 }
             }
         }
@@ -308,6 +602,7 @@ pub fn get_article_metadata(document: &Html, json_ld: Metadata) -> Metadata {
                 .or_else(|| values.get("title"))
                 .or_else(|| values.get("twitter:title"))
                 .or_else(|| values.get("parsely-title"))
+                .or_else(|| values.get("itemprop:name"))
                 .cloned()
         }),
         ..Default::default()
@@ -321,11 +616,14 @@ pub fn get_article_metadata(document: &Html, json_ld: Metadata) -> Metadata {
         metadata.title = Some(String::new());
     }
 
-    let article_author = values
-        .get("article:author")
-        .or_else(|| values.get("article:author_name"))
-        .filter(|v| !utils::is_url(v))
-        .cloned();
+    let article_author = if article_authors.is_empty() {
+        values
+            .get("article:author_name")
+            .filter(|v| !utils::is_url(v))
+            .cloned()
+    } else {
+        Some(article_authors.join(", "))
+    };
 
     let dom_byline = extract_byline_from_document(document);
     let mut meta_byline = json_ld.byline.or_else(|| {
@@ -335,6 +633,7 @@ pub fn get_article_metadata(document: &Html, json_ld: Metadata) -> Metadata {
             .or_else(|| values.get("author"))
             .or_else(|| values.get("parsely-author"))
             .or(article_author.as_ref())
+            .or_else(|| values.get("itemprop:author").filter(|v| !utils::is_url(v)))
             .cloned()
     });
 
@@ -361,19 +660,33 @@ pub fn get_article_metadata(document: &Html, json_ld: Metadata) -> Metadata {
             .or_else(|| values.get("weibo:webpage:description"))
             .or_else(|| values.get("description"))
             .or_else(|| values.get("twitter:description"))
+            .or_else(|| values.get("itemprop:description"))
             .cloned()
     });
 
     metadata.site_name = json_ld
         .site_name
-        .or_else(|| values.get("og:site_name").cloned());
+        .or_else(|| values.get("og:site_name").cloned())
+        .or(json_ld.organization_name);
 
-    metadata.published_time = json_ld.published_time.or_else(|| {
-        values
-            .get("article:published_time")
-            .or_else(|| values.get("parsely-pub-date"))
-            .cloned()
-    });
+    metadata.published_time = json_ld
+        .published_time
+        .or_else(|| extract_microdata_published_time(document))
+        .or_else(|| {
+            values
+                .get("article:published_time")
+                .or_else(|| values.get("parsely-pub-date"))
+                .or_else(|| values.get("itemprop:datepublished"))
+                .cloned()
+        });
+
+    push_tags(&mut metadata.tags, article_tags);
+    push_tags(&mut metadata.tags, keywords);
+    push_tags(&mut metadata.tags, json_ld.tags.iter().map(String::as_str));
+
+    metadata.recipe = json_ld.recipe;
+    metadata.article_body = json_ld.article_body;
+    metadata.location = json_ld.location;
 
     // Extract image from meta tags with priority order
     metadata.image = json_ld.image.or_else(|| {
@@ -387,6 +700,8 @@ pub fn get_article_metadata(document: &Html, json_ld: Metadata) -> Metadata {
             .cloned()
     });
 
+    metadata.image_alt = values.get("og:image:alt").cloned().or(json_ld.image_alt);
+
     // If no image found in standard meta tags, try additional sources
     if metadata.image.is_none() {
         metadata.image = extract_image_from_document(document);
@@ -394,14 +709,16 @@ pub fn get_article_metadata(document: &Html, json_ld: Metadata) -> Metadata {
 
     metadata.lang = extract_language_from_document(document);
 
-    metadata.title = metadata.title.map(|t| utils::unescape_html_entities(&t));
+    metadata.title = metadata
+        .title
+        .map(|t| utils::strip_invisible_chars(&utils::unescape_html_entities(&t)).into_owned());
     metadata.byline = metadata
         .byline
-        .map(|b| utils::unescape_html_entities(&b))
+        .map(|b| utils::strip_invisible_chars(&utils::unescape_html_entities(&b)).into_owned())
         .and_then(|b| utils::clean_byline_text(&b));
     metadata.excerpt = metadata
         .excerpt
-        .map(|e| utils::unescape_html_entities(&e))
+        .map(|e| utils::strip_invisible_chars(&utils::unescape_html_entities(&e)).into_owned())
         .and_then(|e| {
             let trimmed = e.trim();
             if trimmed.is_empty() {
@@ -443,15 +760,33 @@ pub fn get_article_metadata(document: &Html, json_ld: Metadata) -> Metadata {
         }
     }
 
+    metadata.raw_title = extract_raw_title(document);
+    if metadata.byline.is_none() {
+        metadata.byline = metadata.raw_title.as_deref().and_then(byline_from_title);
+    }
+
     if let (Some(byline), Some(site_name)) = (metadata.byline.clone(), metadata.site_name.clone()) {
         if utils::is_byline_redundant_with_site_name(&byline, &site_name) {
             metadata.byline = None;
         }
     }
 
+    // Only trust the avatar when the final byline is the one read from the DOM
+    metadata.author_image = dom_byline.and_then(|dom_value| {
+        let byline = byline_match_key(metadata.byline.as_deref()?);
+        let dom_text = utils::clean_byline_text(&dom_value.text).unwrap_or(dom_value.text);
+        if byline == byline_match_key(&dom_text) {
+            dom_value.author_image
+        } else {
+            None
+        }
+    });
+
     metadata.published_time = metadata
         .published_time
         .map(|p| utils::unescape_html_entities(&p));
+    metadata.updated_label = extract_updated_label(document);
+    metadata.robots = extract_robots(document);
 
     // Clean up image URL
     metadata.image = metadata.image.and_then(|img| {
@@ -461,10 +796,186 @@ pub fn get_article_metadata(document: &Html, json_ld: Metadata) -> Metadata {
         }
         Some(utils::unescape_html_entities(trimmed))
     });
+    metadata.image_alt = metadata
+        .image_alt
+        .filter(|_| metadata.image.is_some())
+        .map(|alt| utils::unescape_html_entities(&alt));
 
     metadata
 }
 
+/// Whether a microdata property belongs to a nested item rather than the page's own
+///
+/// A `<meta itemprop="name">` inside `<div itemprop="author" itemscope>` names the
+/// author, not the article, so only properties of top-level items are used as metadata.
+fn in_nested_microdata_item(element: ElementRef) -> bool {
+    element
+        .ancestors()
+        .filter_map(ElementRef::wrap)
+        .any(|ancestor| {
+            let attrs = ancestor.value();
+            attrs.attr("itemscope").is_some() && attrs.attr("itemprop").is_some()
+        })
+}
+
+/// Extract the publication date from schema.org microdata
+///
+/// Looks for the first element with `itemprop="datePublished"` and reads its
+/// `datetime` attribute (`<time>`), then its `content` attribute (`<meta>`), then its
+/// text.
+fn extract_microdata_published_time(document: &Html) -> Option<String> {
+    let selector = Selector::parse("[itemprop]").ok()?;
+
+    for elem in document.select(&selector) {
+        let itemprop = elem.value().attr("itemprop").unwrap_or("");
+        if !itemprop
+            .split_whitespace()
+            .any(|prop| prop.eq_ignore_ascii_case("datePublished"))
+        {
+            continue;
+        }
+
+        let value = elem
+            .value()
+            .attr("datetime")
+            .or_else(|| elem.value().attr("content"))
+            .map(str::to_string)
+            .unwrap_or_else(|| elem.text().collect::<String>());
+        let trimmed = utils::normalize_whitespace(&value).trim().to_string();
+        if !trimmed.is_empty() {
+            return Some(trimmed);
+        }
+    }
+
+    None
+}
+
+/// Author named in the page title, such as "Why X Matters - by Jane Doe - Site"
+///
+/// A low-confidence last resort for pages with no other byline signal: the title is
+/// split on the usual separators, and a part reading "by <Name>" is used when the name
+/// looks like a person's and is capitalized, apart from particles such as "van".
+fn byline_from_title(title: &str) -> Option<String> {
+    const PARTICLES: [&str; 9] = ["de", "da", "del", "der", "di", "du", "la", "van", "von"];
+    let capitalized = |name: &str| {
+        name.split_whitespace()
+            .all(|word| word.starts_with(char::is_uppercase) || PARTICLES.contains(&word))
+    };
+
+    static SEPARATOR: Lazy<regex::Regex> =
+        Lazy::new(|| regex::Regex::new(r"\s[|\-–—·»/]\s").unwrap());
+
+    SEPARATOR.split(title).skip(1).find_map(|part| {
+        let part = part.trim();
+        let head = part.get(..3)?;
+        if !head.eq_ignore_ascii_case("by ") {
+            return None;
+        }
+        let name = part[3..].trim();
+        (utils::looks_like_author_name(name) && capitalized(name)).then(|| name.to_string())
+    })
+}
+
+/// Extract the `<title>` tag text as written, only trimmed
+fn extract_raw_title(document: &Html) -> Option<String> {
+    static SELECTOR: Lazy<Selector> = Lazy::new(|| Selector::parse("title").unwrap());
+
+    let title = document
+        .select(&SELECTOR)
+        .next()?
+        .text()
+        .collect::<String>();
+    let trimmed = title.trim();
+    (!trimmed.is_empty()).then(|| trimmed.to_string())
+}
+
+/// Extract the `<meta name="robots">` directive, such as `noindex, nofollow`
+///
+/// Several robots meta tags are joined with commas, in document order.
+fn extract_robots(document: &Html) -> Option<String> {
+    static SELECTOR: Lazy<Selector> = Lazy::new(|| Selector::parse("meta[name]").unwrap());
+
+    let directives: Vec<String> = document
+        .select(&SELECTOR)
+        .filter(|meta| {
+            meta.value()
+                .attr("name")
+                .is_some_and(|name| name.trim().eq_ignore_ascii_case("robots"))
+        })
+        .filter_map(|meta| meta.value().attr("content"))
+        .map(|content| utils::normalize_whitespace(content).trim().to_string())
+        .filter(|content| !content.is_empty())
+        .collect();
+
+    if directives.is_empty() {
+        None
+    } else {
+        Some(directives.join(", "))
+    }
+}
+
+/// Extract the human-readable "updated" label, such as `Updated 3:45pm`
+///
+/// Uses the first short element whose class or id mentions "updated", or whose own
+/// text starts with "Updated". The label is kept as displayed, not parsed as a date.
+fn extract_updated_label(document: &Html) -> Option<String> {
+    static SELECTOR: Lazy<Selector> = Lazy::new(|| Selector::parse("body *").unwrap());
+    const MAX_LABEL_LEN: usize = 100;
+
+    for elem in document.select(&SELECTOR) {
+        if matches!(
+            elem.value().name(),
+            "script" | "style" | "noscript" | "template"
+        ) {
+            continue;
+        }
+
+        let marked = [elem.value().attr("class"), elem.value().attr("id")]
+            .into_iter()
+            .flatten()
+            .any(names_updated);
+        let own_text: String = elem
+            .children()
+            .filter_map(|child| child.value().as_text().map(|text| text.to_string()))
+            .collect();
+        if !marked && !starts_with_updated(own_text.trim_start()) {
+            continue;
+        }
+
+        let text = utils::normalize_whitespace(&elem.text().collect::<String>())
+            .trim()
+            .to_string();
+        if !text.is_empty() && text.chars().count() <= MAX_LABEL_LEN {
+            return Some(text);
+        }
+    }
+
+    None
+}
+
+/// Whether a class or id has "updated" as a word, as in `story-updated`, `updated_at`,
+/// `lastUpdated` or `updatedAt`
+fn names_updated(value: &str) -> bool {
+    value.split(|c: char| !c.is_alphanumeric()).any(|word| {
+        let camel_head = word
+            .get(..7)
+            .is_some_and(|head| head.eq_ignore_ascii_case("updated"))
+            && word[7..].starts_with(char::is_uppercase);
+        let camel_tail = word
+            .strip_suffix("Updated")
+            .is_some_and(|head| head.ends_with(|c: char| c.is_lowercase() || c.is_ascii_digit()));
+        word.eq_ignore_ascii_case("updated") || camel_head || camel_tail
+    })
+}
+
+fn starts_with_updated(text: &str) -> bool {
+    let Some(prefix) = text.get(..7) else {
+        return false;
+    };
+    prefix.eq_ignore_ascii_case("updated")
+        && !text[7..].chars().next().is_some_and(char::is_alphanumeric)
+}
+
 /// Extract image URL from document structure
 ///
 /// Checks additional sources when meta tags don't provide an image:
@@ -514,15 +1025,78 @@ fn extract_image_from_document(document: &Html) -> Option<String> {
     None
 }
 
+/// Find the avatar of the author whose byline was read from `element`
+///
+/// Takes the first image inside the byline element or inside an enclosing author box
+/// (an ancestor with a byline/author class or id). Icons, sprites, tracking pixels and
+/// images declared smaller than 32 pixels are skipped.
+fn author_avatar(element: &ElementRef) -> Option<String> {
+    const MAX_BOX_DEPTH: usize = 2;
+
+    std::iter::once(*element)
+        .chain(
+            element
+                .ancestors()
+                .filter_map(ElementRef::wrap)
+                .take(MAX_BOX_DEPTH)
+                .take_while(element_has_byline_keyword),
+        )
+        .find_map(avatar_in)
+}
+
+fn avatar_in(container: ElementRef) -> Option<String> {
+    static IMG_SELECTOR: Lazy<Selector> = Lazy::new(|| Selector::parse("img").unwrap());
+    const MIN_SIZE: u32 = 32;
+    const ICON_HINTS: [&str; 5] = ["icon", "sprite", "spacer", "pixel", "logo"];
+
+    container.select(&IMG_SELECTOR).find_map(|img| {
+        let value = img.value();
+        let too_small = ["width", "height"].iter().any(|name| {
+            value
+                .attr(name)
+                .and_then(|size| size.trim().trim_end_matches("px").parse::<u32>().ok())
+                .is_some_and(|size| size < MIN_SIZE)
+        });
+        if too_small {
+            return None;
+        }
+        let src = ["src", "data-src"]
+            .iter()
+            .filter_map(|name| value.attr(name))
+            .map(str::trim)
+            .find(|src| !src.is_empty() && !src.to_ascii_lowercase().starts_with("data:"))?;
+        let hints = format!("{} {}", src, value.attr("class").unwrap_or_default()).to_lowercase();
+        if ICON_HINTS.iter().any(|hint| hints.contains(hint)) {
+            return None;
+        }
+        Some(src.to_string())
+    })
+}
+
+fn byline_match_key(text: &str) -> String {
+    let text = text
+        .split_whitespace()
+        .collect::<Vec<_>>()
+        .join(" ")
+        .to_lowercase();
+    text.strip_prefix("by ").unwrap_or(&text).to_string()
+}
+
 #[derive(Clone, Debug, PartialEq, Eq)]
 struct DomBylineCandidate {
     text: String,
     confidence: DomBylineConfidence,
+    /// Avatar found in the element the byline was read from, or its author box
+    author_image: Option<String>,
 }
 
 impl DomBylineCandidate {
-    fn new(text: String, confidence: DomBylineConfidence) -> Self {
-        Self { text, confidence }
+    fn new(text: String, confidence: DomBylineConfidence, source: Option<&ElementRef>) -> Self {
+        Self {
+            text,
+            confidence,
+            author_image: source.and_then(author_avatar),
+        }
     }
 }
 
@@ -548,6 +1122,7 @@ fn extract_byline_from_document(document: &Html) -> Option<DomBylineCandidate> {
         return Some(DomBylineCandidate::new(
             candidate,
             DomBylineConfidence::High,
+            None,
         ));
     }
 
@@ -563,6 +1138,7 @@ fn extract_byline_from_document(document: &Html) -> Option<DomBylineCandidate> {
                 return Some(DomBylineCandidate::new(
                     parent_text,
                     DomBylineConfidence::High,
+                    Some(&link),
                 ));
             }
 
@@ -582,6 +1158,7 @@ fn extract_byline_from_document(document: &Html) -> Option<DomBylineCandidate> {
                             return Some(DomBylineCandidate::new(
                                 cleaned,
                                 DomBylineConfidence::High,
+                                Some(&link),
                             ))
                         }
                         utils::CleanBylineOutcome::DroppedOrgCredit => return None,
@@ -604,6 +1181,7 @@ fn extract_byline_from_document(document: &Html) -> Option<DomBylineCandidate> {
                 return Some(DomBylineCandidate::new(
                     parent_text,
                     DomBylineConfidence::High,
+                    Some(&elem),
                 ));
             }
 
@@ -623,6 +1201,7 @@ fn extract_byline_from_document(document: &Html) -> Option<DomBylineCandidate> {
                             return Some(DomBylineCandidate::new(
                                 cleaned,
                                 DomBylineConfidence::High,
+                                Some(&elem),
                             ))
                         }
                         utils::CleanBylineOutcome::DroppedOrgCredit => return None,
@@ -679,7 +1258,8 @@ fn extract_byline_from_document(document: &Html) -> Option<DomBylineCandidate> {
                     };
                     match utils::clean_byline_text_with_reason(&text) {
                         utils::CleanBylineOutcome::Accepted(cleaned) => {
-                            let candidate = DomBylineCandidate::new(cleaned, confidence);
+                            let candidate =
+                                DomBylineCandidate::new(cleaned, confidence, Some(&elem));
                             if is_priority_dom_candidate(&candidate, text_is_caps) {
                                 return Some(candidate);
                             } else if fallback_candidate.is_none() {
@@ -729,8 +1309,11 @@ fn extract_byline_from_document(document: &Html) -> Option<DomBylineCandidate> {
             {
                 match utils::clean_byline_text_with_reason(&text) {
                     utils::CleanBylineOutcome::Accepted(cleaned) => {
-                        let candidate =
-                            DomBylineCandidate::new(cleaned, DomBylineConfidence::Medium);
+                        let candidate = DomBylineCandidate::new(
+                            cleaned,
+                            DomBylineConfidence::Medium,
+                            Some(&elem),
+                        );
                         if is_priority_dom_candidate(&candidate, text_is_caps) {
                             return Some(candidate);
                         } else if fallback_candidate.is_none() {
@@ -765,7 +1348,8 @@ fn extract_byline_from_document(document: &Html) -> Option<DomBylineCandidate> {
             {
                 match utils::clean_byline_text_with_reason(&text) {
                     utils::CleanBylineOutcome::Accepted(cleaned) => {
-                        let candidate = DomBylineCandidate::new(cleaned, DomBylineConfidence::Low);
+                        let candidate =
+                            DomBylineCandidate::new(cleaned, DomBylineConfidence::Low, Some(&elem));
                         if is_priority_dom_candidate(&candidate, text_is_caps) {
                             return Some(candidate);
                         } else if fallback_candidate.is_none() {
@@ -800,7 +1384,8 @@ fn extract_byline_from_document(document: &Html) -> Option<DomBylineCandidate> {
             if utils::looks_like_byline(&text) || text_is_caps {
                 match utils::clean_byline_text_with_reason(&text) {
                     utils::CleanBylineOutcome::Accepted(cleaned) => {
-                        let candidate = DomBylineCandidate::new(cleaned, DomBylineConfidence::Low);
+                        let candidate =
+                            DomBylineCandidate::new(cleaned, DomBylineConfidence::Low, Some(&elem));
                         if is_priority_dom_candidate(&candidate, text_is_caps) {
                             return Some(candidate);
                         } else if fallback_candidate.is_none() {
@@ -1024,7 +1609,10 @@ fn should_prefer_child_names(element: &ElementRef, raw_text: &str, names: &[Stri
         return true;
     }
 
-    if tokens.iter().any(|token| looks_like_job_descriptor(token)) {
+    if tokens
+        .iter()
+        .any(|token| utils::looks_like_job_descriptor(token))
+    {
         return true;
     }
 
@@ -1035,31 +1623,6 @@ fn should_prefer_child_names(element: &ElementRef, raw_text: &str, names: &[Stri
     false
 }
 
-fn looks_like_job_descriptor(token: &str) -> bool {
-    const JOB_KEYWORDS: [&str; 19] = [
-        "reporter",
-        "editor",
-        "writer",
-        "staff",
-        "senior",
-        "technologist",
-        "correspondent",
-        "columnist",
-        "analyst",
-        "producer",
-        "anchor",
-        "bureau",
-        "desk",
-        "spokesman",
-        "spokeswoman",
-        "spokesperson",
-        "contributor",
-        "team",
-        "author",
-    ];
-    JOB_KEYWORDS.contains(&token)
-}
-
 const MONTH_KEYWORDS: [&str; 24] = [
     "jan",
     "january",
@@ -1095,6 +1658,12 @@ fn should_prefer_dom_byline(existing: &str, dom: &str, confidence: DomBylineConf
         return false;
     }
 
+    // The same person written differently: keep whichever form is shorter
+    let existing_key = utils::person_name_key(existing_clean);
+    if !existing_key.is_empty() && existing_key == utils::person_name_key(dom_clean) {
+        return name_word_count(dom_clean) < name_word_count(existing_clean);
+    }
+
     if utils::looks_like_org_credit(existing_clean) && !utils::looks_like_org_credit(dom_clean) {
         return true;
     }
@@ -1166,6 +1735,18 @@ fn should_prefer_dom_byline(existing: &str, dom: &str, confidence: DomBylineConf
     true
 }
 
+/// Words in a byline, not counting a leading "by"
+fn name_word_count(byline: &str) -> usize {
+    let mut words = byline.split_whitespace().peekable();
+    if words
+        .peek()
+        .is_some_and(|word| word.eq_ignore_ascii_case("by"))
+    {
+        words.next();
+    }
+    words.count()
+}
+
 fn should_prefer_caps_standfirst(existing: &str, candidate: &str) -> bool {
     let existing_clean = existing.trim();
     let candidate_clean = candidate.trim();
@@ -1360,17 +1941,16 @@ fn is_noise_byline_context(element: &ElementRef) -> bool {
 /// 1. <html lang=""> attribute
 /// 2. Content-Language meta tag
 /// 3. http-equiv="Content-Language"
+/// 4. og:locale meta tag (language subtag only)
 fn extract_language_from_document(document: &Html) -> Option<String> {
-    if let Some(html_elem) = document.root_element().first_child() {
-        if let Some(node_ref) = scraper::ElementRef::wrap(html_elem) {
-            if node_ref.value().name() == "html" {
-                if let Some(lang) = node_ref.value().attr("lang") {
-                    let lang = lang.trim();
-                    if !lang.is_empty() {
-                        return Some(lang.to_string());
-                    }
-                }
-            }
+    let html_elem = document.root_element();
+    if html_elem.value().name() == "html" {
+        if let Some(lang) = html_elem
+            .value()
+            .attr("lang")
+            .and_then(normalize_language_tag)
+        {
+            return Some(lang);
         }
     }
 
@@ -1378,22 +1958,32 @@ fn extract_language_from_document(document: &Html) -> Option<String> {
         Selector::parse("meta[http-equiv='Content-Language'], meta[http-equiv='content-language']")
     {
         for meta in document.select(&meta_selector) {
-            if let Some(content) = meta.value().attr("content") {
-                let lang = content.trim();
-                if !lang.is_empty() {
-                    return Some(lang.to_string());
-                }
+            if let Some(lang) = meta
+                .value()
+                .attr("content")
+                .and_then(normalize_language_tag)
+            {
+                return Some(lang);
             }
         }
     }
 
     if let Ok(meta_selector) = Selector::parse("meta[name='lang'], meta[name='language']") {
         for meta in document.select(&meta_selector) {
-            if let Some(content) = meta.value().attr("content") {
-                let lang = content.trim();
-                if !lang.is_empty() {
-                    return Some(lang.to_string());
-                }
+            if let Some(lang) = meta
+                .value()
+                .attr("content")
+                .and_then(normalize_language_tag)
+            {
+                return Some(lang);
+            }
+        }
+    }
+
+    if let Ok(meta_selector) = Selector::parse("meta[property='og:locale']") {
+        for meta in document.select(&meta_selector) {
+            if let Some(lang) = meta.value().attr("content").and_then(language_from_locale) {
+                return Some(lang);
             }
         }
     }
@@ -1401,6 +1991,49 @@ fn extract_language_from_document(document: &Html) -> Option<String> {
     None
 }
 
+/// Normalize a `lang` value to BCP 47 casing, such as `EN_us` to `en-US`
+///
+/// Only the first tag of a comma-separated list is kept. The primary language subtag is
+/// lowercased, a two-letter region uppercased and a four-letter script title-cased.
+/// Returns `None` when the value does not start with a language subtag.
+fn normalize_language_tag(value: &str) -> Option<String> {
+    let tag = value.split(',').next()?.trim();
+    let mut subtags = tag.split(['-', '_']);
+
+    let language = subtags.next()?;
+    if !(2..=8).contains(&language.len()) || !language.chars().all(|c| c.is_ascii_alphabetic()) {
+        return None;
+    }
+
+    let mut normalized = language.to_ascii_lowercase();
+    for subtag in subtags {
+        if subtag.is_empty() || !subtag.chars().all(|c| c.is_ascii_alphanumeric()) {
+            break;
+        }
+        normalized.push('-');
+        let is_alphabetic = subtag.chars().all(|c| c.is_ascii_alphabetic());
+        match subtag.len() {
+            2 if is_alphabetic => normalized.push_str(&subtag.to_ascii_uppercase()),
+            4 if is_alphabetic => {
+                normalized.push_str(&subtag[..1].to_ascii_uppercase());
+                normalized.push_str(&subtag[1..].to_ascii_lowercase());
+            }
+            _ => normalized.push_str(&subtag.to_ascii_lowercase()),
+        }
+    }
+    Some(normalized)
+}
+
+/// Extract the language subtag from a locale such as `en_US` or `fr-FR`
+fn language_from_locale(locale: &str) -> Option<String> {
+    let language = locale.trim().split(['_', '-']).next()?;
+    if (2..=3).contains(&language.len()) && language.chars().all(|c| c.is_ascii_alphabetic()) {
+        Some(language.to_ascii_lowercase())
+    } else {
+        None
+    }
+}
+
 /// Extract and clean the title from the document's <title> tag
 ///
 /// Implements sophisticated heuristics to remove site names and clean up titles.
@@ -1415,6 +2048,7 @@ fn extract_title_from_document(document: &Html) -> Option<String> {
 
     let mut cur_title = orig_title.clone();
     let mut title_had_hierarchical_separators = false;
+    let mut title_matched_heading = false;
 
     fn word_count(s: &str) -> usize {
         s.split_whitespace().count()
@@ -1440,27 +2074,42 @@ fn extract_title_from_document(document: &Html) -> Option<String> {
         }
     } else if cur_title.contains(": ") {
         let h_selector = Selector::parse("h1, h2").unwrap();
-        let trimmed_title = cur_title.trim();
-        let has_matching_heading = document
+        let headings: Vec<String> = document
             .select(&h_selector)
-            .any(|h| h.text().collect::<String>().trim() == trimmed_title);
-
-        if !has_matching_heading {
-            if let Some(last_colon_pos) = cur_title.rfind(':') {
-                let after_colon = cur_title[(last_colon_pos + 1)..].trim().to_string();
-                if word_count(&after_colon) < 3 {
-                    if let Some(first_colon_pos) = cur_title.find(':') {
-                        let after_first = cur_title[(first_colon_pos + 1)..].trim().to_string();
-                        let before_first = &cur_title[..first_colon_pos];
-
-                        if word_count(before_first) > 5 {
-                            cur_title = orig_title.clone();
-                        } else {
-                            cur_title = after_first;
-                        }
-                    }
+            .map(|h| utils::normalize_whitespace(h.text().collect::<String>().trim()))
+            .collect();
+        let trimmed_title = utils::normalize_whitespace(cur_title.trim());
+        let has_matching_heading = headings.contains(&trimmed_title);
+
+        // A heading repeating the text after a colon names the real headline. The text
+        // before a colon is not tried, as a logo heading often repeats the site name.
+        let matching_piece = if has_matching_heading {
+            None
+        } else {
+            cur_title
+                .match_indices(':')
+                .map(|(pos, _)| utils::normalize_whitespace(cur_title[(pos + 1)..].trim()))
+                .find(|piece| !piece.is_empty() && headings.contains(piece))
+        };
+
+        if let Some(piece) = matching_piece {
+            cur_title = piece;
+            title_matched_heading = true;
+        } else if !has_matching_heading {
+            // Without a heading repeating the whole title, the part after the colon is
+            // the title and the part before it a site or section name
+            if let (Some(first_colon_pos), Some(last_colon_pos)) =
+                (cur_title.find(':'), cur_title.rfind(':'))
+            {
+                let after_last = cur_title[(last_colon_pos + 1)..].trim().to_string();
+                if word_count(&after_last) < 3 {
+                    // Too short: split at the first colon instead
+                    cur_title = cur_title[(first_colon_pos + 1)..].trim().to_string();
+                } else if word_count(&cur_title[..first_colon_pos]) > 5 {
+                    // A long lead-in means the colon is part of the title itself
+                    cur_title = orig_title.clone();
                 } else {
-                    cur_title = after_colon;
+                    cur_title = after_last;
                 }
             }
         }
@@ -1469,7 +2118,9 @@ fn extract_title_from_document(document: &Html) -> Option<String> {
         let h1s: Vec<_> = document.select(&h1_selector).collect();
 
         if h1s.len() == 1 {
-            cur_title = h1s[0].text().collect::<String>().trim().to_string();
+            if let Some(heading) = heading_title_text(h1s[0]) {
+                cur_title = heading;
+            }
         }
     }
 
@@ -1479,7 +2130,7 @@ fn extract_title_from_document(document: &Html) -> Option<String> {
         .to_string();
 
     let cur_word_count = word_count(&cur_title);
-    if cur_word_count <= 4 {
+    if cur_word_count <= 4 && !title_matched_heading {
         let orig_without_sep = sep_regex.replace_all(&orig_title, " ").to_string();
         let orig_word_count = word_count(&orig_without_sep);
 
@@ -1491,6 +2142,55 @@ fn extract_title_from_document(document: &Html) -> Option<String> {
     Some(cur_title)
 }
 
+/// Title text of a heading used as a title fallback
+///
+/// Images in the heading (usually a site logo) don't contribute their alt text. An
+/// image-only heading falls back to the alt text, but only when it reads like a
+/// headline: at least four words and no mention of a logo.
+fn heading_title_text(heading: ElementRef) -> Option<String> {
+    let text = utils::normalize_whitespace(&heading.text().collect::<String>())
+        .trim()
+        .to_string();
+    if !text.is_empty() {
+        return Some(text);
+    }
+
+    static IMG_SELECTOR: Lazy<Selector> = Lazy::new(|| Selector::parse("img[alt]").unwrap());
+    heading
+        .select(&IMG_SELECTOR)
+        .filter_map(|img| img.value().attr("alt"))
+        .map(|alt| utils::normalize_whitespace(alt).trim().to_string())
+        .find(|alt| alt.split_whitespace().count() >= 4 && !alt.to_lowercase().contains("logo"))
+}
+
+/// Infer a publication date from a dated URL path
+///
+/// Many blogs encode the date in the path (`/2015/05/12/slug` or `/2015-05-12-slug`).
+/// Returns a date-only ISO 8601 string (`YYYY-MM-DD`) when one is found. This is a
+/// last-resort fallback and should only be used when no other date source matched.
+pub fn extract_date_from_url(url: &str) -> Option<String> {
+    static URL_DATE_REGEX: Lazy<regex::Regex> = Lazy::new(|| {
+        regex::Regex::new(r"(?:^|/)(\d{4})([/-])(\d{1,2})([/-])(\d{1,2})(?:$|[/\-_.])").unwrap()
+    });
+
+    let parsed = url::Url::parse(url).ok()?;
+    let caps = URL_DATE_REGEX.captures(parsed.path())?;
+
+    // Mixed separators such as `/2015/05-12` are more likely IDs than dates
+    if caps[2] != caps[4] {
+        return None;
+    }
+
+    let year: u32 = caps[1].parse().ok()?;
+    let month: u32 = caps[3].parse().ok()?;
+    let day: u32 = caps[5].parse().ok()?;
+    if !(1990..=2100).contains(&year) || !(1..=12).contains(&month) || !(1..=31).contains(&day) {
+        return None;
+    }
+
+    Some(format!("{year:04}-{month:02}-{day:02}"))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -1580,19 +2280,20 @@ mod tests {
     }
 
     #[test]
-    fn test_json_ld_image_array_extraction() {
+    fn test_json_ld_content_location() {
         let html = r#"
             <html>
                 <head>
                     <script type="application/ld+json">
                     {
                         "@context": "https://schema.org",
-                        "@type": "Article",
-                        "headline": "Test Article",
-                        "image": [
-                            "https://example.com/image1.jpg",
-                            "https://example.com/image2.jpg"
-                        ]
+                        "@type": "NewsArticle",
+                        "headline": "Harbor Reopens After Storm Repairs",
+                        "contentLocation": {
+                            "@type": "Place",
+                            "name": "Port Townsend, Washington"
+                        },
+                        "locationCreated": "Seattle"
                     }
                     </script>
                 </head>
@@ -1601,22 +2302,64 @@ mod tests {
 
         let document = Html::parse_document(html);
         let metadata = get_json_ld(&document);
+        assert_eq!(
+            metadata.location.as_deref(),
+            Some("Port Townsend, Washington")
+        );
 
+        let place = serde_json::json!({
+            "@type": "Place",
+            "address": {
+                "@type": "PostalAddress",
+                "addressLocality": "Port Townsend",
+                "addressRegion": "WA",
+                "addressCountry": { "@type": "Country", "name": "US" }
+            }
+        });
         assert_eq!(
-            metadata.image,
-            Some("https://example.com/image1.jpg".to_string())
+            json_ld_place_name(&place).as_deref(),
+            Some("Port Townsend, WA, US")
         );
     }
 
     #[test]
-    fn test_meta_tag_extraction() {
+    fn test_json_ld_image_array_extraction() {
         let html = r#"
             <html>
                 <head>
-                    <meta property="og:title" content="OG Title" />
-                    <meta name="author" content="Jane Smith" />
-                    <meta property="og:description" content="OG Description" />
-                </head>
+                    <script type="application/ld+json">
+                    {
+                        "@context": "https://schema.org",
+                        "@type": "Article",
+                        "headline": "Test Article",
+                        "image": [
+                            "https://example.com/image1.jpg",
+                            "https://example.com/image2.jpg"
+                        ]
+                    }
+                    </script>
+                </head>
+            </html>
+        "#;
+
+        let document = Html::parse_document(html);
+        let metadata = get_json_ld(&document);
+
+        assert_eq!(
+            metadata.image,
+            Some("https://example.com/image1.jpg".to_string())
+        );
+    }
+
+    #[test]
+    fn test_meta_tag_extraction() {
+        let html = r#"
+            <html>
+                <head>
+                    <meta property="og:title" content="OG Title" />
+                    <meta name="author" content="Jane Smith" />
+                    <meta property="og:description" content="OG Description" />
+                </head>
             </html>
         "#;
 
@@ -1629,6 +2372,59 @@ mod tests {
         assert_eq!(metadata.excerpt, Some("OG Description".to_string()));
     }
 
+    #[test]
+    fn test_microdata_published_time() {
+        let html = r#"
+            <html>
+                <head>
+                    <meta property="article:published_time" content="2020-01-01T00:00:00Z" />
+                </head>
+                <body itemscope itemtype="https://schema.org/NewsArticle">
+                    <time itemprop="datePublished" datetime="2024-03-05T09:30:00Z">March 5, 2024</time>
+                </body>
+            </html>
+        "#;
+
+        let document = Html::parse_document(html);
+        let metadata = get_article_metadata(&document, Metadata::default());
+        assert_eq!(
+            metadata.published_time,
+            Some("2024-03-05T09:30:00Z".to_string())
+        );
+
+        let html =
+            r#"<html><body><span itemprop="datePublished"> 5 March 2024 </span></body></html>"#;
+        let document = Html::parse_document(html);
+        let metadata = get_article_metadata(&document, Metadata::default());
+        assert_eq!(metadata.published_time, Some("5 March 2024".to_string()));
+    }
+
+    #[test]
+    fn test_tags_extraction() {
+        let html = r#"
+            <html>
+                <head>
+                    <meta property="article:tag" content="Climate" />
+                    <meta property="article:tag" content=" Energy Policy " />
+                    <meta property="article:tag" content="" />
+                    <meta name="keywords" content="energy policy, Solar,wind power, climate" />
+                    <script type="application/ld+json">
+                        {"@context": "https://schema.org", "@type": "NewsArticle", "keywords": ["Wind Power", "Grid"]}
+                    </script>
+                </head>
+            </html>
+        "#;
+
+        let document = Html::parse_document(html);
+        let json_ld = get_json_ld(&document);
+        let metadata = get_article_metadata(&document, json_ld);
+
+        assert_eq!(
+            metadata.tags,
+            vec!["Climate", "Energy Policy", "Solar", "wind power", "Grid"]
+        );
+    }
+
     #[test]
     fn test_og_image_extraction() {
         let html = r#"
@@ -1669,6 +2465,54 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_og_image_alt() {
+        let html = r#"
+            <html>
+                <head>
+                    <meta property="og:image" content="https://example.com/harbour.jpg" />
+                    <meta property="og:image:alt" content="Ships moored in the harbour at dusk" />
+                </head>
+            </html>
+        "#;
+        let document = Html::parse_document(html);
+        let metadata = get_article_metadata(&document, Metadata::default());
+        assert_eq!(
+            metadata.image.as_deref(),
+            Some("https://example.com/harbour.jpg")
+        );
+        assert_eq!(
+            metadata.image_alt.as_deref(),
+            Some("Ships moored in the harbour at dusk")
+        );
+
+        let html = r#"
+            <html>
+                <head>
+                    <script type="application/ld+json">
+                    {
+                        "@context": "https://schema.org",
+                        "@type": "NewsArticle",
+                        "headline": "Harbour reopens",
+                        "image": {
+                            "@type": "ImageObject",
+                            "url": "https://example.com/harbour.jpg",
+                            "caption": "The harbour after the storm"
+                        }
+                    }
+                    </script>
+                </head>
+            </html>
+        "#;
+        let document = Html::parse_document(html);
+        let json_ld = get_json_ld(&document);
+        let metadata = get_article_metadata(&document, json_ld);
+        assert_eq!(
+            metadata.image_alt.as_deref(),
+            Some("The harbour after the storm")
+        );
+    }
+
     #[test]
     fn test_twitter_image_extraction() {
         let html = r#"
@@ -1755,6 +2599,87 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_invisible_chars_stripped_from_metadata() {
+        let html = "<html><head>\
+            <title>\u{feff}Harbor Reopens After Storm Repairs</title>\
+            <meta name=\"author\" content=\"Maria\u{200b} Lopez\">\
+            <meta name=\"description\" content=\"\u{feff}Boats return to the marina.\">\
+            </head><body></body></html>";
+
+        let document = Html::parse_document(html);
+        let metadata = get_article_metadata(&document, Metadata::default());
+
+        assert_eq!(
+            metadata.title.as_deref(),
+            Some("Harbor Reopens After Storm Repairs")
+        );
+        assert_eq!(metadata.byline.as_deref(), Some("Maria Lopez"));
+        assert_eq!(
+            metadata.excerpt.as_deref(),
+            Some("Boats return to the marina.")
+        );
+    }
+
+    #[test]
+    fn test_multiple_article_author_metas() {
+        let html = r#"
+            <html>
+                <head>
+                    <meta property="article:author" content="Ann Lee" />
+                    <meta property="og:article:author" content="Bob Stone" />
+                    <meta property="article:author" content="https://example.com/authors/bob" />
+                    <meta property="article:author" content="Cara Diaz" />
+                    <meta property="article:author" content="ann lee" />
+                </head>
+            </html>
+        "#;
+
+        let document = Html::parse_document(html);
+        let metadata = get_article_metadata(&document, Metadata::default());
+
+        assert_eq!(
+            metadata.byline.as_deref(),
+            Some("Ann Lee, Bob Stone, Cara Diaz")
+        );
+    }
+
+    #[test]
+    fn test_itemprop_meta_extraction() {
+        let html = r#"
+            <html itemscope itemtype="https://schema.org/NewsArticle">
+                <head>
+                    <meta itemprop="name" content="Harbor Reopens After Storm Repairs" />
+                    <meta itemprop="description" content="Boats return to the marina after months of work." />
+                    <meta itemprop="datePublished" content="2024-05-02T08:00:00Z" />
+                    <meta itemprop="author" content="Maria Lopez" />
+                </head>
+                <body>
+                    <div itemprop="publisher" itemscope itemtype="https://schema.org/Organization">
+                        <meta itemprop="name" content="Coastal Gazette" />
+                    </div>
+                </body>
+            </html>
+        "#;
+
+        let document = Html::parse_document(html);
+        let metadata = get_article_metadata(&document, Metadata::default());
+
+        assert_eq!(metadata.byline.as_deref(), Some("Maria Lopez"));
+        assert_eq!(
+            metadata.published_time.as_deref(),
+            Some("2024-05-02T08:00:00Z")
+        );
+        assert_eq!(
+            metadata.excerpt.as_deref(),
+            Some("Boats return to the marina after months of work.")
+        );
+        assert_eq!(
+            metadata.title.as_deref(),
+            Some("Harbor Reopens After Storm Repairs")
+        );
+    }
+
     #[test]
     fn test_thumbnail_meta_extraction() {
         let html = r#"
@@ -1811,21 +2736,52 @@ mod tests {
 
     #[test]
     fn test_title_extraction_colon() {
-        let html = r#"
-            <html>
-                <head>
-                    <title>Site Name: Article Title</title>
-                </head>
-            </html>
-        "#;
+        let cases = [
+            // Short site name before the colon
+            (
+                "Example News: Council approves new budget for harbour repairs",
+                "Council approves new budget for harbour repairs",
+            ),
+            // Too few words would be left, so the full title is kept
+            ("Site Name: Article Title", "Site Name: Article Title"),
+            ("Opinion: Why X matters", "Opinion: Why X matters"),
+            // Splits at the first colon when the last one leaves a fragment
+            (
+                "Review: The Harbour at Night: A Novel",
+                "The Harbour at Night: A Novel",
+            ),
+            // A long lead-in means the colon belongs to the title
+            (
+                "What the council decided on Tuesday night: the harbour will reopen soon",
+                "What the council decided on Tuesday night: the harbour will reopen soon",
+            ),
+        ];
+
+        for (title, expected) in cases {
+            let html = format!("<html><head><title>{title}</title></head><body></body></html>");
+            let document = Html::parse_document(&html);
+            assert_eq!(
+                extract_title_from_document(&document).as_deref(),
+                Some(expected),
+                "title {title:?}"
+            );
+        }
 
+        // A heading repeating the full title keeps it whole
+        let html = "<html><head><title>Example News: Council approves new budget</title></head><body><h1>Example News: Council approves new budget</h1></body></html>";
         let document = Html::parse_document(html);
-        let title = extract_title_from_document(&document);
+        assert_eq!(
+            extract_title_from_document(&document).as_deref(),
+            Some("Example News: Council approves new budget")
+        );
 
-        // TODO: Colon separator extraction needs refinement
-        // For now, just verify we got a title
-        assert!(title.is_some());
-        assert!(title.as_ref().unwrap().len() > 0);
+        // A heading repeating the part after the colon is the headline, however short
+        let html = "<html><head><title>Section: Real Headline</title></head><body><h1>Real  Headline</h1></body></html>";
+        let document = Html::parse_document(html);
+        assert_eq!(
+            extract_title_from_document(&document).as_deref(),
+            Some("Real Headline")
+        );
     }
 
     #[test]
@@ -2065,11 +3021,13 @@ mod tests {
 
     #[test]
     fn test_dom_byline_overrides_agency_credit() {
-        let html = r#"
+        for agency in ["AFP", "Par AFP", "(AFP)", "EFE", "Xinhua", "PTI", "AFP/DPA"] {
+            let html = format!(
+                r#"
             <html>
                 <head>
                     <meta property="og:title" content="Titre" />
-                    <meta name="author" content="AFP" />
+                    <meta name="author" content="{agency}" />
                 </head>
                 <body>
                     <article>
@@ -2078,10 +3036,16 @@ mod tests {
                     </article>
                 </body>
             </html>
-        "#;
-        let document = Html::parse_document(html);
-        let metadata = get_article_metadata(&document, Metadata::default());
-        assert_eq!(metadata.byline, Some("Par Sébastien Farcis".to_string()));
+        "#
+            );
+            let document = Html::parse_document(&html);
+            let metadata = get_article_metadata(&document, Metadata::default());
+            assert_eq!(
+                metadata.byline,
+                Some("Par Sébastien Farcis".to_string()),
+                "{agency}"
+            );
+        }
     }
 
     #[test]
@@ -2115,7 +3079,7 @@ mod tests {
             "pb-byline element not found"
         );
         let elem = document.select(&selector).next().unwrap();
-        let text = collect_byline_candidate_text(elem.clone());
+        let text = collect_byline_candidate_text(elem);
         assert!(
             text.contains("Erin Cunningham"),
             "pb-byline text was {:?}",
@@ -2124,4 +3088,342 @@ mod tests {
         let dom_byline = extract_byline_from_document(&document).expect("should detect DOM byline");
         assert_eq!(dom_byline.text, "By Erin Cunningham");
     }
+
+    #[test]
+    fn test_extract_date_from_url() {
+        assert_eq!(
+            extract_date_from_url("https://blog.example.com/2015/05/12/some-slug/"),
+            Some("2015-05-12".to_string())
+        );
+        assert_eq!(
+            extract_date_from_url("https://example.com/posts/2019-11-3-notes.html"),
+            Some("2019-11-03".to_string())
+        );
+        assert_eq!(
+            extract_date_from_url("https://example.com/2015/13/40/slug"),
+            None
+        );
+        assert_eq!(
+            extract_date_from_url("https://example.com/item/12345678"),
+            None
+        );
+    }
+
+    #[test]
+    fn test_language_from_og_locale() {
+        let html = r#"
+            <html>
+                <head>
+                    <meta property="og:locale" content="en_US" />
+                    <title>Locale only</title>
+                </head>
+                <body><p>Content</p></body>
+            </html>
+        "#;
+        let document = Html::parse_document(html);
+        let metadata = get_article_metadata(&document, Metadata::default());
+        assert_eq!(metadata.lang, Some("en".to_string()));
+
+        assert_eq!(language_from_locale("fr_FR"), Some("fr".to_string()));
+        assert_eq!(language_from_locale("not a locale"), None);
+    }
+
+    #[test]
+    fn test_language_tag_normalization() {
+        let cases = [
+            ("EN-us", Some("en-US")),
+            ("en, fr", Some("en")),
+            (" pt_br ", Some("pt-BR")),
+            ("zh-hant-TW", Some("zh-Hant-TW")),
+            ("es-419", Some("es-419")),
+            ("", None),
+            ("12", None),
+        ];
+        for (value, expected) in cases {
+            assert_eq!(
+                normalize_language_tag(value).as_deref(),
+                expected,
+                "lang {value:?}"
+            );
+        }
+
+        let html = r#"<html lang="EN-us"><head><title>Test</title></head><body></body></html>"#;
+        let document = Html::parse_document(html);
+        let metadata = get_article_metadata(&document, Metadata::default());
+        assert_eq!(metadata.lang.as_deref(), Some("en-US"));
+    }
+
+    #[test]
+    fn test_json_ld_multiple_blocks_precedence() {
+        let breadcrumbs = r#"<script type="application/ld+json">{"@context":"https://schema.org","@type":"BreadcrumbList","itemListElement":[{"@type":"ListItem","position":1,"name":"World"}]}</script>"#;
+        let organization = r#"<script type="application/ld+json">{"@context":"https://schema.org","@type":"Organization","name":"Example Media Group"}</script>"#;
+        let article = r#"<script type="application/ld+json">{"@context":"https://schema.org","@type":"NewsArticle","headline":"Harbour reopens after storm","author":{"@type":"Person","name":"Jane Doe"},"publisher":{"@type":"Organization","name":"Example News"}}</script>"#;
+        let second_article = r#"<script type="application/ld+json">{"@context":"https://schema.org","@type":"Article","headline":"Related: storm season outlook","author":{"@type":"Person","name":"John Roe"}}</script>"#;
+
+        for blocks in [
+            [breadcrumbs, organization, article, second_article],
+            [organization, breadcrumbs, article, second_article],
+            [article, second_article, organization, breadcrumbs],
+        ] {
+            let html = format!("<html><head>{}</head><body></body></html>", blocks.concat());
+            let metadata = get_json_ld(&Html::parse_document(&html));
+            assert_eq!(
+                metadata.title.as_deref(),
+                Some("Harbour reopens after storm")
+            );
+            assert_eq!(metadata.byline.as_deref(), Some("Jane Doe"));
+            assert_eq!(metadata.site_name.as_deref(), Some("Example News"));
+        }
+
+        let html = format!("<html><head>{organization}{second_article}</head><body></body></html>");
+        let document = Html::parse_document(&html);
+        let json_ld = get_json_ld(&document);
+        assert_eq!(
+            json_ld.title.as_deref(),
+            Some("Related: storm season outlook")
+        );
+        assert_eq!(json_ld.site_name, None);
+        let metadata = get_article_metadata(&document, json_ld);
+        assert_eq!(metadata.site_name.as_deref(), Some("Example Media Group"));
+    }
+
+    #[test]
+    fn test_title_from_h1_with_logo() {
+        let title_for = |h1: &str| {
+            let html =
+                format!("<html><head><title>Home</title></head><body><h1>{h1}</h1></body></html>");
+            extract_title_from_document(&Html::parse_document(&html))
+        };
+
+        assert_eq!(
+            title_for(r#"<img src="/logo.png" alt="Daily Planet logo"> Storm closes the harbour for three days"#)
+                .as_deref(),
+            Some("Storm closes the harbour for three days")
+        );
+        assert_eq!(
+            title_for(r#"<img src="/headline.png" alt="Storm closes the harbour for three days">"#)
+                .as_deref(),
+            Some("Storm closes the harbour for three days")
+        );
+        assert_eq!(
+            title_for(r#"<img src="/logo.png" alt="Daily Planet logo">"#).as_deref(),
+            Some("Home")
+        );
+    }
+
+    #[test]
+    fn test_raw_title_keeps_site_name() {
+        let html = r#"<html><head>
+            <title> Council approves new budget for next year | Example News </title>
+        </head><body></body></html>"#;
+        let document = Html::parse_document(html);
+        let metadata = get_article_metadata(&document, Metadata::default());
+        assert_eq!(
+            metadata.title.as_deref(),
+            Some("Council approves new budget for next year")
+        );
+        assert_eq!(
+            metadata.raw_title.as_deref(),
+            Some("Council approves new budget for next year | Example News")
+        );
+
+        let document = Html::parse_document("<html><head></head><body></body></html>");
+        let metadata = get_article_metadata(&document, Metadata::default());
+        assert_eq!(metadata.raw_title, None);
+    }
+
+    #[test]
+    fn test_byline_from_title_as_last_resort() {
+        let byline_for = |title: &str, body: &str| {
+            let html =
+                format!("<html><head><title>{title}</title></head><body>{body}</body></html>");
+            get_article_metadata(&Html::parse_document(&html), Metadata::default()).byline
+        };
+
+        assert_eq!(
+            byline_for("Why Transit Matters - by Jane Doe - Example News", "").as_deref(),
+            Some("Jane Doe")
+        );
+        // Other byline signals win, and "by" inside the headline is not an author
+        assert_eq!(
+            byline_for(
+                "Why Transit Matters - by Jane Doe - Example News",
+                r#"<a rel="author" href="/authors/jsmith">John Smith</a>"#
+            )
+            .as_deref(),
+            Some("John Smith")
+        );
+        assert_eq!(byline_for("Bitten by a Shark - Example News", ""), None);
+        assert_eq!(byline_for("Transit Matters | By the Numbers", ""), None);
+    }
+
+    #[test]
+    fn test_author_image_from_author_box() {
+        let html = r#"<html><head></head><body>
+            <div class="share"><img src="/icons/twitter.png" width="16" height="16"></div>
+            <div class="author-box">
+                <img src="/avatars/jane-doe.jpg" width="96" height="96" alt="">
+                <span class="byline">By <a rel="author" href="/authors/jdoe">Jane Doe</a></span>
+            </div>
+            <p>Article text.</p>
+        </body></html>"#;
+        let metadata = get_article_metadata(&Html::parse_document(html), Metadata::default());
+        assert!(metadata.byline.is_some());
+        assert_eq!(
+            metadata.author_image.as_deref(),
+            Some("/avatars/jane-doe.jpg")
+        );
+
+        // Icons in the byline are not avatars
+        let html = r#"<html><head></head><body>
+            <p class="byline"><img src="/img/pen-icon.svg"> By Jane Doe</p>
+        </body></html>"#;
+        let metadata = get_article_metadata(&Html::parse_document(html), Metadata::default());
+        assert!(metadata.byline.is_some());
+        assert_eq!(metadata.author_image, None);
+
+        // An author box that did not produce the byline is not used
+        let html = r#"<html><head></head><body>
+            <p class="byline">By Jane Doe</p>
+            <div class="sidebar"><div class="author-box">
+                <img src="/avatars/jane-doe.jpg" width="96" height="96" alt="">
+                <a rel="author" href="/authors/jdoe">Jane Doe</a>
+            </div></div>
+        </body></html>"#;
+        let metadata = get_article_metadata(&Html::parse_document(html), Metadata::default());
+        assert_eq!(metadata.byline.as_deref(), Some("Jane Doe"));
+        assert_eq!(metadata.author_image, None);
+    }
+
+    #[test]
+    fn test_robots_directive() {
+        let html = r#"<html><head>
+            <meta name="ROBOTS" content="noindex,  nofollow">
+            <meta name="googlebot" content="noarchive">
+        </head><body></body></html>"#;
+        let document = Html::parse_document(html);
+        let metadata = get_article_metadata(&document, Metadata::default());
+        assert_eq!(metadata.robots.as_deref(), Some("noindex, nofollow"));
+
+        let document = Html::parse_document("<html><head></head><body></body></html>");
+        let metadata = get_article_metadata(&document, Metadata::default());
+        assert_eq!(metadata.robots, None);
+    }
+
+    #[test]
+    fn test_updated_label() {
+        let html = r#"<html><head>
+            <meta property="article:published_time" content="2024-03-01T09:00:00Z">
+            </head><body>
+            <p class="dateline">Published March 1, 2024</p>
+            <p><span class="story-updated">Updated 3:45pm</span></p>
+            <p>Updated rules were announced by the council on Friday after a long debate.</p>
+        </body></html>"#;
+        let document = Html::parse_document(html);
+        let metadata = get_article_metadata(&document, get_json_ld(&document));
+        assert_eq!(metadata.updated_label.as_deref(), Some("Updated 3:45pm"));
+        assert_eq!(
+            metadata.published_time.as_deref(),
+            Some("2024-03-01T09:00:00Z")
+        );
+
+        let html =
+            "<html><body><div><em>Updated:</em> <time>March 2, 2024</time></div></body></html>";
+        let document = Html::parse_document(html);
+        let metadata = get_article_metadata(&document, get_json_ld(&document));
+        assert_eq!(metadata.updated_label.as_deref(), Some("Updated:"));
+
+        let html = "<html><body><p>Nothing was updated here.</p></body></html>";
+        let document = Html::parse_document(html);
+        let metadata = get_article_metadata(&document, get_json_ld(&document));
+        assert_eq!(metadata.updated_label, None);
+
+        // "updated" must be a whole word of the class or id
+        let html = r#"<html><body><div class="autoupdatedfeed">Live scores</div><span id="lastUpdated">Mar 2</span></body></html>"#;
+        let document = Html::parse_document(html);
+        let metadata = get_article_metadata(&document, get_json_ld(&document));
+        assert_eq!(metadata.updated_label.as_deref(), Some("Mar 2"));
+    }
+
+    #[test]
+    fn test_json_ld_recipe() {
+        let recipe = r#"<script type="application/ld+json">{
+            "@context": "https://schema.org",
+            "@graph": [
+                {"@type": "WebSite", "name": "Example Kitchen"},
+                {
+                    "@type": "Recipe",
+                    "name": "Weeknight Tomato Soup",
+                    "prepTime": "PT10M",
+                    "cookTime": "PT25M",
+                    "totalTime": "PT35M",
+                    "recipeIngredient": ["2 tbsp olive oil", "1 onion, diced", "800g canned tomatoes"],
+                    "recipeInstructions": [
+                        {"@type": "HowToStep", "text": "Soften the onion in the oil."},
+                        {"@type": "HowToSection", "name": "Finish", "itemListElement": [
+                            {"@type": "HowToStep", "text": "Add the tomatoes and simmer."},
+                            {"@type": "HowToStep", "text": "Blend until smooth."}
+                        ]}
+                    ]
+                }
+            ]
+        }</script>"#;
+        let html = format!("<html><head>{recipe}</head><body></body></html>");
+        let document = Html::parse_document(&html);
+        let metadata = get_article_metadata(&document, get_json_ld(&document));
+
+        let recipe = metadata.recipe.expect("recipe");
+        assert_eq!(recipe.name.as_deref(), Some("Weeknight Tomato Soup"));
+        assert_eq!(
+            recipe.ingredients,
+            vec!["2 tbsp olive oil", "1 onion, diced", "800g canned tomatoes"]
+        );
+        assert_eq!(
+            recipe.instructions,
+            vec![
+                "Soften the onion in the oil.",
+                "Add the tomatoes and simmer.",
+                "Blend until smooth."
+            ]
+        );
+        assert_eq!(recipe.prep_time.as_deref(), Some("PT10M"));
+        assert_eq!(recipe.cook_time.as_deref(), Some("PT25M"));
+        assert_eq!(recipe.total_time.as_deref(), Some("PT35M"));
+    }
+
+    #[test]
+    fn test_equivalent_meta_and_dom_bylines() {
+        let html = r#"<html><head><meta name="author" content="Jane Q. Doe"></head><body>
+            <article><p class="byline">By Jane Doe</p><p>The harbour reopened on Tuesday.</p></article>
+        </body></html>"#;
+        let document = Html::parse_document(html);
+        let metadata = get_article_metadata(&document, get_json_ld(&document));
+        assert_eq!(metadata.byline.as_deref(), Some("By Jane Doe"));
+
+        assert!(!should_prefer_dom_byline(
+            "Jane Doe",
+            "By Jane Q. Doe",
+            DomBylineConfidence::High
+        ));
+    }
+
+    #[test]
+    fn test_json_ld_string_author() {
+        let html = r#"<html><head><script type="application/ld+json">{"@context":"https://schema.org","@type":"NewsArticle","headline":"Harbour reopens","author":"Jane Doe"}</script></head><body></body></html>"#;
+        let metadata = get_json_ld(&Html::parse_document(html));
+        assert_eq!(metadata.byline.as_deref(), Some("Jane Doe"));
+
+        let html = r#"<html><head><script type="application/ld+json">{"@context":"https://schema.org","@type":"NewsArticle","headline":"Harbour reopens","author":["Jane Doe",{"@type":"Person","name":"John Roe"}]}</script></head><body></body></html>"#;
+        let metadata = get_json_ld(&Html::parse_document(html));
+        assert_eq!(metadata.byline.as_deref(), Some("Jane Doe, John Roe"));
+
+        // Profile URLs are not names, whichever form they come in
+        let html = r#"<html><head><script type="application/ld+json">{"@context":"https://schema.org","@type":"NewsArticle","headline":"Harbour reopens","author":"https://example.com/authors/jdoe"}</script></head><body></body></html>"#;
+        let metadata = get_json_ld(&Html::parse_document(html));
+        assert_eq!(metadata.byline, None);
+
+        let html = r#"<html><head><script type="application/ld+json">{"@context":"https://schema.org","@type":"NewsArticle","headline":"Harbour reopens","author":[{"@type":"Person","name":"https://example.com/authors/jdoe"},"  ","John Roe"]}</script></head><body></body></html>"#;
+        let metadata = get_json_ld(&Html::parse_document(html));
+        assert_eq!(metadata.byline.as_deref(), Some("John Roe"));
+    }
 }
@@ -24,6 +24,57 @@
 //! ```
 
 use regex::Regex;
+use std::time::Duration;
+
+/// Output formatting of [`Article::content`](crate::Article::content).
+///
+/// Selected with [`ReadabilityOptions::serializer`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Serializer {
+    /// HTML5 serialization as produced by the cleaning passes.
+    ///
+    /// Void elements are written without a closing slash (`<br>`), attributes are
+    /// sorted by name unless [`ReadabilityOptions::attribute_order`] lists them, and
+    /// whitespace between elements is kept as in the source.
+    #[default]
+    ScraperDefault,
+
+    /// Well-formed XHTML, with void elements self-closed (`<br />`, `<img ... />`).
+    ///
+    /// Written from the parsed content: text and attribute values are escaped for XML,
+    /// non-breaking spaces are written as characters rather than `&nbsp;`, `<svg>` and
+    /// `<math>` carry their namespace, and comments are dropped. Attributes are ordered
+    /// and whitespace is kept as in `ScraperDefault`. This follows the formatting of
+    /// Mozilla's `expected.html` fixtures, so output can be compared with them where the
+    /// content agrees.
+    Xhtml,
+
+    /// Like `ScraperDefault`, with insignificant whitespace removed.
+    ///
+    /// Whitespace is collapsed as by
+    /// [`ReadabilityOptions::minify_whitespace`]; `<pre>`, `<code>` and `<textarea>`
+    /// content is left untouched.
+    Compact,
+}
+
+/// What to do with an `<iframe>` whose source is not an allowed video.
+///
+/// Selected with [`ReadabilityOptions::iframe_fallback`]. With `LinkPlaceholder`,
+/// iframes matching the video allowlist are kept as they are.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum IframeFallback {
+    /// Drop every iframe, allowlisted videos included, as the parser always has.
+    #[default]
+    Remove,
+
+    /// Replace the iframe with a link to its `src`, so readers can still open the
+    /// embedded map, form or widget. Iframes without an `http` or `https` `src` are
+    /// dropped.
+    LinkPlaceholder,
+
+    /// Keep the iframe as it is.
+    Keep,
+}
 
 /// Configuration options for the Readability parser.
 ///
@@ -144,6 +195,361 @@ pub struct ReadabilityOptions {
     ///
     /// Default: `0.0`
     pub link_density_modifier: f64,
+
+    /// Drop inline `data:` URI images from the output.
+    ///
+    /// When `true`, `<img>` elements whose `src` is a `data:` URI larger than
+    /// [`max_data_uri_bytes`](Self::max_data_uri_bytes) are removed during cleaning.
+    /// Small inline images such as icons are kept.
+    ///
+    /// Default: `false`
+    pub drop_data_uri_images: bool,
+
+    /// Size threshold in bytes for dropping `data:` URI images.
+    ///
+    /// Only used when [`drop_data_uri_images`](Self::drop_data_uri_images) is enabled.
+    /// Images whose `src` attribute is at most this many bytes are kept.
+    ///
+    /// Default: `4096`
+    pub max_data_uri_bytes: usize,
+
+    /// Replace dropped `data:` URI images with their alt text.
+    ///
+    /// When `true`, a dropped image is replaced by its `alt` text (if any) instead of
+    /// being removed outright, so the surrounding text still reads naturally.
+    ///
+    /// Default: `false`
+    pub data_uri_placeholder: bool,
+
+    /// Collapse insignificant whitespace in the output HTML.
+    ///
+    /// When `true`, runs of whitespace between tags are collapsed and whitespace next to
+    /// block-level elements is removed. Content inside `<pre>` and `<code>` is preserved,
+    /// so the rendered output is unchanged while the byte size shrinks.
+    ///
+    /// Default: `false`
+    pub minify_whitespace: bool,
+
+    /// Keep inline `<svg>` illustrations in the output.
+    ///
+    /// When `true`, containers holding an inline SVG whose width or height is at least
+    /// 100 pixels survive conditional cleaning, so charts and diagrams are not removed
+    /// along with their otherwise empty wrappers. Small icon SVGs get no special
+    /// treatment. Kept SVGs are reduced to an allowlist of drawing elements and
+    /// attributes: scripts, `foreignObject`, animations, event handlers and links to
+    /// anything but `http`/`https` URLs are stripped.
+    ///
+    /// Default: `false`
+    pub keep_inline_svg: bool,
+
+    /// Prefer the on-page `<h1>` over metadata for the article title.
+    ///
+    /// When `true` and the extracted content contains exactly one `<h1>` with text,
+    /// that heading becomes [`Article::title`](crate::Article::title). The metadata
+    /// title stays available in [`Article::meta_title`](crate::Article::meta_title).
+    /// Useful for sites whose meta titles are stuffed with SEO keywords. Pair with
+    /// `remove_title_heading` to drop that heading from the content.
+    ///
+    /// Default: `false`
+    pub prefer_heading_title: bool,
+
+    /// Remove the byline element from the extracted content.
+    ///
+    /// The byline is already reported in [`Article::byline`](crate::Article::byline), so
+    /// keeping it in the body duplicates it. Only an element marked as a byline (by class,
+    /// id, `rel="author"` or itemprop) whose text matches the detected byline is removed.
+    ///
+    /// Default: `true`
+    pub remove_byline_from_content: bool,
+
+    /// Collect outbound links from the content into
+    /// [`Article::links`](crate::Article::links).
+    ///
+    /// Links are resolved against the base URL and deduplicated. Off by default to avoid
+    /// the extra pass and allocation when links are not needed.
+    ///
+    /// Default: `false`
+    pub collect_links: bool,
+
+    /// Language of the content, used as a hint for language-specific cleaning.
+    ///
+    /// A primary language subtag such as `"fr"` or `"en-GB"`. When set, the byline prefix
+    /// (`"Par"` for French, `"Von"` for German, ...) is stripped from the detected byline,
+    /// along with a trailing date written with that language's month names. The hint
+    /// overrides document detection for these heuristics only; `Article::lang` still
+    /// reports the language declared by the document.
+    ///
+    /// Default: `None`
+    pub content_language: Option<String>,
+
+    /// Remove the heading that repeats the title from the content.
+    ///
+    /// After the title is chosen, the first `<h1>` or `<h2>` in the content whose text matches
+    /// it (ignoring case and punctuation) is removed. Combined with `prefer_heading_title`,
+    /// the content heading becomes the title and is then dropped from the body, so it
+    /// appears only once.
+    ///
+    /// Default: `false`
+    pub remove_title_heading: bool,
+
+    /// CSS selectors for the article container, tried before scoring.
+    ///
+    /// For sites with a known layout, such as `"#article-body"`. The first selector that
+    /// matches an element with at least `char_threshold` characters of text is used as the
+    /// content directly, skipping candidate scoring. When nothing matches, extraction
+    /// falls back to scoring. Invalid selectors are rejected by
+    /// [`Readability::new`](crate::Readability::new) with
+    /// [`ReadabilityError::InvalidSelector`](crate::ReadabilityError::InvalidSelector).
+    ///
+    /// Default: empty
+    pub content_selectors: Vec<String>,
+
+    /// Tag names scored as paragraphs.
+    ///
+    /// Scoring centers on paragraphs: each one long enough becomes a candidate whose length
+    /// and commas add to its ancestors' scores, and a short paragraph next to the chosen
+    /// content is kept with it. Sites that write prose in `<li>` or `<section>` elements
+    /// can list those tags here so they count the same way. Tags already scored as
+    /// containers, such as `section`, `div` or `td`, are not added as candidates a second
+    /// time; listing them only lets short ones join the content as siblings. An empty
+    /// list means `p`. Entries that are not plain tag names are rejected by
+    /// [`Readability::new`](crate::Readability::new) with
+    /// [`ReadabilityError::InvalidSelector`](crate::ReadabilityError::InvalidSelector).
+    ///
+    /// Default: `vec!["p"]`
+    pub paragraph_like_tags: Vec<String>,
+
+    /// Keep `aria-*` and `role` attributes on elements in the content.
+    ///
+    /// These carry accessibility information, such as a figure's `aria-label`, for consumers
+    /// that re-render content in accessible readers. When disabled they are stripped from
+    /// the content.
+    ///
+    /// Default: `true`
+    pub preserve_aria: bool,
+
+    /// Wrap the cleaned content in an `<article>` element.
+    ///
+    /// The element carries `lang` and `dir` attributes from the extracted metadata, giving
+    /// drop-in HTML5 markup. When the content already has a single `<div>` or `<article>`
+    /// wrapper, that wrapper is replaced rather than nested.
+    ///
+    /// Default: `false`
+    pub wrap_in_article: bool,
+
+    /// Remove a breadcrumb trail, such as "Home › News › World", that leads the content.
+    ///
+    /// Only the first block of the content is considered, and only when it is made of links
+    /// split by breadcrumb separators (`›`, `»`, `/`, `>`, `→` or `|`). Off by default to
+    /// match Mozilla's Readability, which keeps such trails.
+    ///
+    /// Default: `false`
+    pub remove_leading_breadcrumb: bool,
+
+    /// Output formatting of the article HTML.
+    ///
+    /// See [`Serializer`] for what each mode guarantees. Text content is the same in every
+    /// mode.
+    ///
+    /// Default: `Serializer::ScraperDefault`
+    pub serializer: Serializer,
+
+    /// Attribute names written first on every element, in this order.
+    ///
+    /// The remaining attributes follow, sorted by name, in every [`Serializer`] mode.
+    /// Names match case-insensitively. Lets output line up with fixtures that write, for
+    /// example, `href` before `class`; the order attributes had in the source is not
+    /// kept by the parser and cannot be restored.
+    ///
+    /// Default: empty, all attributes sorted by name
+    pub attribute_order: Vec<String>,
+
+    /// Copy an image's caption into its `alt` attribute when `alt` is missing.
+    ///
+    /// The caption is the `<figcaption>` of the enclosing `<figure>`, or a following sibling
+    /// whose class mentions "caption". Existing `alt` attributes, including empty ones, are
+    /// never overwritten.
+    ///
+    /// Default: `false`
+    pub caption_to_alt: bool,
+
+    /// Record the blocks removed by the cleaning passes in
+    /// [`ParseStats::removed_blocks`](crate::ParseStats::removed_blocks).
+    ///
+    /// Useful when tuning site-specific rules, to spot removal that ate real content. Only
+    /// [`Readability::parse_with_stats`](crate::Readability::parse_with_stats) exposes the
+    /// list.
+    ///
+    /// Default: `false`
+    pub trace_removed_blocks: bool,
+
+    /// Return the top candidate alone, without merging in its siblings.
+    ///
+    /// For tightly scoped extraction, such as a single comment or answer, where neighbouring
+    /// content would only add noise.
+    ///
+    /// Default: `false`
+    pub disable_sibling_aggregation: bool,
+
+    /// How to treat iframes that do not match the video allowlist.
+    ///
+    /// See [`IframeFallback`] for the available modes.
+    ///
+    /// Default: `IframeFallback::Remove`
+    pub iframe_fallback: IframeFallback,
+
+    /// Keep at most this many images in the content, removing the rest.
+    ///
+    /// Images are counted in document order. The lead image ([`Article::image`](crate::Article::image))
+    /// is kept even beyond the limit. A `<figure>` or `<picture>` left without an image is
+    /// removed with it. `0` keeps every image.
+    ///
+    /// Default: `0` (unlimited)
+    pub max_images: usize,
+
+    /// Fraction of its score a candidate loses when it sits in a sponsored block.
+    ///
+    /// A block looks sponsored when its class or id mentions sponsored, promoted or paid
+    /// partner content, or when it opens with a label such as "Sponsored" or "Paid
+    /// Partnership". `0.0` disables the penalty; `1.0` rules such blocks out. Off by
+    /// default to keep scoring the same as Mozilla's Readability; `0.5` is a reasonable
+    /// setting for pages with native advertising.
+    ///
+    /// Default: `0.0`
+    pub sponsored_penalty: f64,
+
+    /// Render footnote references as Markdown footnotes (`[^1]`).
+    ///
+    /// When set, footnotes in the content (a `<sup>` link or `role="doc-noteref"` link to a
+    /// list item or `role="doc-footnote"` element) are collected into `Article::footnotes`.
+    /// [`Article::to_markdown`](crate::Article::to_markdown) then writes references as
+    /// `[^n]` and the notes as a definitions block at the end, instead of leaving the
+    /// numbers inline and the notes list in place.
+    ///
+    /// Default: `false`
+    pub markdown_footnotes: bool,
+
+    /// Maximum number of consecutive `<br>` elements kept in the content.
+    ///
+    /// Runs of `<br>` left after the paragraph conversion (separated only by whitespace)
+    /// are cut down to this many, so stray gaps don't open up in the text. Line breaks
+    /// inside `<pre>` are left alone. `0` disables the cap.
+    ///
+    /// Default: `1`
+    pub max_consecutive_brs: usize,
+
+    /// Keep ruby annotations (furigana) in the content.
+    ///
+    /// `<ruby>`, `<rt>` and `<rp>` survive cleaning, and
+    /// [`Article::to_text`](crate::Article::to_text) renders them as `漢字(かんじ)`. When
+    /// disabled, the `<rt>` and `<rp>` annotations are removed and only the base text is
+    /// kept.
+    ///
+    /// Default: `true`
+    pub preserve_ruby: bool,
+
+    /// Minimum number of paragraphs the extracted content must have.
+    ///
+    /// Counted after cleaning: `<p>`, `<pre>` and `<blockquote>` elements with text, not
+    /// counting those inside a `<figure>` or `<figcaption>`. Content with fewer is treated as
+    /// no article, so a lone caption long enough to pass `char_threshold` is not returned as
+    /// a stub. `0` disables the check.
+    ///
+    /// Default: `1`
+    pub min_paragraphs: usize,
+
+    /// Minimum content score the top candidate needs to be accepted.
+    ///
+    /// On non-article pages even the best candidate can be weak; when its score is below
+    /// this floor, that extraction attempt yields nothing, and when no attempt clears it,
+    /// parsing returns `None` instead of emitting junk. Matches from `content_selectors` are
+    /// trusted and skip the check. `0.0` accepts any candidate.
+    ///
+    /// Default: `0.0`
+    pub min_candidate_score: f64,
+
+    /// Annotate content blocks with their position in the original document.
+    ///
+    /// Before extraction, every paragraph, heading, list item, `<pre>`, `<blockquote>`,
+    /// `<figure>` and `<table>` in the source gets a `data-pos` attribute holding its index in
+    /// document order, starting at `0`. The blocks that survive extraction keep it, so content
+    /// can be mapped back to the page for scroll syncing or highlighting. Blocks created during
+    /// extraction, such as paragraphs converted from `<div>`s, have none.
+    ///
+    /// Default: `false`
+    pub annotate_positions: bool,
+
+    /// Collect the source location of each kept block into
+    /// [`Article::source_paths`](crate::Article::source_paths).
+    ///
+    /// The blocks numbered by `annotate_positions` are numbered in the source markup before
+    /// any cleaning, and each one that survives extraction is listed as a CSS path into the
+    /// source document, so a browser extension can highlight the kept nodes on the live
+    /// page. The `data-pos` attributes are removed from `content` again unless
+    /// `annotate_positions` is also set, in which case their indices follow the same
+    /// numbering.
+    ///
+    /// Default: `false`
+    pub collect_source_paths: bool,
+
+    /// Record the original tag name of `<div>`s turned into `<p>`s.
+    ///
+    /// A `<div>` with no block-level children is turned into a `<p>` when the content is
+    /// assembled; with this set, the new element carries `data-orig-tag="div"`. Useful
+    /// for debugging extraction and for re-renderers that need the source structure.
+    ///
+    /// Only that conversion is annotated. `<center>` rewritten to `<div>` during
+    /// preprocessing, paragraphs built from `<br>` runs, the `<article>` added by
+    /// [`wrap_in_article`](Self::wrap_in_article) and unwrapped wrapper elements carry
+    /// no marker.
+    ///
+    /// Default: `false`
+    pub annotate_original_tags: bool,
+
+    /// Move floated callouts out of the middle of the text.
+    ///
+    /// A box floated beside the article (an inline `float` style, or a class such as
+    /// `float-right`, `pull-left` or `callout`) often sits mid-article in the source, so
+    /// source order splits a paragraph run around it. With this set, such a box is moved
+    /// after the text that follows it in its container.
+    ///
+    /// Default: `false`
+    pub correct_float_order: bool,
+
+    /// Time budget for a single parse.
+    ///
+    /// The budget is checked between phases (metadata, scoring, cleaning) rather than
+    /// inside them. When it has run out after metadata, parsing returns `None`; after
+    /// scoring, the extracted content is returned uncleaned; after cleaning, the optional
+    /// post-processing passes over the content are skipped, while metadata fixes such
+    /// as the lead image fallback and `content_language` byline stripping still run.
+    /// The phase at which the budget ran out is
+    /// reported in [`ParseStats::budget_exceeded`](crate::ParseStats::budget_exceeded),
+    /// and logged with `debug` set.
+    ///
+    /// Default: `None` (no budget)
+    pub max_duration: Option<Duration>,
+
+    /// Use the JSON-LD `articleBody` as the content when the page provides one.
+    ///
+    /// Some sites put the full article text in their structured data. With this set, an
+    /// `articleBody` of at least `char_threshold` characters is split into paragraphs at
+    /// its line breaks and used as the content, and the page itself is not scored, which
+    /// sidesteps ad-heavy markup entirely. Shorter or missing bodies fall back to scoring.
+    /// Has no effect when `disable_json_ld` is set.
+    ///
+    /// Default: `false`
+    pub prefer_jsonld_body: bool,
+
+    /// Keep `<colgroup>` and `<col>` elements in data tables.
+    ///
+    /// Column groups carry the table's column structure, such as a `span` covering
+    /// several columns, which is needed to re-render the table faithfully. While a table
+    /// has them, its empty edge columns are not trimmed, so the groups keep lining up
+    /// with the cells. When `false`, they are removed from every table.
+    ///
+    /// Default: `true`
+    pub keep_table_columns: bool,
 }
 
 impl Default for ReadabilityOptions {
@@ -158,6 +564,41 @@ impl Default for ReadabilityOptions {
             disable_json_ld: false,
             allowed_video_regex: None,
             link_density_modifier: 0.0,
+            drop_data_uri_images: false,
+            max_data_uri_bytes: 4096,
+            data_uri_placeholder: false,
+            minify_whitespace: false,
+            keep_inline_svg: false,
+            prefer_heading_title: false,
+            remove_byline_from_content: true,
+            collect_links: false,
+            content_language: None,
+            remove_title_heading: false,
+            content_selectors: Vec::new(),
+            paragraph_like_tags: vec!["p".to_string()],
+            preserve_aria: true,
+            wrap_in_article: false,
+            remove_leading_breadcrumb: false,
+            serializer: Serializer::ScraperDefault,
+            attribute_order: Vec::new(),
+            caption_to_alt: false,
+            trace_removed_blocks: false,
+            disable_sibling_aggregation: false,
+            iframe_fallback: IframeFallback::Remove,
+            max_images: 0,
+            sponsored_penalty: 0.0,
+            markdown_footnotes: false,
+            max_consecutive_brs: 1,
+            preserve_ruby: true,
+            min_paragraphs: 1,
+            min_candidate_score: 0.0,
+            annotate_positions: false,
+            collect_source_paths: false,
+            annotate_original_tags: false,
+            correct_float_order: false,
+            max_duration: None,
+            prefer_jsonld_body: false,
+            keep_table_columns: true,
         }
     }
 }
@@ -196,6 +637,41 @@ pub struct ReadabilityOptionsBuilder {
     disable_json_ld: Option<bool>,
     allowed_video_regex: Option<Regex>,
     link_density_modifier: Option<f64>,
+    drop_data_uri_images: Option<bool>,
+    max_data_uri_bytes: Option<usize>,
+    data_uri_placeholder: Option<bool>,
+    minify_whitespace: Option<bool>,
+    keep_inline_svg: Option<bool>,
+    prefer_heading_title: Option<bool>,
+    remove_byline_from_content: Option<bool>,
+    collect_links: Option<bool>,
+    content_language: Option<String>,
+    remove_title_heading: Option<bool>,
+    content_selectors: Option<Vec<String>>,
+    paragraph_like_tags: Option<Vec<String>>,
+    preserve_aria: Option<bool>,
+    wrap_in_article: Option<bool>,
+    remove_leading_breadcrumb: Option<bool>,
+    serializer: Option<Serializer>,
+    attribute_order: Option<Vec<String>>,
+    caption_to_alt: Option<bool>,
+    trace_removed_blocks: Option<bool>,
+    disable_sibling_aggregation: Option<bool>,
+    iframe_fallback: Option<IframeFallback>,
+    max_images: Option<usize>,
+    sponsored_penalty: Option<f64>,
+    markdown_footnotes: Option<bool>,
+    max_consecutive_brs: Option<usize>,
+    preserve_ruby: Option<bool>,
+    min_paragraphs: Option<usize>,
+    min_candidate_score: Option<f64>,
+    annotate_positions: Option<bool>,
+    collect_source_paths: Option<bool>,
+    annotate_original_tags: Option<bool>,
+    correct_float_order: Option<bool>,
+    max_duration: Option<Duration>,
+    prefer_jsonld_body: Option<bool>,
+    keep_table_columns: Option<bool>,
 }
 
 impl ReadabilityOptionsBuilder {
@@ -253,6 +729,216 @@ impl ReadabilityOptionsBuilder {
         self
     }
 
+    /// Drop large inline data URI images
+    pub fn drop_data_uri_images(mut self, drop: bool) -> Self {
+        self.drop_data_uri_images = Some(drop);
+        self
+    }
+
+    /// Set the data URI size threshold in bytes
+    pub fn max_data_uri_bytes(mut self, max: usize) -> Self {
+        self.max_data_uri_bytes = Some(max);
+        self
+    }
+
+    /// Replace dropped data URI images with their alt text
+    pub fn data_uri_placeholder(mut self, placeholder: bool) -> Self {
+        self.data_uri_placeholder = Some(placeholder);
+        self
+    }
+
+    /// Collapse insignificant whitespace in the output HTML
+    pub fn minify_whitespace(mut self, minify: bool) -> Self {
+        self.minify_whitespace = Some(minify);
+        self
+    }
+
+    /// Keep inline SVG illustrations
+    pub fn keep_inline_svg(mut self, keep: bool) -> Self {
+        self.keep_inline_svg = Some(keep);
+        self
+    }
+
+    /// Prefer the content `<h1>` as the article title
+    pub fn prefer_heading_title(mut self, prefer: bool) -> Self {
+        self.prefer_heading_title = Some(prefer);
+        self
+    }
+
+    /// Set whether to remove the byline element from the content
+    pub fn remove_byline_from_content(mut self, remove: bool) -> Self {
+        self.remove_byline_from_content = Some(remove);
+        self
+    }
+
+    /// Set whether to collect outbound links into `Article::links`
+    pub fn collect_links(mut self, collect: bool) -> Self {
+        self.collect_links = Some(collect);
+        self
+    }
+
+    /// Set the content language hint for language-specific cleaning
+    pub fn content_language(mut self, language: impl Into<String>) -> Self {
+        self.content_language = Some(language.into());
+        self
+    }
+
+    /// Set whether to remove the heading that repeats the title from the content
+    pub fn remove_title_heading(mut self, remove: bool) -> Self {
+        self.remove_title_heading = Some(remove);
+        self
+    }
+
+    /// Set CSS selectors for the article container, tried before scoring
+    pub fn content_selectors(mut self, selectors: Vec<String>) -> Self {
+        self.content_selectors = Some(selectors);
+        self
+    }
+
+    /// Set the tag names scored as paragraphs
+    pub fn paragraph_like_tags(mut self, tags: Vec<String>) -> Self {
+        self.paragraph_like_tags = Some(tags);
+        self
+    }
+
+    /// Set whether to keep `aria-*` and `role` attributes
+    pub fn preserve_aria(mut self, preserve: bool) -> Self {
+        self.preserve_aria = Some(preserve);
+        self
+    }
+
+    /// Set whether to wrap the content in a semantic `<article>` element
+    pub fn wrap_in_article(mut self, wrap: bool) -> Self {
+        self.wrap_in_article = Some(wrap);
+        self
+    }
+
+    /// Set whether to remove a breadcrumb trail that leads the content
+    pub fn remove_leading_breadcrumb(mut self, remove: bool) -> Self {
+        self.remove_leading_breadcrumb = Some(remove);
+        self
+    }
+
+    /// Set the output formatting of the article HTML
+    pub fn serializer(mut self, serializer: Serializer) -> Self {
+        self.serializer = Some(serializer);
+        self
+    }
+
+    /// Set the attribute names written first on every element
+    pub fn attribute_order(mut self, names: Vec<String>) -> Self {
+        self.attribute_order = Some(names);
+        self
+    }
+
+    /// Set whether to copy captions into missing image `alt` attributes
+    pub fn caption_to_alt(mut self, enabled: bool) -> Self {
+        self.caption_to_alt = Some(enabled);
+        self
+    }
+
+    /// Set whether to record blocks removed by the cleaning passes
+    pub fn trace_removed_blocks(mut self, trace: bool) -> Self {
+        self.trace_removed_blocks = Some(trace);
+        self
+    }
+
+    /// Set whether to return the top candidate without its siblings
+    pub fn disable_sibling_aggregation(mut self, disable: bool) -> Self {
+        self.disable_sibling_aggregation = Some(disable);
+        self
+    }
+
+    /// Set how to treat iframes that do not match the video allowlist
+    pub fn iframe_fallback(mut self, fallback: IframeFallback) -> Self {
+        self.iframe_fallback = Some(fallback);
+        self
+    }
+
+    /// Set the maximum number of images kept in the content (0 = unlimited)
+    pub fn max_images(mut self, max: usize) -> Self {
+        self.max_images = Some(max);
+        self
+    }
+
+    /// Set the fraction of score lost by candidates in sponsored blocks
+    pub fn sponsored_penalty(mut self, penalty: f64) -> Self {
+        self.sponsored_penalty = Some(penalty);
+        self
+    }
+
+    /// Render footnote references as Markdown footnotes
+    pub fn markdown_footnotes(mut self, enabled: bool) -> Self {
+        self.markdown_footnotes = Some(enabled);
+        self
+    }
+
+    /// Set the maximum number of consecutive `<br>` elements kept (0 = no limit)
+    pub fn max_consecutive_brs(mut self, max: usize) -> Self {
+        self.max_consecutive_brs = Some(max);
+        self
+    }
+
+    /// Keep ruby annotations (furigana) in the content
+    pub fn preserve_ruby(mut self, preserve: bool) -> Self {
+        self.preserve_ruby = Some(preserve);
+        self
+    }
+
+    /// Set the minimum number of paragraphs in the extracted content
+    pub fn min_paragraphs(mut self, count: usize) -> Self {
+        self.min_paragraphs = Some(count);
+        self
+    }
+
+    /// Set the minimum content score for accepting the top candidate
+    pub fn min_candidate_score(mut self, score: f64) -> Self {
+        self.min_candidate_score = Some(score);
+        self
+    }
+
+    /// Annotate content blocks with `data-pos` indices in source order
+    pub fn annotate_positions(mut self, annotate: bool) -> Self {
+        self.annotate_positions = Some(annotate);
+        self
+    }
+
+    /// Collect the source paths of the kept blocks
+    pub fn collect_source_paths(mut self, collect: bool) -> Self {
+        self.collect_source_paths = Some(collect);
+        self
+    }
+
+    /// Record the original tag name of converted elements in `data-orig-tag`
+    pub fn annotate_original_tags(mut self, annotate: bool) -> Self {
+        self.annotate_original_tags = Some(annotate);
+        self
+    }
+
+    /// Move floated callouts after the text they interrupt
+    pub fn correct_float_order(mut self, correct: bool) -> Self {
+        self.correct_float_order = Some(correct);
+        self
+    }
+
+    /// Set a time budget for a single parse
+    pub fn max_duration(mut self, budget: Duration) -> Self {
+        self.max_duration = Some(budget);
+        self
+    }
+
+    /// Use the JSON-LD `articleBody` as the content when it is long enough
+    pub fn prefer_jsonld_body(mut self, prefer: bool) -> Self {
+        self.prefer_jsonld_body = Some(prefer);
+        self
+    }
+
+    /// Set whether to keep `<colgroup>` and `<col>` in data tables
+    pub fn keep_table_columns(mut self, keep: bool) -> Self {
+        self.keep_table_columns = Some(keep);
+        self
+    }
+
     /// Build the ReadabilityOptions
     pub fn build(self) -> ReadabilityOptions {
         let defaults = ReadabilityOptions::default();
@@ -272,6 +958,79 @@ impl ReadabilityOptionsBuilder {
             link_density_modifier: self
                 .link_density_modifier
                 .unwrap_or(defaults.link_density_modifier),
+            drop_data_uri_images: self
+                .drop_data_uri_images
+                .unwrap_or(defaults.drop_data_uri_images),
+            max_data_uri_bytes: self
+                .max_data_uri_bytes
+                .unwrap_or(defaults.max_data_uri_bytes),
+            data_uri_placeholder: self
+                .data_uri_placeholder
+                .unwrap_or(defaults.data_uri_placeholder),
+            minify_whitespace: self.minify_whitespace.unwrap_or(defaults.minify_whitespace),
+            keep_inline_svg: self.keep_inline_svg.unwrap_or(defaults.keep_inline_svg),
+            prefer_heading_title: self
+                .prefer_heading_title
+                .unwrap_or(defaults.prefer_heading_title),
+            remove_byline_from_content: self
+                .remove_byline_from_content
+                .unwrap_or(defaults.remove_byline_from_content),
+            collect_links: self.collect_links.unwrap_or(defaults.collect_links),
+            content_language: self.content_language.or(defaults.content_language),
+            remove_title_heading: self
+                .remove_title_heading
+                .unwrap_or(defaults.remove_title_heading),
+            content_selectors: self.content_selectors.unwrap_or(defaults.content_selectors),
+            paragraph_like_tags: self
+                .paragraph_like_tags
+                .unwrap_or(defaults.paragraph_like_tags),
+            preserve_aria: self.preserve_aria.unwrap_or(defaults.preserve_aria),
+            wrap_in_article: self.wrap_in_article.unwrap_or(defaults.wrap_in_article),
+            remove_leading_breadcrumb: self
+                .remove_leading_breadcrumb
+                .unwrap_or(defaults.remove_leading_breadcrumb),
+            serializer: self.serializer.unwrap_or(defaults.serializer),
+            attribute_order: self.attribute_order.unwrap_or(defaults.attribute_order),
+            caption_to_alt: self.caption_to_alt.unwrap_or(defaults.caption_to_alt),
+            trace_removed_blocks: self
+                .trace_removed_blocks
+                .unwrap_or(defaults.trace_removed_blocks),
+            disable_sibling_aggregation: self
+                .disable_sibling_aggregation
+                .unwrap_or(defaults.disable_sibling_aggregation),
+            iframe_fallback: self.iframe_fallback.unwrap_or(defaults.iframe_fallback),
+            max_images: self.max_images.unwrap_or(defaults.max_images),
+            sponsored_penalty: self.sponsored_penalty.unwrap_or(defaults.sponsored_penalty),
+            markdown_footnotes: self
+                .markdown_footnotes
+                .unwrap_or(defaults.markdown_footnotes),
+            max_consecutive_brs: self
+                .max_consecutive_brs
+                .unwrap_or(defaults.max_consecutive_brs),
+            preserve_ruby: self.preserve_ruby.unwrap_or(defaults.preserve_ruby),
+            min_paragraphs: self.min_paragraphs.unwrap_or(defaults.min_paragraphs),
+            min_candidate_score: self
+                .min_candidate_score
+                .unwrap_or(defaults.min_candidate_score),
+            annotate_positions: self
+                .annotate_positions
+                .unwrap_or(defaults.annotate_positions),
+            collect_source_paths: self
+                .collect_source_paths
+                .unwrap_or(defaults.collect_source_paths),
+            annotate_original_tags: self
+                .annotate_original_tags
+                .unwrap_or(defaults.annotate_original_tags),
+            correct_float_order: self
+                .correct_float_order
+                .unwrap_or(defaults.correct_float_order),
+            max_duration: self.max_duration.or(defaults.max_duration),
+            prefer_jsonld_body: self
+                .prefer_jsonld_body
+                .unwrap_or(defaults.prefer_jsonld_body),
+            keep_table_columns: self
+                .keep_table_columns
+                .unwrap_or(defaults.keep_table_columns),
         }
     }
 }
@@ -3,8 +3,10 @@
 //! This module implements Mozilla's _prepArticle pipeline, which cleans
 //! the extracted article content by removing unwanted elements.
 
+use crate::options::Serializer;
 use once_cell::sync::Lazy;
 use regex::Regex;
+use scraper::{node::Node, ElementRef, Html};
 
 /// Remove nav-heavy wrappers by descending into content-like children.
 fn unwrap_nav_wrappers(html: &str) -> String {
@@ -20,15 +22,17 @@ fn unwrap_nav_wrappers(html: &str) -> String {
 
 /// Prepare extracted article content for final output
 ///
-/// This implements Mozilla's _prepArticle() pipeline using regex-based cleaning
-pub fn prep_article(html: &str) -> String {
+/// This implements Mozilla's _prepArticle() pipeline using regex-based cleaning.
+/// With `keep_iframes` set, iframes are left for
+/// [`cleaner::apply_iframe_fallback`](crate::cleaner::apply_iframe_fallback) to handle.
+pub fn prep_article(html: &str, keep_iframes: bool) -> String {
     let mut html = html.to_string();
 
     // Unwrap nav wrappers before removing elements
     html = unwrap_nav_wrappers(&html);
 
     // Step 1: Remove unwanted elements
-    html = remove_unwanted_elements(&html);
+    html = remove_unwanted_elements(&html, keep_iframes);
 
     // Step 2: Remove share buttons and social widgets
     html = remove_share_elements(&html);
@@ -45,8 +49,8 @@ pub fn prep_article(html: &str) -> String {
 /// Remove unwanted elements that are never part of article content
 ///
 /// Removes: forms, fieldsets, footer, aside, object, embed, iframe,
-/// input, textarea, select, button
-fn remove_unwanted_elements(html: &str) -> String {
+/// input, textarea, select, button. Iframes are skipped when `keep_iframes` is set.
+fn remove_unwanted_elements(html: &str, keep_iframes: bool) -> String {
     let mut result = html.to_string();
     let tags = vec![
         ("form", r"(?is)<form\b[^>]*?>.*?</form>"),
@@ -69,7 +73,10 @@ fn remove_unwanted_elements(html: &str) -> String {
         ("link", r"(?is)<link\b[^>]*?>.*?</link>|<link\b[^>]*?/?>"),
     ];
 
-    for (_name, pattern) in tags {
+    for (name, pattern) in tags {
+        if keep_iframes && name == "iframe" {
+            continue;
+        }
         let re = Regex::new(pattern).unwrap();
         result = re.replace_all(&result, "").to_string();
     }
@@ -150,6 +157,251 @@ fn remove_empty_paragraphs(html: &str) -> String {
     html
 }
 
+/// Collapse insignificant whitespace in serialized article HTML
+///
+/// Runs of whitespace in text are collapsed to a single space, and whitespace next to
+/// block-level tags is dropped entirely. Content inside `<pre>`, `<code>` and
+/// `<textarea>` is left untouched, so the rendered output does not change. Walks the
+/// parsed content, like [`to_xhtml`], so attribute values are never touched.
+pub fn minify_whitespace(html: &str) -> String {
+    let mut fragment = Html::parse_fragment(html);
+    let root = fragment.root_element().id();
+
+    let collapsed: Vec<_> = fragment
+        .tree
+        .nodes()
+        .filter_map(|node| {
+            let Node::Text(text) = node.value() else {
+                return None;
+            };
+            let preformatted = node.ancestors().any(|ancestor| {
+                ancestor
+                    .value()
+                    .as_element()
+                    .is_some_and(|element| matches!(element.name(), "pre" | "code" | "textarea"))
+            });
+            if preformatted {
+                return None;
+            }
+
+            let mut collapsed = String::with_capacity(text.len());
+            for c in text.chars() {
+                if c.is_ascii_whitespace() {
+                    if !collapsed.ends_with(' ') {
+                        collapsed.push(' ');
+                    }
+                } else {
+                    collapsed.push(c);
+                }
+            }
+            // Whitespace at the start or end of the fragment counts as next to a block
+            let after_block = node
+                .prev_sibling()
+                .or(node.parent())
+                .is_some_and(|prev| prev.id() == root || is_block_node(prev.value()));
+            let before_block = node
+                .next_sibling()
+                .or(node.parent())
+                .is_some_and(|next| next.id() == root || is_block_node(next.value()));
+            if after_block {
+                collapsed = collapsed.trim_start_matches(' ').to_string();
+            }
+            if before_block {
+                collapsed = collapsed.trim_end_matches(' ').to_string();
+            }
+            Some((node.id(), collapsed))
+        })
+        .collect();
+
+    for (id, collapsed) in collapsed {
+        if let Some(mut node) = fragment.tree.get_mut(id) {
+            if let Node::Text(text) = node.value() {
+                text.text = collapsed.into();
+            }
+        }
+    }
+    fragment.root_element().inner_html()
+}
+
+/// Reformat serialized article HTML for the chosen [`Serializer`]
+///
+/// Attributes named in `attribute_order` are written first, in that order; the others
+/// follow sorted by name.
+pub fn apply_serializer(html: &str, serializer: Serializer, attribute_order: &[String]) -> String {
+    match serializer {
+        Serializer::ScraperDefault => order_attributes(html, attribute_order),
+        Serializer::Xhtml => to_xhtml(html, attribute_order),
+        Serializer::Compact => minify_whitespace(&order_attributes(html, attribute_order)),
+    }
+}
+
+/// Re-serialize HTML with the attributes in `attribute_order` first
+///
+/// Returns the HTML unchanged when no order is given.
+fn order_attributes(html: &str, attribute_order: &[String]) -> String {
+    if attribute_order.is_empty() {
+        return html.to_string();
+    }
+    let mut fragment = Html::parse_fragment(html);
+    for node in fragment.tree.values_mut() {
+        if let Node::Element(element) = node {
+            element
+                .attrs
+                .sort_by_key(|(name, _)| attribute_rank(&name.local, attribute_order));
+        }
+    }
+    fragment.root_element().inner_html()
+}
+
+/// Position of attribute `name` in `attribute_order`, after every listed name if absent
+fn attribute_rank(name: &str, attribute_order: &[String]) -> usize {
+    attribute_order
+        .iter()
+        .position(|listed| listed.eq_ignore_ascii_case(name))
+        .unwrap_or(attribute_order.len())
+}
+
+/// Serialize HTML as well-formed XHTML, for [`Serializer::Xhtml`]
+///
+/// Walks the parsed content rather than rewriting the markup, so text and attribute
+/// values that look like tags are escaped, never reinterpreted.
+pub(crate) fn to_xhtml(html: &str, attribute_order: &[String]) -> String {
+    let fragment = Html::parse_fragment(html);
+    let mut out = String::with_capacity(html.len());
+    write_xhtml_children(fragment.root_element(), attribute_order, &mut out);
+    out
+}
+
+fn write_xhtml_children(element: ElementRef, attribute_order: &[String], out: &mut String) {
+    for child in element.children() {
+        match child.value() {
+            Node::Text(text) => out.push_str(&escape_xml(&text.text, false)),
+            Node::Element(_) => {
+                if let Some(child_element) = ElementRef::wrap(child) {
+                    write_xhtml_element(child_element, attribute_order, out);
+                }
+            }
+            _ => {}
+        }
+    }
+}
+
+fn write_xhtml_element(element: ElementRef, attribute_order: &[String], out: &mut String) {
+    const VOID: &[&str] = &[
+        "area", "base", "br", "col", "embed", "hr", "img", "input", "link", "meta", "param",
+        "source", "track", "wbr",
+    ];
+
+    let tag = element.value().name();
+    if !is_xml_name(tag) {
+        write_xhtml_children(element, attribute_order, out);
+        return;
+    }
+
+    out.push('<');
+    out.push_str(tag);
+    let namespace = match tag {
+        "svg" => Some("http://www.w3.org/2000/svg"),
+        "math" => Some("http://www.w3.org/1998/Math/MathML"),
+        _ => None,
+    };
+    if let Some(namespace) = namespace {
+        if element.value().attr("xmlns").is_none() {
+            out.push_str(&format!(r#" xmlns="{namespace}""#));
+        }
+    }
+    let mut attrs: Vec<(&str, &str)> = element.value().attrs().collect();
+    attrs.sort_by_key(|(name, _)| attribute_rank(name, attribute_order));
+    for (name, value) in attrs {
+        if is_xml_name(name) {
+            out.push_str(&format!(r#" {name}="{}""#, escape_xml(value, true)));
+        }
+    }
+
+    if VOID.contains(&tag) {
+        out.push_str(" />");
+        return;
+    }
+    out.push('>');
+    write_xhtml_children(element, attribute_order, out);
+    out.push_str(&format!("</{tag}>"));
+}
+
+/// Whether `name` can be written as an XML element or attribute name without a prefix
+fn is_xml_name(name: &str) -> bool {
+    let mut chars = name.chars();
+    chars
+        .next()
+        .is_some_and(|first| first.is_ascii_alphabetic() || first == '_')
+        && chars.all(|c| c.is_ascii_alphanumeric() || matches!(c, '_' | '-' | '.'))
+}
+
+/// Escape text for XML, dropping characters XML 1.0 does not allow
+pub(crate) fn escape_xml(text: &str, in_attribute: bool) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' if !in_attribute => escaped.push_str("&gt;"),
+            '"' if in_attribute => escaped.push_str("&quot;"),
+            '\t' | '\n' | '\r' => escaped.push(c),
+            c if c < ' ' || matches!(c, '\u{fffe}' | '\u{ffff}') => {}
+            c => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+/// Whether a serialized tag opens or closes a block-level element
+/// Whether `node` is an element that starts a new line
+fn is_block_node(node: &Node) -> bool {
+    node.as_element()
+        .is_some_and(|element| is_block_tag(element.name()))
+}
+
+fn is_block_tag(name: &str) -> bool {
+    const BLOCK_TAGS: &[&str] = &[
+        "address",
+        "article",
+        "aside",
+        "blockquote",
+        "br",
+        "dd",
+        "div",
+        "dl",
+        "dt",
+        "figcaption",
+        "figure",
+        "footer",
+        "h1",
+        "h2",
+        "h3",
+        "h4",
+        "h5",
+        "h6",
+        "header",
+        "hr",
+        "li",
+        "main",
+        "nav",
+        "ol",
+        "p",
+        "pre",
+        "section",
+        "table",
+        "tbody",
+        "td",
+        "tfoot",
+        "th",
+        "thead",
+        "tr",
+        "ul",
+    ];
+
+    BLOCK_TAGS.contains(&name)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -165,7 +417,7 @@ mod tests {
             </article>
         "#;
 
-        let cleaned = remove_unwanted_elements(html);
+        let cleaned = remove_unwanted_elements(html, false);
 
         assert!(cleaned.contains("<h1>Title</h1>"));
         assert!(cleaned.contains("<p>Content</p>"));
@@ -249,7 +501,7 @@ mod tests {
             </article>
         "#;
 
-        let cleaned = prep_article(html);
+        let cleaned = prep_article(html, false);
 
         assert!(cleaned.contains("<h1>Article Title</h1>"));
         assert!(cleaned.contains("<p>First paragraph</p>"));
@@ -258,4 +510,67 @@ mod tests {
         assert!(!cleaned.contains("<form"));
         assert!(!cleaned.contains("<p></p>"));
     }
+
+    #[test]
+    fn test_minify_whitespace_reduces_size() {
+        let html = "<div>\n    <h2>  Heading  </h2>\n    <p>Some   text\n      with <em>inline</em>   markup.</p>\n    <pre>  keep\n    this  </pre>\n</div>\n";
+
+        let minified = minify_whitespace(html);
+
+        assert!(minified.len() < html.len());
+        assert_eq!(
+            minified,
+            "<div><h2>Heading</h2><p>Some text with <em>inline</em> markup.</p><pre>  keep\n    this  </pre></div>"
+        );
+    }
+
+    #[test]
+    fn test_minify_whitespace_leaves_attribute_values_alone() {
+        let html = "<p title=\"a >  b\">  Some   text\u{a0} here </p>\n<p><a href=\"/x\" title=\" > \">link</a>  after</p>";
+
+        assert_eq!(
+            minify_whitespace(html),
+            "<p title=\"a >  b\">Some text&nbsp; here</p><p><a href=\"/x\" title=\" > \">link</a> after</p>"
+        );
+    }
+
+    #[test]
+    fn test_apply_serializer() {
+        let html = "<p>Line one<br>line two</p>\n<p>\n  <img alt=\"a > b\" src=\"/x.png\">\n</p>";
+
+        assert_eq!(
+            apply_serializer(html, Serializer::ScraperDefault, &[]),
+            html
+        );
+        assert_eq!(
+            apply_serializer(html, Serializer::Xhtml, &[]),
+            "<p>Line one<br />line two</p>\n<p>\n  <img alt=\"a > b\" src=\"/x.png\" />\n</p>"
+        );
+        assert_eq!(
+            apply_serializer(html, Serializer::Compact, &[]),
+            "<p>Line one<br>line two</p><p><img alt=\"a > b\" src=\"/x.png\"></p>"
+        );
+
+        let html = r#"<p><a class="x" href="/y" id="z">y</a><img alt="" src="/i.png"></p>"#;
+        let order = ["href".to_string(), "SRC".to_string(), "id".to_string()];
+        assert_eq!(
+            apply_serializer(html, Serializer::ScraperDefault, &order),
+            r#"<p><a href="/y" id="z" class="x">y</a><img src="/i.png" alt=""></p>"#
+        );
+        assert_eq!(
+            apply_serializer(html, Serializer::Xhtml, &order),
+            r#"<p><a href="/y" id="z" class="x">y</a><img src="/i.png" alt="" /></p>"#
+        );
+    }
+
+    #[test]
+    fn test_xhtml_serializer_is_well_formed() {
+        let html = r#"<p title="<br>">Tom&nbsp;&amp; Jerry &lt;br&gt;</p><svg viewBox="0 0 10 10"><rect width="5" height="5"></rect></svg><!-- note -->"#;
+        let xhtml = apply_serializer(html, Serializer::Xhtml, &[]);
+        assert_eq!(
+            xhtml,
+            "<p title=\"&lt;br>\">Tom\u{a0}&amp; Jerry &lt;br&gt;</p><svg xmlns=\"http://www.w3.org/2000/svg\" viewBox=\"0 0 10 10\"><rect height=\"5\" width=\"5\"></rect></svg>"
+        );
+        roxmltree::Document::parse(&format!("<div>{xhtml}</div>")).unwrap();
+    }
 }
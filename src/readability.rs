@@ -27,16 +27,21 @@
 //! ```
 
 use crate::{
-    article::Article,
+    article::{Article, LinkRef},
     cleaner,
-    content_extractor::grab_article,
+    content_extractor::{grab_article, ExtractedContent},
     dom_utils,
     error::{ReadabilityError, Result},
     metadata::{get_article_metadata, get_json_ld, Metadata},
-    options::ReadabilityOptions,
+    options::{IframeFallback, ReadabilityOptions},
+    render,
+    stats::ParseStats,
     utils,
 };
-use scraper::{ElementRef, Html, Selector};
+use once_cell::sync::Lazy;
+use scraper::{node::Node, ElementRef, Html, Selector};
+use std::collections::{HashMap, HashSet};
+use std::time::Instant;
 
 /// The main Readability parser.
 ///
@@ -101,11 +106,24 @@ use scraper::{ElementRef, Html, Selector};
 /// let readability = Readability::new(html, None, Some(options))?;
 /// # Ok::<(), Box<dyn std::error::Error>>(())
 /// ```
+///
+/// ## Async Runtimes
+///
+/// Parsing is CPU-bound and blocks the calling thread, so async code should run it on a
+/// blocking pool such as `tokio::task::spawn_blocking`. `Readability` and [`Article`]
+/// are `Send`: the document is only parsed inside [`parse()`](Readability::parse), so
+/// the instance can be built on the async side and moved into the blocking task.
+///
+/// ```rust
+/// use readabilityrs::Readability;
+///
+/// let readability = Readability::new("<html><body><p>Content</p></body></html>", None, None)?;
+/// let article = std::thread::spawn(move || readability.parse()).join().unwrap();
+/// # let _ = article;
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
 pub struct Readability {
-    /// The HTML document being parsed (raw, for metadata extraction)
-    document: Html,
-
-    /// Original HTML string (stored for preprocessing before content extraction)
+    /// Original HTML string, parsed on demand so the struct stays `Send`
     html: String,
 
     /// Base URL for resolving relative links
@@ -129,10 +147,6 @@ impl Readability {
     /// # Returns
     /// Result containing the Readability instance or an error
     pub fn new(html: &str, url: Option<&str>, options: Option<ReadabilityOptions>) -> Result<Self> {
-        // Parse raw HTML for metadata extraction
-        // Preprocessing happens later in parse() before content extraction
-        let document = Html::parse_document(html);
-
         // Validate base URL if provided
         let base_url = url
             .map(|u| {
@@ -144,8 +158,23 @@ impl Readability {
 
         let options = options.unwrap_or_default();
 
+        // Reject malformed user selectors up front rather than at extraction time
+        if let Some(invalid) = options
+            .content_selectors
+            .iter()
+            .find(|selector| Selector::parse(selector).is_err())
+        {
+            return Err(ReadabilityError::InvalidSelector(invalid.clone()));
+        }
+        if let Some(invalid) = options
+            .paragraph_like_tags
+            .iter()
+            .find(|tag| !crate::scoring::is_valid_paragraph_like_tag(tag))
+        {
+            return Err(ReadabilityError::InvalidSelector(invalid.clone()));
+        }
+
         Ok(Self {
-            document,
             html: html.to_string(),
             base_url,
             options,
@@ -157,27 +186,166 @@ impl Readability {
     ///
     /// # Returns
     /// `Option<Article>` - Some(article) if successful, None if no article found
-    pub fn parse(mut self) -> Option<Article> {
+    pub fn parse(self) -> Option<Article> {
+        self.run(false).0
+    }
+
+    /// Share of the page's text that belongs to the extracted article
+    ///
+    /// The length of the extracted text divided by the length of all text in the
+    /// document, both measured after scripts and styles are stripped. Values close to
+    /// 1.0 suggest a clean article page; low values suggest a portal or link farm.
+    /// Returns 0.0 when no content is found.
+    ///
+    /// Runs content extraction, without the cleaning passes of [`parse`](Self::parse),
+    /// each time it is called. Callers that parse anyway can read the same value from
+    /// [`ParseStats::content_density`] instead.
+    pub fn content_density(&self) -> f64 {
+        let preprocessed_doc = Html::parse_document(&cleaner::prep_document(&self.html));
+        let article_body = if self.options.prefer_jsonld_body && !self.options.disable_json_ld {
+            get_json_ld(&Html::parse_document(&self.html)).article_body
+        } else {
+            None
+        };
+        match self.extract_content(&preprocessed_doc, article_body.as_deref()) {
+            Ok(Some(extracted)) => content_density(&preprocessed_doc, &extracted.html),
+            _ => 0.0,
+        }
+    }
+
+    /// Parse several pages of one site, removing the boilerplate they share
+    ///
+    /// Each page is parsed as by [`parse`](Self::parse). Blocks such as paragraphs, list
+    /// items and headings whose text appears identically in more than half of the
+    /// extracted articles (and in at least two) are treated as site boilerplate, like a
+    /// repeated footer or newsletter pitch, and removed from every article. Blocks
+    /// shorter than 25 characters are never treated as boilerplate.
+    ///
+    /// `content`, `text_content` and `length` are updated to match. Other fields derived
+    /// from the content, such as `excerpt`, `links`, `footnotes` and `source_paths`, are
+    /// left as extracted from the full page.
+    ///
+    /// # Returns
+    /// One entry per input page, in order
+    pub fn parse_batch_with_dedup(docs: Vec<Readability>) -> Vec<Option<Article>> {
+        let mut articles: Vec<Option<Article>> = docs.into_iter().map(Self::parse).collect();
+
+        let block_keys: Vec<HashSet<String>> = articles
+            .iter()
+            .flatten()
+            .filter_map(|article| article.content.as_deref())
+            .map(cleaner::repeated_block_keys)
+            .collect();
+        let mut counts: HashMap<&str, usize> = HashMap::new();
+        for key in block_keys.iter().flatten() {
+            *counts.entry(key.as_str()).or_default() += 1;
+        }
+        let boilerplate: HashSet<String> = counts
+            .into_iter()
+            .filter(|&(_, count)| count >= 2 && count * 2 > block_keys.len())
+            .map(|(key, _)| key.to_string())
+            .collect();
+        if boilerplate.is_empty() {
+            return articles;
+        }
+
+        for article in articles.iter_mut().flatten() {
+            let Some(content) = article.content.as_deref() else {
+                continue;
+            };
+            let content = cleaner::remove_repeated_blocks(content, &boilerplate);
+            let text_content = Self::get_text_content(&content);
+            article.length = text_content.len();
+            article.text_content = Some(text_content);
+            article.content = Some(content);
+        }
+        articles
+    }
+
+    /// Parse the document and report counters collected along the way
+    ///
+    /// Same as [`parse`](Self::parse), but also returns [`ParseStats`] with the number of
+    /// elements removed by cleaning, links absolutized, images kept and the time spent.
+    ///
+    /// # Returns
+    /// The parsed article, if any, and the stats for this parse
+    pub fn parse_with_stats(self) -> (Option<Article>, ParseStats) {
+        self.run(true)
+    }
+
+    /// Shared body of [`parse`](Self::parse) and [`parse_with_stats`](Self::parse_with_stats);
+    /// `measure_density` fills [`ParseStats::content_density`], which costs a pass over
+    /// the whole page
+    fn run(mut self, measure_density: bool) -> (Option<Article>, ParseStats) {
+        let started = Instant::now();
+        let mut stats = ParseStats {
+            trace_removed_blocks: self.options.trace_removed_blocks,
+            ..Default::default()
+        };
+
+        // Parse raw HTML for metadata extraction; preprocessing for content extraction
+        // happens on a separate copy below
+        let document = Html::parse_document(&self.html);
         let json_ld = if !self.options.disable_json_ld {
-            get_json_ld(&self.document)
+            get_json_ld(&document)
         } else {
             Metadata::default()
         };
 
-        self.metadata = get_article_metadata(&self.document, json_ld);
+        self.metadata = get_article_metadata(&document, json_ld);
+        if self.over_budget(started, "metadata", &mut stats) {
+            stats.elapsed = started.elapsed();
+            return (None, stats);
+        }
 
-        let preprocessed_html = cleaner::prep_document(&self.html);
+        // Blocks are numbered in the source, before preparation changes the tree
+        let preprocessed_html =
+            if self.options.annotate_positions || self.options.collect_source_paths {
+                cleaner::prep_document(&cleaner::annotate_positions(&self.html))
+            } else {
+                cleaner::prep_document(&self.html)
+            };
         let preprocessed_doc = Html::parse_document(&preprocessed_html);
 
-        match grab_article(&preprocessed_doc, &self.options) {
-            Ok(Some(content_html)) => {
-                let cleaned_wrapper_html =
-                    cleaner::clean_article_content_light(&content_html, self.base_url.as_deref())
-                        .unwrap_or_else(|_| content_html.clone());
+        let extracted =
+            self.extract_content(&preprocessed_doc, self.metadata.article_body.as_deref());
+        let article = match extracted {
+            Ok(Some(extracted)) => {
+                let content_html = extracted.html;
+                if measure_density {
+                    stats.content_density = content_density(&preprocessed_doc, &content_html);
+                }
+                let (mut body_html, pull_quotes) = cleaner::extract_pull_quotes(&content_html);
+                if self.options.correct_float_order {
+                    body_html = cleaner::move_floated_blocks(&body_html);
+                }
+                // Out of time after scoring: keep the extracted content uncleaned
+                let mut cleaned_html = if self.over_budget(started, "scoring", &mut stats) {
+                    body_html
+                } else {
+                    let cleaned_wrapper_html = cleaner::clean_article_content_light(
+                        &body_html,
+                        self.base_url.as_deref(),
+                        &mut stats,
+                    )
+                    .unwrap_or_else(|_| body_html.clone());
 
-                let prepped_html = crate::post_processor::prep_article(&cleaned_wrapper_html);
-                let cleaned_html =
-                    match cleaner::clean_article_content(&prepped_html, self.base_url.as_deref()) {
+                    let keep_iframes = self.options.iframe_fallback != IframeFallback::Remove;
+                    let mut prepped_html =
+                        crate::post_processor::prep_article(&cleaned_wrapper_html, keep_iframes);
+                    if keep_iframes {
+                        prepped_html = cleaner::apply_iframe_fallback(
+                            &prepped_html,
+                            self.options.iframe_fallback,
+                            self.options.allowed_video_regex.as_ref(),
+                        );
+                    }
+                    match cleaner::clean_article_content(
+                        &prepped_html,
+                        self.base_url.as_deref(),
+                        &self.options,
+                        &mut stats,
+                    ) {
                         Ok(html) => html,
                         Err(e) => {
                             if self.options.debug {
@@ -185,9 +353,107 @@ impl Readability {
                             }
                             prepped_html
                         }
-                    };
+                    }
+                };
+
+                // Without social or microdata images, the content's first image leads
+                if self.metadata.image.is_none() {
+                    self.metadata.image = lead_image_from_content(&cleaned_html);
+                }
+
+                // Out of time after cleaning: skip the optional passes over the content,
+                // but not the metadata fixes around them
+                if !self.over_budget(started, "cleaning", &mut stats) {
+                    if self.options.drop_data_uri_images {
+                        cleaned_html = cleaner::drop_data_uri_images(
+                            &cleaned_html,
+                            self.options.max_data_uri_bytes,
+                            self.options.data_uri_placeholder,
+                        );
+                    }
+
+                    if self.options.max_images > 0 {
+                        cleaned_html = cleaner::limit_images(
+                            &cleaned_html,
+                            self.options.max_images,
+                            self.metadata.image.as_deref(),
+                        );
+                    }
+
+                    if !self.options.preserve_ruby {
+                        cleaned_html = cleaner::strip_ruby_annotations(&cleaned_html);
+                    }
+
+                    if self.options.max_consecutive_brs > 0 {
+                        cleaned_html =
+                            cleaner::collapse_brs(&cleaned_html, self.options.max_consecutive_brs);
+                    }
+
+                    if self.options.caption_to_alt {
+                        cleaned_html = cleaner::caption_to_alt(&cleaned_html);
+                    }
+
+                    if self.options.remove_byline_from_content {
+                        if let Some(byline) = self.metadata.byline.as_deref() {
+                            cleaned_html = cleaner::remove_byline_element(&cleaned_html, byline);
+                        }
+                    }
+
+                    if self.options.remove_leading_breadcrumb {
+                        cleaned_html = cleaner::remove_leading_breadcrumb(&cleaned_html);
+                    }
+
+                    if self.options.minify_whitespace {
+                        cleaned_html = crate::post_processor::minify_whitespace(&cleaned_html);
+                    }
+                }
+
+                if let Some(language) = self.options.content_language.as_deref() {
+                    self.metadata.byline = self
+                        .metadata
+                        .byline
+                        .as_deref()
+                        .map(|byline| utils::strip_localized_byline(byline, language));
+                }
+
+                // The title is settled before the heading that repeats it is removed
+                let meta_title = self.metadata.title.clone();
+                let title = if self.options.prefer_heading_title {
+                    self.find_content_heading(&cleaned_html)
+                        .or_else(|| self.metadata.title.clone())
+                } else {
+                    self.metadata.title.clone()
+                };
+                // The region's accessible name is a last resort for pages with no title
+                let title = match title {
+                    Some(title) if title.trim().is_empty() => {
+                        extracted.region_label.clone().or(Some(title))
+                    }
+                    None => extracted.region_label.clone(),
+                    title => title,
+                };
+
+                if self.options.remove_title_heading {
+                    if let Some(title) = title.as_deref() {
+                        cleaned_html = cleaner::remove_title_heading(&cleaned_html, title);
+                    }
+                }
+
+                let source_paths = if self.options.collect_source_paths {
+                    let paths = cleaner::block_source_paths(&self.html);
+                    let (html, positions) =
+                        cleaner::take_positions(&cleaned_html, !self.options.annotate_positions);
+                    cleaned_html = html;
+                    positions
+                        .into_iter()
+                        .filter_map(|pos| paths.get(pos).cloned())
+                        .collect()
+                } else {
+                    Vec::new()
+                };
 
-                let text_content = self.get_text_content(&cleaned_html);
+                let summary_block = cleaner::find_summary_block(&cleaned_html);
+                let text_content = Self::get_text_content(&cleaned_html);
                 let length = text_content.len();
 
                 // Generate excerpt from content if not in metadata
@@ -198,21 +464,101 @@ impl Readability {
                 });
 
                 // Extract text direction from document
-                let dir = crate::dom_utils::get_article_direction(&self.document);
+                let dir = crate::dom_utils::get_article_direction(&document);
+
+                if self.options.wrap_in_article {
+                    cleaned_html = cleaner::wrap_in_article(
+                        &cleaned_html,
+                        self.metadata.lang.as_deref(),
+                        dir.as_deref(),
+                    );
+                }
+
+                cleaned_html = crate::post_processor::apply_serializer(
+                    &cleaned_html,
+                    self.options.serializer,
+                    &self.options.attribute_order,
+                );
+
+                // Fall back to a date encoded in the URL path when metadata has none
+                let mut published_time_inferred = false;
+                if self.metadata.published_time.is_none() {
+                    if let Some(date) = self
+                        .base_url
+                        .as_deref()
+                        .and_then(crate::metadata::extract_date_from_url)
+                    {
+                        self.metadata.published_time = Some(date);
+                        published_time_inferred = true;
+                    }
+                }
+
+                let content_doc = Html::parse_fragment(&cleaned_html);
+                let link_density = crate::dom_utils::get_link_density(content_doc.root_element());
+                if let Ok(img_selector) = Selector::parse("img") {
+                    stats.images_kept = content_doc.select(&img_selector).count();
+                }
+                let confidence = crate::scoring::extraction_confidence(
+                    extracted.scores,
+                    link_density,
+                    self.metadata.byline.is_some(),
+                    self.metadata.published_time.is_some() && !published_time_inferred,
+                    extracted.used_fallback,
+                );
+
+                let links = if self.options.collect_links {
+                    self.collect_links(&cleaned_html)
+                } else {
+                    Vec::new()
+                };
+                let footnotes = if self.options.markdown_footnotes {
+                    render::collect_footnotes(&cleaned_html)
+                } else {
+                    Vec::new()
+                };
+
+                let author_image = self
+                    .metadata
+                    .author_image
+                    .take()
+                    .and_then(|src| utils::to_absolute_url(&src, self.base_url.as_deref()));
+
+                // Fallback titles, late bylines and generated excerpts bypass the
+                // metadata cleanup
+                let strip_invisible = |text: Option<String>| {
+                    text.map(|text| utils::strip_invisible_chars(&text).into_owned())
+                };
 
                 Some(Article {
-                    title: self.metadata.title,
+                    title: strip_invisible(title),
+                    meta_title,
+                    raw_title: self.metadata.raw_title,
                     content: Some(cleaned_html),
                     raw_content: Some(content_html),
                     text_content: Some(text_content),
                     length,
-                    excerpt,
+                    excerpt: strip_invisible(excerpt),
                     image: self.metadata.image,
-                    byline: self.metadata.byline,
+                    image_alt: self.metadata.image_alt,
+                    byline: strip_invisible(self.metadata.byline),
+                    author_image,
                     dir,
                     site_name: self.metadata.site_name,
                     lang: self.metadata.lang,
                     published_time: self.metadata.published_time,
+                    published_time_inferred,
+                    confidence,
+                    pull_quotes,
+                    summary_block,
+                    links,
+                    source_paths,
+                    footnotes,
+                    tags: self.metadata.tags,
+                    updated_label: self.metadata.updated_label,
+                    region_label: extracted.region_label,
+                    robots: self.metadata.robots,
+                    recipe: self.metadata.recipe,
+                    location: self.metadata.location,
                 })
             }
             Ok(None) => None,
@@ -222,13 +568,163 @@ impl Readability {
                 }
                 None
             }
+        };
+
+        // A stub, such as a lone long caption, is not an article
+        let min_paragraphs = self.options.min_paragraphs;
+        let article = article.filter(|article| {
+            let paragraphs = article.content.as_deref().map_or(0, count_paragraphs);
+            if paragraphs < min_paragraphs && self.options.debug {
+                eprintln!(
+                    "Rejecting content with {paragraphs} paragraphs (minimum {min_paragraphs})"
+                );
+            }
+            paragraphs >= min_paragraphs
+        });
+
+        stats.elapsed = started.elapsed();
+        (article, stats)
+    }
+
+    /// Find the article content in the prepared document
+    ///
+    /// With [`prefer_jsonld_body`](ReadabilityOptions::prefer_jsonld_body), a long enough
+    /// JSON-LD `articleBody` is used as is, without scoring the page.
+    fn extract_content(
+        &self,
+        preprocessed_doc: &Html,
+        article_body: Option<&str>,
+    ) -> Result<Option<ExtractedContent>> {
+        let jsonld_body = article_body
+            .filter(|body| {
+                self.options.prefer_jsonld_body
+                    && utils::normalize_whitespace(body).trim().len() >= self.options.char_threshold
+            })
+            .map(json_ld_body_to_html);
+        match jsonld_body {
+            Some(html) => Ok(Some(ExtractedContent {
+                html,
+                scores: None,
+                used_fallback: false,
+                region_label: None,
+            })),
+            None => grab_article(preprocessed_doc, &self.options),
+        }
+    }
+
+    /// Whether the `max_duration` budget has run out by the end of `phase`
+    ///
+    /// Records `phase` in [`ParseStats::budget_exceeded`] when it has.
+    fn over_budget(&self, started: Instant, phase: &str, stats: &mut ParseStats) -> bool {
+        let Some(budget) = self.options.max_duration else {
+            return false;
+        };
+        if started.elapsed() < budget {
+            return false;
+        }
+        if self.options.debug {
+            eprintln!("Time budget of {budget:?} exceeded after {phase}, returning partial result");
         }
+        stats.budget_exceeded = Some(phase.to_string());
+        true
     }
 
     /// Extract plain text from HTML content
-    fn get_text_content(&self, html: &str) -> String {
+    ///
+    /// Words hyphenated across line breaks are joined, except in `<pre>` and `<code>`,
+    /// where a hyphen before a line break is part of the code.
+    fn get_text_content(html: &str) -> String {
+        let doc = Html::parse_fragment(html);
+        let mut text = String::new();
+        let mut prose = String::new();
+        for node in doc.root_element().descendants() {
+            let Node::Text(chunk) = node.value() else {
+                continue;
+            };
+            let in_code = node
+                .ancestors()
+                .filter_map(ElementRef::wrap)
+                .any(|ancestor| matches!(ancestor.value().name(), "pre" | "code"));
+            if in_code {
+                text.push_str(&utils::merge_hyphenated_line_breaks(&prose));
+                prose.clear();
+                text.push_str(&chunk.text);
+            } else {
+                prose.push_str(&chunk.text);
+            }
+        }
+        text.push_str(&utils::merge_hyphenated_line_breaks(&prose));
+        text
+    }
+
+    /// Collect outbound links from the content, resolved against the base URL
+    ///
+    /// Fragment-only links, links back to the page itself and `javascript:` links are
+    /// skipped. Each target is listed once, with the anchor text of its first link.
+    fn collect_links(&self, html: &str) -> Vec<LinkRef> {
+        let doc = Html::parse_fragment(html);
+        let Ok(selector) = Selector::parse("a[href]") else {
+            return Vec::new();
+        };
+        let page = self.base_url.as_deref().and_then(|base| {
+            url::Url::parse(base).ok().map(|mut url| {
+                url.set_fragment(None);
+                url.to_string()
+            })
+        });
+
+        let mut links: Vec<LinkRef> = Vec::new();
+        for anchor in doc.select(&selector) {
+            let href = anchor.value().attr("href").unwrap_or("").trim();
+            if href.starts_with('#') || href.to_ascii_lowercase().starts_with("javascript:") {
+                continue;
+            }
+            let Some(resolved) = utils::to_absolute_url(href, self.base_url.as_deref()) else {
+                continue;
+            };
+            if let Some(page) = page.as_deref() {
+                let target = resolved.split('#').next().unwrap_or(&resolved);
+                if target == page && resolved.contains('#') {
+                    continue;
+                }
+            }
+            if links.iter().any(|link| link.href == resolved) {
+                continue;
+            }
+
+            let text = utils::normalize_whitespace(&anchor.text().collect::<String>())
+                .trim()
+                .to_string();
+            links.push(LinkRef {
+                href: resolved,
+                text,
+            });
+        }
+        links
+    }
+
+    /// Find the single `<h1>` headline inside the extracted content
+    ///
+    /// Returns `None` when the content has no `<h1>` or several of them, since
+    /// there is no clear headline in either case.
+    fn find_content_heading(&self, html: &str) -> Option<String> {
         let doc = Html::parse_fragment(html);
-        doc.root_element().text().collect::<String>()
+        let h1_selector = Selector::parse("h1").ok()?;
+
+        let mut headings = doc.select(&h1_selector);
+        let heading = headings.next()?;
+        if headings.next().is_some() {
+            return None;
+        }
+
+        let text = utils::normalize_whitespace(&heading.text().collect::<String>())
+            .trim()
+            .to_string();
+        if text.is_empty() {
+            None
+        } else {
+            Some(text)
+        }
     }
 
     /// Generate an excerpt from the first paragraph of article HTML
@@ -257,6 +753,15 @@ impl Readability {
                 continue;
             }
 
+            // Captions describe an image, not the article
+            let in_figure = p
+                .ancestors()
+                .filter_map(ElementRef::wrap)
+                .any(|ancestor| matches!(ancestor.value().name(), "figure" | "figcaption"));
+            if in_figure {
+                continue;
+            }
+
             let class_attr = p.value().attr("class").unwrap_or("");
             let id_attr = p.value().attr("id").unwrap_or("");
             let class_lower = class_attr.to_lowercase();
@@ -379,6 +884,8 @@ impl Readability {
 
     /// Truncate text to a maximum length, trying to break at word boundary
     ///
+    /// Never splits a grapheme cluster such as an emoji sequence.
+    ///
     /// # Arguments
     /// * `text` - Text to truncate
     /// * `max_len` - Maximum length in characters
@@ -391,7 +898,7 @@ impl Readability {
             return text.to_string();
         }
 
-        let truncated: String = text.chars().take(max_len).collect();
+        let truncated = utils::truncate_chars(text, max_len);
         if let Some(last_space_pos) = truncated.rfind(char::is_whitespace) {
             truncated[..last_space_pos].trim().to_string()
         } else {
@@ -408,9 +915,119 @@ impl Readability {
     }
 }
 
+/// URL of the first `<picture>` or `<img>` in the content, at its largest size
+///
+/// For a `<picture>`, every `<source>` and the `<img>` are considered, and the largest
+/// `srcset` candidate wins. `data:` URIs and images declared smaller than 100 pixels,
+/// such as icons, are skipped.
+fn lead_image_from_content(html: &str) -> Option<String> {
+    static SELECTOR: Lazy<Selector> = Lazy::new(|| Selector::parse("picture, img").unwrap());
+    static SOURCES: Lazy<Selector> = Lazy::new(|| Selector::parse("source[srcset], img").unwrap());
+    const MIN_SIZE: u32 = 100;
+
+    let fragment = Html::parse_fragment(html);
+    fragment.select(&SELECTOR).find_map(|element| {
+        let value = element.value();
+        if value.name() == "img"
+            && element
+                .ancestors()
+                .filter_map(ElementRef::wrap)
+                .any(|ancestor| ancestor.value().name() == "picture")
+        {
+            return None;
+        }
+        let too_small = ["width", "height"].iter().any(|name| {
+            value
+                .attr(name)
+                .and_then(|size| size.trim().parse::<u32>().ok())
+                .is_some_and(|size| size < MIN_SIZE)
+        });
+        if value.name() == "img" && too_small {
+            return None;
+        }
+
+        let sources: Vec<ElementRef> = if value.name() == "picture" {
+            element.select(&SOURCES).collect()
+        } else {
+            vec![element]
+        };
+        let srcset = sources
+            .iter()
+            .filter_map(|source| source.value().attr("srcset"))
+            .collect::<Vec<_>>()
+            .join(", ");
+        let url = utils::largest_srcset_url(&srcset).or_else(|| {
+            sources
+                .iter()
+                .find_map(|source| source.value().attr("src"))
+                .map(str::trim)
+        })?;
+        (!url.is_empty() && !url.to_ascii_lowercase().starts_with("data:")).then(|| url.to_string())
+    })
+}
+
+/// Wrap a plain-text JSON-LD `articleBody` into paragraphs, one per non-empty line
+fn json_ld_body_to_html(body: &str) -> String {
+    let mut html = String::from("<div>");
+    for line in body.lines() {
+        let text = utils::normalize_whitespace(&utils::unescape_html_entities(line));
+        let text = text.trim();
+        if text.is_empty() {
+            continue;
+        }
+        html.push_str("<p>");
+        html.push_str(
+            &text
+                .replace('&', "&amp;")
+                .replace('<', "&lt;")
+                .replace('>', "&gt;"),
+        );
+        html.push_str("</p>");
+    }
+    html.push_str("</div>");
+    html
+}
+
+/// Number of `<p>`, `<pre>` and `<blockquote>` elements with text, outside figures
+fn count_paragraphs(html: &str) -> usize {
+    static SELECTOR: Lazy<Selector> = Lazy::new(|| Selector::parse("p, pre, blockquote").unwrap());
+
+    Html::parse_fragment(html)
+        .select(&SELECTOR)
+        .filter(|paragraph| {
+            !paragraph
+                .ancestors()
+                .filter_map(ElementRef::wrap)
+                .any(|ancestor| matches!(ancestor.value().name(), "figure" | "figcaption"))
+        })
+        .filter(|paragraph| !dom_utils::get_inner_text(*paragraph, true).is_empty())
+        .count()
+}
+
+/// Number of characters of whitespace-normalized text in an element
+fn text_length(element: ElementRef) -> usize {
+    dom_utils::get_inner_text(element, true).chars().count()
+}
+
+/// Extracted text length over the text length of the whole page, capped at 1.0
+fn content_density(page: &Html, content_html: &str) -> f64 {
+    let page_length = text_length(page.root_element());
+    if page_length == 0 {
+        return 0.0;
+    }
+    let content_length = text_length(Html::parse_fragment(content_html).root_element());
+    (content_length as f64 / page_length as f64).min(1.0)
+}
+
 #[cfg(test)]
-mod tests {
+pub(crate) mod tests {
     use super::*;
+    use std::time::Duration;
+
+    /// `count` copies of a news paragraph, long enough to be scored as article text
+    pub(crate) fn article_paragraphs(count: usize) -> String {
+        "<p>The council voted on Tuesday to approve a new transit plan, which will add bus lanes, extend light rail service, and fund safety improvements.</p>".repeat(count)
+    }
 
     #[test]
     fn test_new_readability() {
@@ -427,38 +1044,1385 @@ mod tests {
     }
 
     #[test]
-    fn test_parse_simple() {
-        let html = r#"
-            <html>
-                <body>
-                    <article>
-                        <h1>Test Article</h1>
-                        <p>This is a test article with some content.</p>
-                    </article>
-                </body>
-            </html>
-        "#;
+    fn test_invalid_content_selector() {
+        for selector in ["div[&", "main >", "#"] {
+            let options = ReadabilityOptions::builder()
+                .content_selectors(vec!["#article-body".to_string(), selector.to_string()])
+                .build();
+            let result = Readability::new("<html><body></body></html>", None, Some(options));
+            match result {
+                Err(ReadabilityError::InvalidSelector(s)) => assert_eq!(s, selector),
+                _ => panic!("expected InvalidSelector for {selector:?}"),
+            }
+        }
+    }
 
-        let readability = Readability::new(html, None, None).unwrap();
-        let _article = readability.parse();
-        // For now, just test that it doesn't panic
-        // Full functionality will be tested once implementation is complete
+    #[test]
+    fn test_markdown_footnotes() {
+        let paragraph = article_paragraphs(4);
+        let html = format!(
+            r##"<html><head><title>Council approves the new transit plan</title></head><body><article>
+                <p>The plan was first proposed in 2019.<sup id="fnref1"><a href="#fn1">1</a></sup> Critics still disagree.<sup><a href="#fn2">2</a></sup></p>
+                {paragraph}
+                <section class="footnotes"><hr><ol>
+                    <li id="fn1"><p>See the 2019 council minutes. <a href="#fnref1">↩</a></p></li>
+                    <li id="fn2">Opposition statement, March 2020.</li>
+                </ol></section>
+            </article></body></html>"##
+        );
+
+        let options = ReadabilityOptions::builder()
+            .markdown_footnotes(true)
+            .build();
+        let article = Readability::new(&html, None, Some(options))
+            .unwrap()
+            .parse()
+            .unwrap();
+        assert_eq!(article.footnotes.len(), 2);
+        assert_eq!(article.footnotes[1].id, "fn2");
+
+        let markdown = article.to_markdown().unwrap();
+        assert!(markdown.starts_with(
+            "The plan was first proposed in 2019.[^1] Critics still disagree.[^2]\n\n"
+        ));
+        assert!(markdown.ends_with(
+            "\n\n[^1]: See the 2019 council minutes.\n[^2]: Opposition statement, March 2020."
+        ));
+        assert!(!markdown.contains('↩'));
+        assert!(!markdown.contains("1. See"));
+
+        // Without the option the numbers stay inline and the notes list is kept
+        let article = Readability::new(&html, None, None)
+            .unwrap()
+            .parse()
+            .unwrap();
+        assert!(article.footnotes.is_empty());
+        let markdown = article.to_markdown().unwrap();
+        assert!(markdown.contains("proposed in 2019.1 Critics"));
+        assert!(markdown.contains("1. See the 2019 council minutes."));
     }
 
     #[test]
-    fn excerpt_skips_hatnote_paragraphs() {
-        let html = r#"
-        <p class="hatnote" role="note">See also: Something else entirely.</p>
-        <p>This is the first real paragraph with sufficient length to act as an excerpt. It should be returned.</p>
-        "#;
-        let reader = Readability::new(html, None, None).unwrap();
-        let excerpt = reader.generate_excerpt_from_html(html);
+    fn test_content_density() {
+        let paragraph = article_paragraphs(6);
+        let article_page = format!(
+            r#"<html><body>
+                <nav><a href="/">Home</a> <a href="/news">News</a></nav>
+                <article>{paragraph}</article>
+                <footer>Copyright Example News</footer>
+            </body></html>"#
+        );
+        let section = (1..=12)
+            .map(|i| {
+                format!(
+                    r#"<li><a href="/story/{i}">Story number {i} with a catchy headline</a></li>"#
+                )
+            })
+            .collect::<String>();
+        let link_farm = format!(
+            r#"<html><body>
+                <div class="sports"><h2>Sports</h2><ul>{section}</ul></div>
+                <div class="business"><h2>Business</h2><ul>{section}</ul></div>
+                <div class="culture"><h2>Culture</h2><ul>{section}</ul></div>
+                <div class="intro"><p>Welcome to the front page, where every story of the day is listed for you.</p></div>
+            </body></html>"#
+        );
+
+        let article = Readability::new(&article_page, None, None).unwrap();
+        let article_density = article.content_density();
+        let farm_density = Readability::new(&link_farm, None, None)
+            .unwrap()
+            .content_density();
+        assert!(article_density > 0.9, "article density {article_density}");
+        assert!(farm_density < 0.5, "link farm density {farm_density}");
         assert_eq!(
-            excerpt,
-            Some(
-                "This is the first real paragraph with sufficient length to act as an excerpt. It should be returned."
-                    .to_string()
-            )
+            article.parse_with_stats().1.content_density,
+            article_density
+        );
+
+        let empty = Readability::new("<html><body></body></html>", None, None).unwrap();
+        assert_eq!(empty.content_density(), 0.0);
+    }
+
+    #[test]
+    fn test_download_attribute_survives_cleaning() {
+        let paragraph = "<p>The council published its annual report on Tuesday, covering the transit plan, the housing budget, and the new safety improvements across the city.</p>".repeat(4);
+        let html = format!(
+            r#"<html><body><article>
+                <p>Read the <a href="/files/report.pdf" download="report-2024.pdf">full report</a> or the <a href="/files/summary.pdf" download>summary</a>.</p>
+                {paragraph}
+            </article></body></html>"#
+        );
+
+        let article = Readability::new(&html, Some("https://example.com/news/report"), None)
+            .unwrap()
+            .parse()
+            .unwrap();
+        let content = article.content.unwrap();
+        assert!(content.contains(
+            r#"<a download="report-2024.pdf" href="https://example.com/files/report.pdf">"#
+        ));
+        assert!(content.contains(r#"<a download="" href="https://example.com/files/summary.pdf">"#));
+    }
+
+    #[test]
+    fn test_summary_block_kept_in_body() {
+        let paragraph = article_paragraphs(5);
+        let html = format!(
+            r#"<html><body><article>
+                <div class="key-points"><ul>
+                    <li>New bus lanes open next spring.</li>
+                    <li>Light rail reaches the airport by 2027.</li>
+                    <li>Read the <a href="/plan.pdf">full plan</a>.</li>
+                </ul></div>
+                {paragraph}
+            </article></body></html>"#
+        );
+
+        let article = Readability::new(&html, Some("https://example.com/news/transit"), None)
+            .unwrap()
+            .parse()
+            .unwrap();
+        let summary = article.summary_block.unwrap();
+        assert!(summary.starts_with(r#"<div class="key-points">"#));
+        assert!(summary.contains("Light rail reaches the airport by 2027."));
+        assert!(!summary.contains("council voted"));
+        assert!(summary.contains(r#"href="https://example.com/plan.pdf""#));
+        assert!(article
+            .content
+            .unwrap()
+            .contains("Light rail reaches the airport by 2027."));
+    }
+
+    #[test]
+    fn test_invalid_utf8_input() {
+        let paragraph = article_paragraphs(4);
+        let mut bytes = b"<html><head><title>Transit \xff\xfe plan \xed\xa0\x80 approved</title>\
+            <meta name=\"author\" content=\"\xe2\x84\xaaate\xc5\x99ina Nov\xc3\xa1\xc4\x8d\xc0 | Mar 5, 2024 10:00 am\">\
+            </head><body><article><p class=\"byline\">By \xe2\x84\xaaate\xc5\x99ina \xf0\x9f Nov\xc3\xa1\xc4\x8d</p>"
+            .to_vec();
+        bytes.extend_from_slice(paragraph.as_bytes());
+        bytes.extend_from_slice(b"<p>Broken \xc3 bytes \x80\x80 inside a \xf0\x9f\x98 paragraph.</p></article></body></html>");
+
+        let html = String::from_utf8_lossy(&bytes);
+        let article = Readability::new(&html, None, None)
+            .unwrap()
+            .parse()
+            .unwrap();
+        assert!(article.title.unwrap().contains('\u{fffd}'));
+        assert!(article.byline.is_some());
+        assert!(article
+            .text_content
+            .unwrap()
+            .contains("Broken \u{fffd} bytes"));
+    }
+
+    #[test]
+    fn test_ruby_annotations_survive() {
+        let paragraph = "<p>市議会は火曜日、新しい交通計画を承認した。バス専用レーンの追加、ライトレールの延伸、そして市内全域の安全対策に予算が充てられる。市長は記者会見で、この計画が市民の生活を大きく変えると述べた。</p>".repeat(6);
+        let html = format!(
+            r#"<html lang="ja"><body><article>
+                <p>今日は<ruby>漢字<rp>(</rp><rt>かんじ</rt><rp>)</rp></ruby>の勉強をした。<ruby>東<rt>とう</rt>京<rt>きょう</rt></ruby>へ行く。</p>
+                {paragraph}
+            </article></body></html>"#
+        );
+
+        let article = Readability::new(&html, None, None)
+            .unwrap()
+            .parse()
+            .unwrap();
+        assert!(article
+            .content
+            .as_deref()
+            .unwrap()
+            .contains("<ruby>東<rt>とう</rt>京<rt>きょう</rt></ruby>"));
+        assert!(article
+            .to_text()
+            .starts_with("今日は漢字(かんじ)の勉強をした。東(とう)京(きょう)へ行く。"));
+
+        let options = ReadabilityOptions::builder().preserve_ruby(false).build();
+        let article = Readability::new(&html, None, Some(options))
+            .unwrap()
+            .parse()
+            .unwrap();
+        assert!(!article.content.as_deref().unwrap().contains("<rt>"));
+        assert!(article
+            .to_text()
+            .starts_with("今日は漢字の勉強をした。東京へ行く。"));
+    }
+
+    #[test]
+    fn test_parse_on_another_thread() {
+        fn assert_send<T: Send>() {}
+        assert_send::<Readability>();
+        assert_send::<Article>();
+
+        let paragraph = article_paragraphs(5);
+        let html = format!("<html><head><title>Transit plan approved</title></head><body><article>{paragraph}</article></body></html>");
+        let readability = Readability::new(&html, None, None).unwrap();
+
+        let article = std::thread::spawn(move || readability.parse())
+            .join()
+            .unwrap()
+            .unwrap();
+        assert_eq!(article.title.as_deref(), Some("Transit plan approved"));
+        assert!(article.length > 500);
+    }
+
+    #[test]
+    fn test_video_poster_is_absolutized() {
+        let paragraph = article_paragraphs(4);
+        let html = format!(
+            r#"<html><body><article>
+                <p>Watch the vote as it happened in the council chamber.</p>
+                <video controls poster="/media/vote-poster.jpg"><source src="/media/vote.mp4" type="video/mp4"></video>
+                {paragraph}
+                <figure><video src="clip.webm" poster='thumbs/clip.png'></video><figcaption>The vote</figcaption></figure>
+            </article></body></html>"#
+        );
+
+        let article = Readability::new(&html, Some("https://example.com/news/vote"), None)
+            .unwrap()
+            .parse()
+            .unwrap();
+        let content = article.content.unwrap();
+        assert!(content.contains(r#"poster="https://example.com/media/vote-poster.jpg""#));
+        assert!(content.contains(r#"poster="https://example.com/news/thumbs/clip.png""#));
+        assert!(content.contains(r#"src="https://example.com/media/vote.mp4""#));
+    }
+
+    #[test]
+    fn test_audio_player_is_kept_and_absolutized() {
+        let paragraph = article_paragraphs(4);
+        let html = format!(
+            r#"<html><body><article>
+                <p>Listen to this episode of our weekly city podcast.</p>
+                <div class="player"><audio controls><source src="../audio/ep12.mp3" type="audio/mpeg"></audio></div>
+                {paragraph}
+            </article></body></html>"#
+        );
+
+        let article = Readability::new(&html, Some("https://example.com/podcast/ep12"), None)
+            .unwrap()
+            .parse()
+            .unwrap();
+        let content = article.content.unwrap();
+        assert!(content.contains("<audio"));
+        assert!(content.contains(r#"src="https://example.com/audio/ep12.mp3""#));
+    }
+
+    #[test]
+    fn test_parse_batch_with_dedup_removes_shared_footer() {
+        let footer = r#"<div class="signup"><p>Sign up for our morning newsletter to get the day's top stories, delivered to your inbox every weekday before breakfast.</p></div>"#;
+        let topics = ["transit plan", "library budget", "harbour dredging"];
+        let pages: Vec<String> = topics
+            .iter()
+            .map(|topic| {
+                let paragraph = format!("<p>The council voted on Tuesday to approve the {topic}, after a long debate in which members raised questions about cost, timing and public consultation.</p>").repeat(4);
+                format!("<html><body><article><h1>Council approves {topic}</h1>{paragraph}{footer}</article></body></html>")
+            })
+            .collect();
+
+        let readers = pages
+            .iter()
+            .map(|html| Readability::new(html, None, None).unwrap())
+            .collect();
+        let articles = Readability::parse_batch_with_dedup(readers);
+
+        assert_eq!(articles.len(), 3);
+        for (article, topic) in articles.iter().zip(topics) {
+            let article = article.as_ref().unwrap();
+            let content = article.content.as_deref().unwrap();
+            let text = article.text_content.as_deref().unwrap();
+            assert!(!content.contains("morning newsletter"));
+            assert!(!text.contains("morning newsletter"));
+            assert!(text.contains(topic));
+            assert_eq!(article.length, text.len());
+        }
+
+        let single = Readability::new(&pages[0], None, None)
+            .unwrap()
+            .parse()
+            .unwrap();
+        assert!(single.content.unwrap().contains("morning newsletter"));
+    }
+
+    #[test]
+    fn test_min_paragraphs_rejects_caption_stub() {
+        let caption = "A wide view of the harbour at dawn, with the fishing fleet returning, gulls circling over the quay, the old lighthouse on the point, and the hills behind the town lit by the first sun. ".repeat(4);
+        let html = format!(
+            r#"<html><body><article>
+                <p>Our photo of the day, taken by a reader, shows the harbour.</p>
+                <figure><img src="https://example.com/harbour.jpg" alt="Harbour"><figcaption>{caption}</figcaption></figure>
+            </article></body></html>"#
+        );
+
+        let article = Readability::new(&html, None, None).unwrap().parse();
+        assert!(article.is_some());
+
+        let options = ReadabilityOptions::builder().min_paragraphs(2).build();
+        let article = Readability::new(&html, None, Some(options))
+            .unwrap()
+            .parse();
+        assert!(article.is_none());
+    }
+
+    #[test]
+    fn test_lead_image_from_content_picture() {
+        let paragraph = article_paragraphs(4);
+        let html = format!(
+            r#"<html><head><title>Transit plan approved</title></head><body><article>
+                <p><img src="/icons/share.png" width="16" height="16" alt="Share"></p>
+                <picture>
+                    <source type="image/webp" srcset="/img/tram-640.webp 640w, /img/tram-1600.webp 1600w">
+                    <img src="/img/tram-800.jpg" srcset="/img/tram-800.jpg 800w, /img/tram-1200.jpg 1200w" alt="A tram">
+                </picture>
+                {paragraph}
+                <img src="/img/map.jpg" alt="Route map">
+            </article></body></html>"#
+        );
+
+        let article = Readability::new(&html, Some("https://example.com/news/transit"), None)
+            .unwrap()
+            .parse()
+            .unwrap();
+        assert_eq!(
+            article.image.as_deref(),
+            Some("https://example.com/img/tram-1600.webp")
+        );
+
+        // Social metadata still takes precedence
+        let html = html.replace(
+            "<title>",
+            r#"<meta property="og:image" content="https://example.com/social.jpg"><title>"#,
+        );
+        let article = Readability::new(&html, None, None)
+            .unwrap()
+            .parse()
+            .unwrap();
+        assert_eq!(
+            article.image.as_deref(),
+            Some("https://example.com/social.jpg")
+        );
+    }
+
+    #[test]
+    fn test_correct_float_order_moves_callout() {
+        let paragraph = article_paragraphs(2);
+        let html = format!(
+            r#"<html><body><article>
+                {paragraph}
+                <div class="callout" style="float: right; width: 30%"><p>Did you know? The city's first tram line opened in 1891 and ran until 1957.</p></div>
+                {paragraph}
+                <p>Work on the first bus lanes is expected to begin next spring, once the contracts are signed.</p>
+            </article></body></html>"#
+        );
+
+        let options = ReadabilityOptions::builder()
+            .correct_float_order(true)
+            .build();
+        let content = Readability::new(&html, None, Some(options))
+            .unwrap()
+            .parse()
+            .unwrap()
+            .content
+            .unwrap();
+        let callout = content.find("Did you know?").unwrap();
+        let last_paragraph = content.find("Work on the first bus lanes").unwrap();
+        assert!(callout > last_paragraph, "{content}");
+
+        let content = Readability::new(&html, None, None)
+            .unwrap()
+            .parse()
+            .unwrap()
+            .content
+            .unwrap();
+        assert!(content.find("Did you know?") < content.find("Work on the first bus lanes"));
+    }
+
+    #[test]
+    fn test_max_duration_budget() {
+        let paragraph = article_paragraphs(3);
+        let html = format!("<html><head><title>Transit plan</title></head><body><article>{paragraph}</article></body></html>");
+
+        // An exhausted budget stops after metadata
+        let options = ReadabilityOptions::builder()
+            .max_duration(Duration::ZERO)
+            .build();
+        let (article, stats) = Readability::new(&html, None, Some(options))
+            .unwrap()
+            .parse_with_stats();
+        assert!(article.is_none());
+        assert_eq!(stats.budget_exceeded.as_deref(), Some("metadata"));
+
+        let options = ReadabilityOptions::builder()
+            .max_duration(Duration::from_secs(60))
+            .build();
+        let (budgeted, stats) = Readability::new(&html, None, Some(options))
+            .unwrap()
+            .parse_with_stats();
+        let budgeted = budgeted.unwrap();
+        assert_eq!(stats.budget_exceeded, None);
+        let plain = Readability::new(&html, None, None)
+            .unwrap()
+            .parse()
+            .unwrap();
+        assert_eq!(budgeted.content, plain.content);
+    }
+
+    #[test]
+    fn test_annotate_original_tags() {
+        let paragraph = article_paragraphs(3);
+        let html = format!(
+            r#"<html><body><article>
+                {paragraph}
+                <div>Construction on the first bus lanes is expected to begin next spring, once the contracts have been signed.</div>
+                {paragraph}
+            </article></body></html>"#
+        );
+
+        let options = ReadabilityOptions::builder()
+            .annotate_original_tags(true)
+            .build();
+        let content = Readability::new(&html, None, Some(options))
+            .unwrap()
+            .parse()
+            .unwrap()
+            .content
+            .unwrap();
+        assert!(
+            content.contains(r#"<p data-orig-tag="div">Construction on the first bus lanes"#),
+            "{content}"
+        );
+        assert_eq!(content.matches("data-orig-tag").count(), 1);
+
+        let content = Readability::new(&html, None, None)
+            .unwrap()
+            .parse()
+            .unwrap()
+            .content
+            .unwrap();
+        assert!(!content.contains("data-orig-tag"));
+    }
+
+    #[test]
+    fn test_annotate_positions() {
+        let paragraphs = article_paragraphs(2);
+        let html = format!(
+            r#"<html><body>
+                <nav><ul><li><a href="/">Home</a></li><li><a href="/news">News</a></li></ul></nav>
+                <article>
+                    <h2>Transit plan approved</h2>
+                    {paragraphs}
+                    <blockquote>We have waited a long time for this, and the whole city will benefit from it.</blockquote>
+                    {paragraphs}
+                </article>
+            </body></html>"#
+        );
+
+        let options = ReadabilityOptions::builder()
+            .annotate_positions(true)
+            .build();
+        let article = Readability::new(&html, None, Some(options))
+            .unwrap()
+            .parse()
+            .unwrap();
+        let content = article.content.unwrap();
+        let positions: Vec<usize> = regex::Regex::new(r#"data-pos="(\d+)""#)
+            .unwrap()
+            .captures_iter(&content)
+            .map(|caps| caps[1].parse().unwrap())
+            .collect();
+        assert_eq!(positions.len(), 6, "{content}");
+        assert!(positions.windows(2).all(|pair| pair[0] < pair[1]));
+        // The navigation list items come first in the source
+        assert!(positions[0] >= 2);
+
+        let article = Readability::new(&html, None, None)
+            .unwrap()
+            .parse()
+            .unwrap();
+        assert!(!article.content.unwrap().contains("data-pos"));
+    }
+
+    #[test]
+    fn test_collect_source_paths() {
+        let paragraphs = article_paragraphs(2);
+        let html = format!(
+            r#"<html><head><script>var x = 1;</script></head><body>
+                <nav><ul><li><a href="/">Home</a></li><li><a href="/news">News</a></li></ul></nav>
+                <article>
+                    <h2>Transit plan approved</h2>
+                    {paragraphs}
+                    <blockquote>We have waited a long time for this, and the whole city will benefit from it.</blockquote>
+                    {paragraphs}
+                </article>
+            </body></html>"#
+        );
+
+        let options = ReadabilityOptions::builder()
+            .collect_source_paths(true)
+            .build();
+        let article = Readability::new(&html, None, Some(options))
+            .unwrap()
+            .parse()
+            .unwrap();
+        let content = article.content.unwrap();
+        assert!(!content.contains("data-pos"));
+        assert_eq!(article.source_paths.len(), 6, "{:?}", article.source_paths);
+
+        let source = Html::parse_document(&html);
+        for path in &article.source_paths {
+            let selector = Selector::parse(path).unwrap();
+            let matches: Vec<_> = source.select(&selector).collect();
+            assert_eq!(matches.len(), 1, "{path}");
+            assert!(matches!(
+                matches[0].value().name(),
+                "h2" | "p" | "blockquote"
+            ));
+            let text = matches[0].text().collect::<String>();
+            assert!(content.contains(text.trim()), "{path}");
+        }
+    }
+
+    #[test]
+    fn test_paragraph_like_tags() {
+        let paragraph = "<p>The council voted on Tuesday to approve a new transit plan, which will add bus lanes, extend light rail service, and fund safety improvements across the city over the next decade.</p>";
+        let html = format!(
+            r#"<html><body><div class="story">{paragraph}{paragraph}{paragraph}{paragraph}</div><section>Construction is expected to begin next spring once the final contracts are signed.</section></body></html>"#
+        );
+
+        let text = Readability::new(&html, None, None)
+            .unwrap()
+            .parse()
+            .unwrap()
+            .text_content
+            .unwrap();
+        assert!(!text.contains("Construction is expected"));
+
+        let options = ReadabilityOptions::builder()
+            .paragraph_like_tags(vec!["p".to_string(), "section".to_string()])
+            .build();
+        let text = Readability::new(&html, None, Some(options))
+            .unwrap()
+            .parse()
+            .unwrap()
+            .text_content
+            .unwrap();
+        assert!(text.contains("Construction is expected"), "{text}");
+
+        // An empty list scores plain paragraphs, as by default
+        let options = ReadabilityOptions::builder()
+            .paragraph_like_tags(Vec::new())
+            .build();
+        let article = Readability::new(&html, None, Some(options))
+            .unwrap()
+            .parse()
+            .unwrap();
+        assert!(article
+            .text_content
+            .unwrap()
+            .contains("The council voted on Tuesday"));
+
+        for tag in ["div > p", "", "li,"] {
+            let options = ReadabilityOptions::builder()
+                .paragraph_like_tags(vec!["p".to_string(), tag.to_string()])
+                .build();
+            match Readability::new(&html, None, Some(options)) {
+                Err(ReadabilityError::InvalidSelector(s)) => assert_eq!(s, tag),
+                _ => panic!("expected InvalidSelector for {tag:?}"),
+            }
+        }
+    }
+
+    #[test]
+    fn test_prefer_jsonld_body() {
+        let page = |body: &str| {
+            let json_ld = serde_json::json!({
+                "@context": "https://schema.org",
+                "@type": "NewsArticle",
+                "headline": "Transit plan approved",
+                "articleBody": body,
+            });
+            let older = "<p>Last year the council delayed the vote twice, citing the cost of new buses, the shortage of drivers, and objections from residents.</p>".repeat(6);
+            format!(
+                r#"<html><head><script type="application/ld+json">{json_ld}</script></head>
+                <body><div class="story">{older}</div></body></html>"#
+            )
+        };
+        let body = "The council voted on Tuesday to approve a new transit plan, which will add bus lanes, extend light rail service, and fund safety improvements.\n\nWork on the first bus lanes is expected to begin next spring, once the contracts are signed & the routes are final.\n\n".repeat(3);
+
+        let options = ReadabilityOptions::builder()
+            .prefer_jsonld_body(true)
+            .build();
+        let article = Readability::new(&page(&body), None, Some(options))
+            .unwrap()
+            .parse()
+            .unwrap();
+        // Unscored content gets a neutral margin: 0.35 + 0.175, plus 0.15 for no links
+        assert!(
+            (article.confidence - 0.675).abs() < 1e-6,
+            "{}",
+            article.confidence
+        );
+        let content = article.content.unwrap();
+        assert!(
+            content.contains("<p>The council voted on Tuesday"),
+            "{content}"
+        );
+        assert!(content.contains("signed &amp; the routes"));
+        assert!(!content.contains("Last year the council"));
+        assert_eq!(count_paragraphs(&content), 6);
+
+        // A short body falls back to scoring the page
+        let options = ReadabilityOptions::builder()
+            .prefer_jsonld_body(true)
+            .build();
+        let content = Readability::new(&page("Transit plan approved."), None, Some(options))
+            .unwrap()
+            .parse()
+            .unwrap()
+            .content
+            .unwrap();
+        assert!(content.contains("Last year the council"));
+    }
+
+    #[test]
+    fn test_keep_table_columns() {
+        let paragraph = article_paragraphs(3);
+        let html = format!(
+            r#"<html><body><article>
+                {paragraph}
+                <table>
+                    <colgroup><col span="2" style="background: #eee"><col></colgroup>
+                    <thead><tr><th>Route</th><th>Stops</th><th>Opens</th></tr></thead>
+                    <tbody>
+                        <tr><td>Line 1</td><td>14</td><td>2026</td></tr>
+                        <tr><td>Line 2</td><td>9</td><td>2027</td></tr>
+                    </tbody>
+                </table>
+                {paragraph}
+            </article></body></html>"#
+        );
+
+        let content = Readability::new(&html, None, None)
+            .unwrap()
+            .parse()
+            .unwrap()
+            .content
+            .unwrap();
+        assert!(content.contains(r#"<colgroup><col span="2">"#), "{content}");
+
+        let options = ReadabilityOptions::builder()
+            .keep_table_columns(false)
+            .build();
+        let content = Readability::new(&html, None, Some(options))
+            .unwrap()
+            .parse()
+            .unwrap()
+            .content
+            .unwrap();
+        assert!(content.contains("<th>Route</th>"));
+        assert!(!content.contains("<col"));
+    }
+
+    #[test]
+    fn test_excerpt_skips_figure_captions() {
+        let paragraph = article_paragraphs(4);
+        let html = format!(
+            r#"<html><body><article>
+                <figure>
+                    <img src="/img/tram.jpg" alt="">
+                    <figcaption><p>A tram crosses the river bridge near the central station on Monday morning.</p></figcaption>
+                </figure>
+                <p>City leaders say the plan is the largest transit investment in a generation.</p>
+                {paragraph}
+            </article></body></html>"#
+        );
+
+        let article = Readability::new(&html, None, None)
+            .unwrap()
+            .parse()
+            .unwrap();
+        assert_eq!(
+            article.excerpt.as_deref(),
+            Some("City leaders say the plan is the largest transit investment in a generation.")
+        );
+    }
+
+    #[test]
+    fn test_parse_simple() {
+        let html = r#"
+            <html>
+                <body>
+                    <article>
+                        <h1>Test Article</h1>
+                        <p>This is a test article with some content.</p>
+                    </article>
+                </body>
+            </html>
+        "#;
+
+        let readability = Readability::new(html, None, None).unwrap();
+        let _article = readability.parse();
+        // For now, just test that it doesn't panic
+        // Full functionality will be tested once implementation is complete
+    }
+
+    #[test]
+    fn excerpt_skips_hatnote_paragraphs() {
+        let html = r#"
+        <p class="hatnote" role="note">See also: Something else entirely.</p>
+        <p>This is the first real paragraph with sufficient length to act as an excerpt. It should be returned.</p>
+        "#;
+        let reader = Readability::new(html, None, None).unwrap();
+        let excerpt = reader.generate_excerpt_from_html(html);
+        assert_eq!(
+            excerpt,
+            Some(
+                "This is the first real paragraph with sufficient length to act as an excerpt. It should be returned."
+                    .to_string()
+            )
+        );
+    }
+
+    #[test]
+    fn excerpt_skips_arabic_navigation_menu() {
+        let html = r#"
+        <p>الرئيسية | أخبار محلية | رياضة | اقتصاد | ثقافة وفنون | اتصل بنا</p>
+        <p>أعلنت الحكومة اليوم عن خطة جديدة لدعم المزارعين في المناطق الريفية خلال الموسم المقبل.</p>
+        "#;
+        let reader = Readability::new(html, None, None).unwrap();
+        let excerpt = reader.generate_excerpt_from_html(html);
+        assert_eq!(
+            excerpt.as_deref(),
+            Some("أعلنت الحكومة اليوم عن خطة جديدة لدعم المزارعين في المناطق الريفية خلال الموسم المقبل.")
+        );
+    }
+
+    #[test]
+    fn test_published_time_inferred_from_url() {
+        let html = r#"
+            <html>
+                <head><title>Notes from the trail</title></head>
+                <body>
+                    <article>
+                        <p>We set out before dawn, following the ridge line as the sun crept over the far hills and lit the valley below.</p>
+                        <p>By noon the trail had narrowed to a goat path, and we stopped to rest in the shade of a rocky outcrop.</p>
+                    </article>
+                </body>
+            </html>
+        "#;
+
+        let reader = Readability::new(
+            html,
+            Some("https://blog.example.com/2015/05/12/notes-from-the-trail/"),
+            None,
+        )
+        .unwrap();
+        let article = reader.parse().unwrap();
+        assert_eq!(article.published_time.as_deref(), Some("2015-05-12"));
+        assert!(article.published_time_inferred);
+    }
+
+    #[test]
+    fn test_prefer_heading_title_over_meta() {
+        let html = r#"
+            <html>
+                <head>
+                    <title>Best Cheap Flights 2024 | Deals | Travel | Example</title>
+                    <meta property="og:title" content="Best Cheap Flights 2024 - Top Deals, Tips and Hacks" />
+                </head>
+                <body>
+                    <article>
+                        <h1>How one airline quietly changed its fares</h1>
+                        <p>Fares on short routes have shifted over the past year in ways most travellers never noticed until they compared receipts.</p>
+                        <p>Analysts say the change reflects a broader move toward dynamic pricing that rewards early booking and flexible dates.</p>
+                    </article>
+                </body>
+            </html>
+        "#;
+
+        let options = ReadabilityOptions::builder()
+            .prefer_heading_title(true)
+            .build();
+        let article = Readability::new(html, None, Some(options))
+            .unwrap()
+            .parse()
+            .unwrap();
+        assert_eq!(
+            article.title.as_deref(),
+            Some("How one airline quietly changed its fares")
+        );
+        assert_eq!(
+            article.meta_title.as_deref(),
+            Some("Best Cheap Flights 2024 - Top Deals, Tips and Hacks")
+        );
+
+        let article = Readability::new(html, None, None).unwrap().parse().unwrap();
+        assert_eq!(article.title, article.meta_title);
+    }
+
+    #[test]
+    fn test_confidence_rewards_metadata() {
+        let body = r#"
+                <body>
+                    <article>
+                        <p>We set out before dawn, following the ridge line as the sun crept over the far hills and lit the valley below.</p>
+                        <p>By noon the trail had narrowed to a goat path, and we stopped to rest in the shade of a rocky outcrop.</p>
+                        <p>The descent took longer than planned, but the lake at the bottom was worth every step of the way down.</p>
+                    </article>
+                </body>"#;
+        let bare = format!("<html><head><title>Trail notes</title></head>{body}</html>");
+        let rich = format!(
+            r#"<html><head><title>Trail notes</title>
+                <meta name="author" content="Jane Doe">
+                <meta property="article:published_time" content="2015-05-12T08:00:00Z">
+            </head>{body}</html>"#
+        );
+
+        let bare = Readability::new(&bare, None, None)
+            .unwrap()
+            .parse()
+            .unwrap();
+        let rich = Readability::new(&rich, None, None)
+            .unwrap()
+            .parse()
+            .unwrap();
+
+        assert!(bare.confidence > 0.0 && bare.confidence <= 1.0);
+        assert!(rich.confidence > bare.confidence);
+    }
+
+    #[test]
+    fn test_hr_scene_break_is_preserved() {
+        let html = r#"
+            <html>
+                <head><title>Essay</title></head>
+                <body>
+                    <article>
+                        <p>The first scene opens in the kitchen, where the kettle has been whistling for a long time and nobody moves.</p>
+                        <p>She stares out of the window at the rain, counting the cars that pass and wondering which one is his.</p>
+                        <hr>
+                        <p>The second scene takes place years later, in a different city, where the same kettle sits on another stove.</p>
+                    </article>
+                </body>
+            </html>
+        "#;
+
+        let article = Readability::new(html, None, None).unwrap().parse().unwrap();
+        assert!(article.content.as_deref().unwrap().contains("<hr"));
+
+        let text = article.to_text();
+        assert!(text.contains("which one is his.\n\n---\n\nThe second scene"));
+    }
+
+    #[test]
+    fn test_byline_removed_from_content() {
+        let html = r#"
+            <html>
+                <head><title>Budget</title></head>
+                <body>
+                    <article>
+                        <h1>Council approves budget</h1>
+                        <p class="byline">By Jane Doe</p>
+                        <p>The council approved the new budget on Tuesday, after weeks of debate, several amendments, and a long public hearing.</p>
+                        <p>Funding for parks, libraries, and road repairs rises slightly, while the transit subsidy stays flat for another year.</p>
+                    </article>
+                </body>
+            </html>
+        "#;
+
+        let article = Readability::new(html, None, None).unwrap().parse().unwrap();
+        assert_eq!(article.byline.as_deref(), Some("By Jane Doe"));
+        assert!(!article.content.unwrap().contains("By Jane Doe"));
+
+        let options = ReadabilityOptions::builder()
+            .remove_byline_from_content(false)
+            .build();
+        let article = Readability::new(html, None, Some(options))
+            .unwrap()
+            .parse()
+            .unwrap();
+        assert!(article.content.unwrap().contains("By Jane Doe"));
+    }
+
+    #[test]
+    fn test_collect_links() {
+        let html = r##"
+            <html>
+                <head><title>Sources</title></head>
+                <body>
+                    <article>
+                        <p>The study, published in <a href="https://journal.example.org/paper">a peer-reviewed journal</a>, followed thousands of patients over ten years.</p>
+                        <p>Our <a href="/methodology">methodology page</a> explains the approach, and <a href="#notes">the notes</a> list every source we used.</p>
+                        <p>Critics of <a href="https://journal.example.org/paper">the paper</a> argue the sample was too narrow, as <a href="story#comments">readers noted</a>.</p>
+                    </article>
+                </body>
+            </html>
+        "##;
+
+        let url = Some("https://news.example.com/2024/story");
+        let article = Readability::new(html, url, None).unwrap().parse().unwrap();
+        assert!(article.links.is_empty());
+
+        let options = ReadabilityOptions::builder().collect_links(true).build();
+        let article = Readability::new(html, url, Some(options))
+            .unwrap()
+            .parse()
+            .unwrap();
+        assert_eq!(
+            article.links,
+            vec![
+                LinkRef {
+                    href: "https://journal.example.org/paper".to_string(),
+                    text: "a peer-reviewed journal".to_string(),
+                },
+                LinkRef {
+                    href: "https://news.example.com/methodology".to_string(),
+                    text: "methodology page".to_string(),
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn test_content_language_hint_strips_french_byline() {
+        let html = r#"
+            <html>
+                <head>
+                    <title>Budget municipal</title>
+                    <meta property="og:locale" content="en_US">
+                </head>
+                <body>
+                    <article>
+                        <p class="byline">Par Jean Dupont, le 3 mars 2024</p>
+                        <p>Le conseil municipal a adopté le nouveau budget mardi, après des semaines de débats, plusieurs amendements et une longue audience publique.</p>
+                        <p>Le financement des parcs, des bibliothèques et de la voirie augmente légèrement, tandis que la subvention aux transports reste stable.</p>
+                    </article>
+                </body>
+            </html>
+        "#;
+
+        let article = Readability::new(html, None, None).unwrap().parse().unwrap();
+        assert_eq!(
+            article.byline.as_deref(),
+            Some("Par Jean Dupont, le 3 mars 2024")
+        );
+
+        let options = ReadabilityOptions::builder().content_language("fr").build();
+        let article = Readability::new(html, None, Some(options))
+            .unwrap()
+            .parse()
+            .unwrap();
+        assert_eq!(article.byline.as_deref(), Some("Jean Dupont"));
+        assert_eq!(article.lang.as_deref(), Some("en"));
+        assert!(!article.content.unwrap().contains("Jean Dupont"));
+    }
+
+    #[test]
+    fn test_parse_with_stats() {
+        let html = r#"
+            <html>
+                <head><title>Harbour</title></head>
+                <body>
+                    <article>
+                        <p>The first paragraph of the story explains what happened at the harbour this morning, as <a href="/reports/harbour">the report</a> describes.</p>
+                        <img src="images/harbour.jpg" alt="The harbour">
+                        <p>The second paragraph adds detail from witnesses who watched the ships arrive at dawn and unload their cargo.</p>
+                        <nav><a href="/a">Related one</a> <a href="/b">Related two</a></nav>
+                    </article>
+                </body>
+            </html>
+        "#;
+
+        let reader = Readability::new(html, Some("https://example.com/news/story"), None).unwrap();
+        let (article, stats) = reader.parse_with_stats();
+        let content = article.unwrap().content.unwrap();
+
+        assert!(content.contains(r#"src="https://example.com/news/images/harbour.jpg""#));
+        assert!(content.contains(r#"href="https://example.com/reports/harbour""#));
+        assert!(stats.links_absolutized >= 2);
+        assert_eq!(stats.images_kept, 1);
+        assert!(stats.elements_removed >= 1);
+        assert!(!content.contains("Related one"));
+    }
+
+    #[test]
+    fn test_trace_removed_blocks() {
+        use crate::stats::RemovalReason;
+
+        let html = r#"
+            <html>
+                <head><title>Harbour</title></head>
+                <body>
+                    <article>
+                        <p>The first paragraph of the story explains what happened at the harbour this morning, when the ships arrived.</p>
+                        <div class="sidebar-widget" id="more"><h3>Most read</h3><p>Harbour fees rise</p></div>
+                        <p>The second paragraph adds detail from witnesses who watched the ships arrive at dawn and unload their cargo.</p>
+                        <nav><a href="/a">Related one</a> <a href="/b">Related two</a></nav>
+                    </article>
+                </body>
+            </html>
+        "#;
+
+        let (_, stats) = Readability::new(html, None, None)
+            .unwrap()
+            .parse_with_stats();
+        assert!(stats.elements_removed >= 2);
+        assert!(stats.removed_blocks.is_empty());
+
+        let options = ReadabilityOptions::builder()
+            .trace_removed_blocks(true)
+            .build();
+        let (_, stats) = Readability::new(html, None, Some(options))
+            .unwrap()
+            .parse_with_stats();
+        assert_eq!(stats.removed_blocks.len(), stats.elements_removed);
+        assert!(stats
+            .removed_blocks
+            .iter()
+            .any(|block| block.tag == "nav" && block.reason == RemovalReason::NavigationLike));
+        let sidebar = stats
+            .removed_blocks
+            .iter()
+            .find(|block| block.id.as_deref() == Some("more"))
+            .unwrap();
+        assert_eq!(sidebar.class.as_deref(), Some("sidebar-widget"));
+        assert_eq!(sidebar.reason, RemovalReason::NavigationLike);
+        assert!(sidebar.text_length > 0);
+    }
+
+    #[test]
+    fn test_center_wrapped_paragraph_survives() {
+        let html = r#"
+            <html>
+                <head><title>Old page</title></head>
+                <body>
+                    <center><font size="4">The line opened in 1887 and carried coal, timber, and passengers between the valley towns for more than sixty years.</font></center>
+                    <p>Its last train ran in 1951, and the old station is now a museum run by volunteers who restore the carriages by hand.</p>
+                    <p>Visitors can ride a restored carriage on the first Sunday of each month, weather permitting, for a small donation.</p>
+                </body>
+            </html>
+        "#;
+
+        let article = Readability::new(html, None, None).unwrap().parse().unwrap();
+        let content = article.content.unwrap();
+        assert!(content.contains("The line opened in 1887"));
+        assert!(!content.contains("<center"));
+        assert!(!content.contains("<font"));
+    }
+
+    #[test]
+    fn test_text_content_keeps_hyphens_in_code() {
+        let text = Readability::get_text_content(
+            "<p>More informa-\ntion is needed.</p><pre>total = base-\nfee</pre><p>Run <code>make-\ninstall</code> now.</p>",
+        );
+        assert!(text.contains("More information is needed."));
+        assert!(text.contains("total = base-\nfee"));
+        assert!(text.contains("make-\ninstall"));
+    }
+
+    #[test]
+    fn test_invisible_chars_stripped_from_fallback_fields() {
+        let html = "<html><head><title>Harbour news - by Jane Doe\u{200b} - Example News</title></head><body><article>\
+            <h1>Harbour\u{200b} reopens</h1>\
+            <p>\u{feff}Ferries are running again after a week of repairs to the storm-damaged quays.</p>\
+            <p>The harbour reopened on Tuesday, and fishing boats, cargo ships, and tour boats returned to their berths.</p>\
+            </article></body></html>";
+
+        let options = ReadabilityOptions::builder()
+            .prefer_heading_title(true)
+            .build();
+        let article = Readability::new(html, None, Some(options))
+            .unwrap()
+            .parse()
+            .unwrap();
+        assert_eq!(article.title.as_deref(), Some("Harbour reopens"));
+        assert_eq!(article.byline.as_deref(), Some("Jane Doe"));
+        assert!(article
+            .excerpt
+            .unwrap()
+            .starts_with("Ferries are running again"));
+    }
+
+    #[test]
+    fn test_heading_title_removed_from_content() {
+        let html = r#"
+            <html>
+                <head><title>Budget vote | City News - Best local news, weather and sports</title></head>
+                <body>
+                    <article>
+                        <h1>Council approves budget</h1>
+                        <p>The council approved the new budget on Tuesday, after weeks of debate, several amendments, and a long public hearing.</p>
+                        <p>Funding for parks, libraries, and road repairs rises slightly, while the transit subsidy stays flat for another year.</p>
+                    </article>
+                </body>
+            </html>
+        "#;
+
+        let options = ReadabilityOptions::builder()
+            .prefer_heading_title(true)
+            .remove_title_heading(true)
+            .build();
+        let article = Readability::new(html, None, Some(options))
+            .unwrap()
+            .parse()
+            .unwrap();
+
+        assert_eq!(article.title.as_deref(), Some("Council approves budget"));
+        assert!(!article.content.unwrap().contains("<h1"));
+        assert!(!article
+            .text_content
+            .unwrap()
+            .contains("Council approves budget"));
+
+        // Without the heading preference the metadata title doesn't match the h1
+        let options = ReadabilityOptions::builder()
+            .remove_title_heading(true)
+            .build();
+        let article = Readability::new(html, None, Some(options))
+            .unwrap()
+            .parse()
+            .unwrap();
+        assert!(article
+            .content
+            .unwrap()
+            .contains("<h1>Council approves budget</h1>"));
+    }
+
+    #[test]
+    fn test_repeated_parses_are_identical() {
+        // Two identical sections give tied candidate scores
+        let section = r#"<div class="section"><p>The committee met on Tuesday to review the proposal, and after a long discussion, the members agreed to postpone the vote until more data was available.</p><p>Residents who attended the meeting said they were frustrated by the delay, but several welcomed the decision to commission an independent study.</p></div>"#;
+        let html = format!(
+            "<html><head><title>Vote postponed</title></head><body>{section}{section}</body></html>"
+        );
+
+        let parse = || {
+            Readability::new(&html, Some("https://example.com/news/vote"), None)
+                .unwrap()
+                .parse()
+                .unwrap()
+        };
+        let first = parse();
+        for _ in 0..5 {
+            let again = parse();
+            assert_eq!(again.content, first.content);
+            assert_eq!(again.text_content, first.text_content);
+        }
+    }
+
+    #[test]
+    fn test_truncate_text_keeps_emoji_whole() {
+        let readability = Readability::new("<html><body></body></html>", None, None).unwrap();
+        let family = "\u{1f468}\u{200d}\u{1f469}\u{200d}\u{1f467}\u{200d}\u{1f466}";
+        let text = format!("Fun{family} and more text");
+
+        // The cut falls inside the seven-character family emoji
+        for max_len in 4..10 {
+            let truncated = readability.truncate_text(&text, max_len);
+            assert_eq!(truncated, "Fun", "max_len {max_len}");
+        }
+        assert_eq!(readability.truncate_text(&text, 12), format!("Fun{family}"));
+    }
+
+    #[test]
+    fn test_wrap_in_article() {
+        let html = r#"<html lang="fr" dir="ltr"><head><title>Le port rouvre</title><meta property="og:locale" content="fr_FR"></head><body>
+            <div class="story-body">
+                <p>Le port a rouvert mardi matin, après trois jours de fermeture causés par la tempête, et les premiers navires sont arrivés dans la soirée.</p>
+                <p>Les autorités ont indiqué que les quais avaient été inspectés, et que les dégâts restaient limités, même si certains entrepôts ont été touchés.</p>
+                <p>Les pêcheurs, privés de sortie pendant plusieurs jours, ont accueilli la nouvelle avec soulagement, tout en réclamant une aide exceptionnelle.</p>
+            </div>
+        </body></html>"#;
+
+        let options = ReadabilityOptions::builder().wrap_in_article(true).build();
+        let article = Readability::new(html, None, Some(options))
+            .unwrap()
+            .parse()
+            .unwrap();
+        let content = article.content.unwrap();
+
+        let fragment = Html::parse_fragment(&content);
+        let top_level: Vec<_> = fragment
+            .root_element()
+            .children()
+            .filter_map(ElementRef::wrap)
+            .collect();
+        assert_eq!(top_level.len(), 1);
+        let wrapper = top_level[0];
+        assert_eq!(wrapper.value().name(), "article");
+        assert_eq!(wrapper.value().attr("lang"), Some("fr"));
+        assert_eq!(wrapper.value().attr("dir"), Some("ltr"));
+        assert!(!content.contains("<div"));
+        assert!(content.contains("Le port a rouvert"));
+    }
+
+    const IFRAME_PAGE: &str = r#"<html><head><title>Harbour reopens</title></head><body><article>
+        <p>The harbour reopened on Tuesday morning, after three days of closure caused by the storm, and ships arrived by evening.</p>
+        <iframe src="https://www.youtube.com/embed/harbour"></iframe>
+        <p>Officials said the quays had been inspected, and that damage was limited, even though some warehouses were hit.</p>
+        <iframe src="https://maps.example.com/embed?q=harbour"></iframe>
+        <p>Fishermen, kept ashore for several days, welcomed the news with relief, while asking for emergency support.</p>
+    </article></body></html>"#;
+
+    fn parse_iframe_page(fallback: IframeFallback) -> String {
+        let options = ReadabilityOptions::builder()
+            .iframe_fallback(fallback)
+            .build();
+        Readability::new(IFRAME_PAGE, None, Some(options))
+            .unwrap()
+            .parse()
+            .unwrap()
+            .content
+            .unwrap()
+    }
+
+    #[test]
+    fn test_iframe_fallback_remove() {
+        let content = parse_iframe_page(IframeFallback::Remove);
+        assert!(!content.contains("maps.example.com"));
+        assert!(content.contains("Fishermen"));
+    }
+
+    #[test]
+    fn test_iframe_fallback_link_placeholder() {
+        let content = parse_iframe_page(IframeFallback::LinkPlaceholder);
+        let fragment = Html::parse_fragment(&content);
+        let link_selector = Selector::parse("a").unwrap();
+        let iframe_selector = Selector::parse("iframe").unwrap();
+
+        let links: Vec<_> = fragment.select(&link_selector).collect();
+        assert_eq!(links.len(), 1);
+        assert_eq!(
+            links[0].value().attr("href"),
+            Some("https://maps.example.com/embed?q=harbour")
+        );
+        let iframes: Vec<_> = fragment.select(&iframe_selector).collect();
+        assert_eq!(iframes.len(), 1);
+        assert!(iframes[0]
+            .value()
+            .attr("src")
+            .unwrap()
+            .contains("youtube.com"));
+    }
+
+    #[test]
+    fn test_iframe_fallback_keep() {
+        let content = parse_iframe_page(IframeFallback::Keep);
+        let fragment = Html::parse_fragment(&content);
+        let iframe_selector = Selector::parse("iframe").unwrap();
+        assert_eq!(fragment.select(&iframe_selector).count(), 2);
+        assert!(!content.contains("<a "));
+    }
+
+    #[test]
+    fn test_stylesheet_hidden_block_is_not_extracted() {
+        let stale = "<p>An earlier draft of the story, kept in the page but hidden by the stylesheet, repeats itself at length, with commas, clauses, and filler.</p>".repeat(8);
+        let html = format!(
+            r#"<html><head><title>Harbour reopens</title>
+            <style>.draft-copy {{ display: none }}</style></head><body>
+            <div class="draft-copy">{stale}</div>
+            <div class="story">
+                <p>The harbour reopened on Tuesday morning, after three days of closure caused by the storm, and ships arrived by evening.</p>
+                <p>Officials said the quays had been inspected, and that damage was limited, even though some warehouses were hit.</p>
+                <p>Fishermen, kept ashore for several days, welcomed the news with relief, while asking for emergency support.</p>
+            </div>
+        </body></html>"#
+        );
+
+        let article = Readability::new(&html, None, None)
+            .unwrap()
+            .parse()
+            .unwrap();
+        let content = article.content.unwrap();
+        assert!(content.contains("The harbour reopened"));
+        assert!(!content.contains("earlier draft"));
+    }
+
+    #[test]
+    fn test_mark_highlights_survive() {
+        let html = r#"<html><head><title>Study notes</title></head><body><article>
+            <p>The treaty was signed in the spring, and <mark>it ended three decades of border disputes</mark> between the two states.</p>
+            <p>Historians still argue about the concessions, the negotiators involved, and how long the peace was expected to last.</p>
+            <p>Later chapters cover the trade agreements, the new customs posts, and the joint commission on shared rivers.</p>
+        </article></body></html>"#;
+
+        let article = Readability::new(html, None, None).unwrap().parse().unwrap();
+        assert!(article
+            .content
+            .as_deref()
+            .unwrap()
+            .contains("<mark>it ended three decades of border disputes</mark>"));
+        assert!(article
+            .to_markdown()
+            .unwrap()
+            .contains("==it ended three decades of border disputes=="));
+    }
+
+    #[test]
+    fn test_max_images_keeps_lead_image() {
+        let figures: String = (1..=10)
+            .map(|i| {
+                format!(
+                    r#"<p>Photo {i} of the gallery shows the harbour after the storm, with boats, quays, and cranes in view.</p>
+                    <figure><img src="https://example.com/photo-{i}.jpg" alt="Photo {i}"></figure>"#
+                )
+            })
+            .collect();
+        let html = format!(
+            r#"<html><head><title>Harbour gallery</title>
+            <meta property="og:image" content="https://example.com/photo-8.jpg"></head>
+            <body><article>{figures}</article></body></html>"#
+        );
+
+        let options = ReadabilityOptions::builder().max_images(3).build();
+        let article = Readability::new(&html, None, Some(options))
+            .unwrap()
+            .parse()
+            .unwrap();
+        let content = article.content.unwrap();
+
+        let fragment = Html::parse_fragment(&content);
+        let img_selector = Selector::parse("img").unwrap();
+        let srcs: Vec<_> = fragment
+            .select(&img_selector)
+            .filter_map(|img| img.value().attr("src"))
+            .collect();
+        assert_eq!(
+            srcs,
+            vec![
+                "https://example.com/photo-1.jpg",
+                "https://example.com/photo-2.jpg",
+                "https://example.com/photo-3.jpg",
+                "https://example.com/photo-8.jpg",
+            ]
+        );
+        let figure_selector = Selector::parse("figure").unwrap();
+        assert_eq!(fragment.select(&figure_selector).count(), 4);
+        assert!(content.contains("Photo 10 of the gallery"));
+    }
+
+    #[test]
+    fn test_region_label_from_aria_labelledby() {
+        let html = r#"<html><head></head><body>
+            <p id="story-label">Harbour reopens after the storm</p>
+            <article aria-labelledby="story-label">
+                <p>The harbour reopened on Tuesday morning, after three days of closure caused by the storm, and ships arrived by evening.</p>
+                <p>Officials said the quays had been inspected, and that damage was limited, even though some warehouses were hit.</p>
+                <p>Fishermen, kept ashore for several days, welcomed the news with relief, while asking for emergency support.</p>
+            </article>
+        </body></html>"#;
+
+        let article = Readability::new(html, None, None).unwrap().parse().unwrap();
+        assert_eq!(
+            article.region_label.as_deref(),
+            Some("Harbour reopens after the storm")
+        );
+        assert_eq!(
+            article.title.as_deref(),
+            Some("Harbour reopens after the storm")
         );
     }
 }
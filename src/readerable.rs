@@ -2,7 +2,8 @@
 //!
 //! This module provides the [`is_probably_readerable`] function, which performs
 //! a fast pre-flight check to determine if a document is likely to have extractable
-//! article content without doing a full parse.
+//! article content without doing a full parse. [`is_probably_readerable_doc`] runs the
+//! same check on a document that has already been parsed.
 //!
 //! ## Use Case
 //!
@@ -31,7 +32,9 @@
 //! This check is significantly faster than a full parse because it only looks
 //! for basic content signals without doing deep analysis or scoring.
 
-use scraper::{Html, Selector};
+use crate::constants::REGEXPS;
+use once_cell::sync::Lazy;
+use scraper::{ElementRef, Html, Selector};
 
 /// Options for the readability pre-flight check.
 ///
@@ -48,6 +51,8 @@ use scraper::{Html, Selector};
 /// let options = ReaderableOptions {
 ///     min_content_length: 200,
 ///     min_score: 30.0,
+///     min_paragraphs: 1,
+///     require_metadata: false,
 /// };
 ///
 /// let is_readerable = is_probably_readerable(html, Some(options));
@@ -69,6 +74,26 @@ pub struct ReaderableOptions {
     ///
     /// Default: `20.0`
     pub min_score: f64,
+
+    /// Minimum number of paragraphs that must reach `min_content_length`.
+    ///
+    /// Checked in addition to `min_score`, to reject pages whose only content is a
+    /// single giant paragraph. An `<article>` counts as a paragraph only when it has
+    /// no qualifying `<p>` or `<pre>` of its own.
+    ///
+    /// Default: `1`
+    pub min_paragraphs: usize,
+
+    /// Also require a byline or a publication date.
+    ///
+    /// For news-focused crawlers: a page whose content passes the score is still
+    /// rejected when it has no author (an author meta tag, `rel="author"`, an `author`
+    /// itemprop or a short byline element) and no date (a published-time meta tag, a
+    /// `datePublished` itemprop or a `<time datetime>`). JSON-LD `author` and
+    /// `datePublished` keys count too.
+    ///
+    /// Default: `false`
+    pub require_metadata: bool,
 }
 
 impl Default for ReaderableOptions {
@@ -76,6 +101,8 @@ impl Default for ReaderableOptions {
         Self {
             min_content_length: 140,
             min_score: 20.0,
+            min_paragraphs: 1,
+            require_metadata: false,
         }
     }
 }
@@ -125,6 +152,8 @@ impl Default for ReaderableOptions {
 /// let options = ReaderableOptions {
 ///     min_content_length: 200,
 ///     min_score: 30.0,
+///     min_paragraphs: 2,
+///     require_metadata: false,
 /// };
 ///
 /// if is_probably_readerable(html, Some(options)) {
@@ -137,7 +166,9 @@ impl Default for ReaderableOptions {
 /// The function finds all `<p>`, `<pre>`, and `<article>` elements in the document,
 /// then filters out paragraphs shorter than the configured `min_content_length`. A score
 /// is calculated based on the remaining content length, and the function returns `true`
-/// if this score exceeds the `min_score` threshold.
+/// if this score exceeds the `min_score` threshold and at least `min_paragraphs`
+/// paragraphs were long enough to count. With `require_metadata`, a byline or date must
+/// be present as well.
 ///
 /// ## Performance
 ///
@@ -145,8 +176,26 @@ impl Default for ReaderableOptions {
 /// large numbers of URLs, pre-filtering in crawlers or scrapers, and quick content
 /// classification tasks.
 pub fn is_probably_readerable(html: &str, options: Option<ReaderableOptions>) -> bool {
-    let options = options.unwrap_or_default();
     let document = Html::parse_document(html);
+    is_probably_readerable_doc(&document, options)
+}
+
+/// Quick readability check on an already parsed document.
+///
+/// Same check as [`is_probably_readerable`], for callers that already hold an
+/// [`Html`] tree and want to avoid parsing the document twice.
+///
+/// ## Example
+///
+/// ```rust
+/// use readabilityrs::is_probably_readerable_doc;
+/// use scraper::Html;
+///
+/// let document = Html::parse_document("<html><body><p>Short</p></body></html>");
+/// assert!(!is_probably_readerable_doc(&document, None));
+/// ```
+pub fn is_probably_readerable_doc(document: &Html, options: Option<ReaderableOptions>) -> bool {
+    let options = options.unwrap_or_default();
 
     // TODO: Implement full isProbablyReaderable logic
     // For now, just do a basic check
@@ -158,7 +207,13 @@ pub fn is_probably_readerable(html: &str, options: Option<ReaderableOptions>) ->
         return false;
     }
 
+    let inner_selector = Selector::parse("p, pre").unwrap();
+    let qualifies = |element: ElementRef| {
+        element.text().collect::<String>().trim().len() >= options.min_content_length
+    };
+
     let mut score = 0.0;
+    let mut paragraph_count = 0;
 
     for p in paragraphs {
         let text = p.text().collect::<String>();
@@ -170,14 +225,63 @@ pub fn is_probably_readerable(html: &str, options: Option<ReaderableOptions>) ->
 
         score += ((text_len - options.min_content_length) as f64).sqrt();
 
-        if score > options.min_score {
-            return true;
+        let is_wrapper = p.value().name() == "article" && p.select(&inner_selector).any(qualifies);
+        if !is_wrapper {
+            paragraph_count += 1;
+        }
+
+        if score > options.min_score && paragraph_count >= options.min_paragraphs {
+            return !options.require_metadata || has_byline_or_date(document);
         }
     }
 
     false
 }
 
+/// Lightweight check for an author or publication date anywhere in the document
+fn has_byline_or_date(document: &Html) -> bool {
+    static METADATA: Lazy<Selector> = Lazy::new(|| {
+        Selector::parse(
+            r#"meta[name="author" i], meta[property="article:author"], meta[property="article:published_time"],
+               meta[name="date" i], meta[name="pubdate" i], meta[name="dc.date" i],
+               meta[itemprop="datePublished"], [rel~="author"], [itemprop~="author"],
+               [itemprop~="datePublished"], time[datetime]"#,
+        )
+        .unwrap()
+    });
+    static MARKED: Lazy<Selector> = Lazy::new(|| Selector::parse("[class], [id]").unwrap());
+    static JSON_LD: Lazy<Selector> =
+        Lazy::new(|| Selector::parse(r#"script[type="application/ld+json"]"#).unwrap());
+    const MAX_BYLINE_LEN: usize = 100;
+
+    if document.select(&METADATA).next().is_some() {
+        return true;
+    }
+
+    let has_byline = document.select(&MARKED).any(|element| {
+        let value = element.value();
+        let marker = format!(
+            "{} {}",
+            value.attr("class").unwrap_or(""),
+            value.attr("id").unwrap_or("")
+        );
+        if !REGEXPS.byline.is_match(&marker) {
+            return false;
+        }
+        let text = element.text().collect::<String>();
+        let length = text.trim().chars().count();
+        length > 0 && length <= MAX_BYLINE_LEN
+    });
+    if has_byline {
+        return true;
+    }
+
+    document.select(&JSON_LD).any(|script| {
+        let json = script.text().collect::<String>();
+        json.contains("\"author\"") || json.contains("\"datePublished\"")
+    })
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -213,4 +317,72 @@ mod tests {
 
         assert!(!is_probably_readerable(html, None));
     }
+
+    #[test]
+    fn test_is_probably_readerable_doc_matches_string_version() {
+        let html = r#"
+            <html>
+                <body>
+                    <article>
+                        <p>This is a long enough paragraph that should make the content readerable.
+                        It has sufficient content to pass the minimum threshold check. Adding more text here to ensure
+                        we definitely exceed the 140 character minimum requirement for each paragraph element.</p>
+                        <p>Another paragraph with more content to increase the score. This paragraph also needs to be
+                        long enough to contribute to the overall readability score calculation and help us pass the test.</p>
+                    </article>
+                </body>
+            </html>
+        "#;
+
+        let document = Html::parse_document(html);
+        assert!(is_probably_readerable_doc(&document, None));
+        assert_eq!(
+            is_probably_readerable_doc(&document, None),
+            is_probably_readerable(html, None)
+        );
+    }
+
+    #[test]
+    fn test_min_paragraphs_rejects_single_paragraph() {
+        let paragraph =
+            "This single paragraph keeps going with filler text about nothing in particular. "
+                .repeat(8);
+        let html = format!("<html><body><article><p>{paragraph}</p></article></body></html>");
+
+        assert!(is_probably_readerable(&html, None));
+
+        let options = ReaderableOptions {
+            min_paragraphs: 2,
+            ..Default::default()
+        };
+        assert!(!is_probably_readerable(&html, Some(options.clone())));
+
+        let html = format!("<html><body><p>{paragraph}</p><p>{paragraph}</p></body></html>");
+        assert!(is_probably_readerable(&html, Some(options)));
+    }
+
+    #[test]
+    fn test_require_metadata_rejects_anonymous_text() {
+        let paragraph =
+            "This paragraph keeps going with filler text about nothing in particular at all. "
+                .repeat(4);
+        let html = format!("<html><body><p>{paragraph}</p><p>{paragraph}</p></body></html>");
+        let options = ReaderableOptions {
+            require_metadata: true,
+            ..Default::default()
+        };
+
+        assert!(is_probably_readerable(&html, None));
+        assert!(!is_probably_readerable(&html, Some(options.clone())));
+
+        let html = format!(
+            r#"<html><body><p class="byline">By Jane Doe</p><p>{paragraph}</p><p>{paragraph}</p></body></html>"#
+        );
+        assert!(is_probably_readerable(&html, Some(options.clone())));
+
+        let html = format!(
+            r#"<html><body><time datetime="2024-03-01">March 1</time><p>{paragraph}</p><p>{paragraph}</p></body></html>"#
+        );
+        assert!(is_probably_readerable(&html, Some(options)));
+    }
 }
@@ -0,0 +1,1108 @@
+//! Structured text rendering of extracted article content.
+
+use crate::article::Footnote;
+use crate::options::Serializer;
+use crate::post_processor::{apply_serializer, escape_xml};
+use crate::utils::normalize_whitespace;
+use scraper::{node::Node, ElementRef, Html, Selector};
+
+/// Separator used for `<hr>` by [`crate::Article::to_text`]
+pub(crate) const DEFAULT_HR_SEPARATOR: &str = "---";
+
+const BLOCK_TAGS: &[&str] = &[
+    "address",
+    "article",
+    "aside",
+    "blockquote",
+    "dd",
+    "div",
+    "dl",
+    "dt",
+    "figcaption",
+    "figure",
+    "footer",
+    "h1",
+    "h2",
+    "h3",
+    "h4",
+    "h5",
+    "h6",
+    "header",
+    "li",
+    "main",
+    "ol",
+    "p",
+    "pre",
+    "section",
+    "table",
+    "tr",
+    "ul",
+];
+
+/// Opening and closing quotation marks for `<q>`, by nesting level
+#[derive(Clone, Copy)]
+struct QuoteMarks {
+    outer: (&'static str, &'static str),
+    inner: (&'static str, &'static str),
+}
+
+impl Default for QuoteMarks {
+    fn default() -> Self {
+        Self {
+            outer: ("\u{201c}", "\u{201d}"),
+            inner: ("\u{2018}", "\u{2019}"),
+        }
+    }
+}
+
+impl QuoteMarks {
+    /// Quotation marks used for a language tag such as `de-AT`, English style otherwise
+    fn for_language(lang: Option<&str>) -> Self {
+        let primary = lang
+            .and_then(|lang| lang.split(['-', '_']).next())
+            .unwrap_or_default()
+            .to_ascii_lowercase();
+        let (outer, inner) = match primary.as_str() {
+            "de" | "cs" | "sk" | "lt" | "bg" | "is" | "et" => {
+                (("\u{201e}", "\u{201c}"), ("\u{201a}", "\u{2018}"))
+            }
+            "fr" => (("\u{ab}", "\u{bb}"), ("\u{2039}", "\u{203a}")),
+            "es" | "it" | "pt" | "ca" | "el" => (("\u{ab}", "\u{bb}"), ("\u{201c}", "\u{201d}")),
+            "ru" | "uk" | "be" => (("\u{ab}", "\u{bb}"), ("\u{201e}", "\u{201c}")),
+            "pl" | "hu" | "ro" => (("\u{201e}", "\u{201d}"), ("\u{ab}", "\u{bb}")),
+            "sv" | "fi" => (("\u{201d}", "\u{201d}"), ("\u{2019}", "\u{2019}")),
+            "da" => (("\u{bb}", "\u{ab}"), ("\u{203a}", "\u{2039}")),
+            "ja" => (("\u{300c}", "\u{300d}"), ("\u{300e}", "\u{300f}")),
+            _ => return Self::default(),
+        };
+        Self { outer, inner }
+    }
+
+    /// Marks for a `<q>` inside `depth` enclosing quotes, alternating outer and inner
+    fn at_depth(&self, depth: usize) -> (&'static str, &'static str) {
+        match depth & 1 {
+            0 => self.outer,
+            _ => self.inner,
+        }
+    }
+}
+
+/// Accumulates blocks of text while walking the content tree
+#[derive(Default)]
+struct TextWriter {
+    blocks: Vec<Block>,
+    current: String,
+    quotes: QuoteMarks,
+    quote_depth: usize,
+}
+
+enum Block {
+    Text(String),
+    Break,
+}
+
+impl TextWriter {
+    /// End the current block; `preformatted` text keeps its spaces and only loses
+    /// leading and trailing line breaks
+    fn flush(&mut self, preformatted: bool) {
+        let text = if preformatted {
+            self.current.trim_matches(|c| c == '\n' || c == '\r')
+        } else {
+            self.current.trim()
+        };
+        if !text.trim().is_empty() {
+            self.blocks.push(Block::Text(text.to_string()));
+        }
+        self.current.clear();
+    }
+
+    fn finish(mut self, hr_separator: &str) -> String {
+        self.flush(false);
+
+        let mut output = String::new();
+        for block in self.blocks {
+            match block {
+                Block::Text(text) => {
+                    if !output.is_empty() {
+                        output.push_str("\n\n");
+                    }
+                    output.push_str(&text);
+                }
+                Block::Break if output.is_empty() => {}
+                Block::Break if hr_separator.is_empty() => output.push('\n'),
+                Block::Break => {
+                    output.push_str("\n\n");
+                    output.push_str(hr_separator);
+                }
+            }
+        }
+        output
+    }
+}
+
+/// Render article HTML as plain text with one blank line between blocks
+///
+/// Whitespace inside blocks is collapsed, except in `<pre>`. Each `<hr>` becomes
+/// `hr_separator` on a line of its own; an empty separator leaves a blank-line pair.
+/// `<q>` is wrapped in the quotation marks of `lang`, alternating for nested quotes.
+pub(crate) fn html_to_text(html: &str, hr_separator: &str, lang: Option<&str>) -> String {
+    let fragment = Html::parse_fragment(html);
+    let mut writer = TextWriter {
+        quotes: QuoteMarks::for_language(lang),
+        ..Default::default()
+    };
+    walk(fragment.root_element(), false, &mut writer);
+    writer.finish(hr_separator)
+}
+
+fn walk(element: ElementRef, in_pre: bool, writer: &mut TextWriter) {
+    for child in element.children() {
+        match child.value() {
+            Node::Text(text) => {
+                if in_pre {
+                    writer.current.push_str(&text.text);
+                } else {
+                    let collapsed = normalize_whitespace(&text.text);
+                    if writer.current.is_empty() || writer.current.ends_with('\n') {
+                        writer.current.push_str(collapsed.trim_start());
+                    } else {
+                        writer.current.push_str(&collapsed);
+                    }
+                }
+            }
+            Node::Element(_) => {
+                let Some(child_element) = ElementRef::wrap(child) else {
+                    continue;
+                };
+                let tag = child_element.value().name();
+                match tag {
+                    "hr" => {
+                        writer.flush(in_pre);
+                        writer.blocks.push(Block::Break);
+                    }
+                    "br" => {
+                        let trimmed = writer.current.trim_end().len();
+                        writer.current.truncate(trimmed);
+                        writer.current.push('\n');
+                    }
+                    "script" | "style" | "template" | "rp" => {}
+                    "rt" => {
+                        writer.current.push('(');
+                        walk(child_element, in_pre, writer);
+                        writer.current.push(')');
+                    }
+                    "q" => {
+                        let (open, close) = writer.quotes.at_depth(writer.quote_depth);
+                        writer.current.push_str(open);
+                        writer.quote_depth += 1;
+                        walk(child_element, in_pre, writer);
+                        writer.quote_depth -= 1;
+                        let trimmed = writer.current.trim_end().len();
+                        writer.current.truncate(trimmed);
+                        writer.current.push_str(close);
+                    }
+                    _ if BLOCK_TAGS.contains(&tag) => {
+                        let pre = in_pre || tag == "pre";
+                        writer.flush(in_pre);
+                        walk(child_element, pre, writer);
+                        writer.flush(pre);
+                    }
+                    _ => walk(child_element, in_pre, writer),
+                }
+            }
+            _ => {}
+        }
+    }
+}
+
+/// Syntax of a lightweight markup language, used by [`html_to_markup`]
+///
+/// The traversal is shared; each format only decides how the pieces are written.
+pub(crate) trait MarkupSyntax {
+    /// Heading line for `<h1>` (level 1) through `<h6>`
+    fn heading(&self, level: usize, text: &str) -> String;
+    /// Marker opening a list item; `depth` is 0 for top-level lists
+    fn list_marker(&self, ordered: bool, index: usize, depth: usize) -> String;
+    /// Whether nested lists and continuation lines are indented to the item's text
+    fn indents_list_items(&self) -> bool;
+    /// Escape text the syntax would read as markup; `line_start` is set when the text
+    /// begins a line
+    fn escape(&self, text: &str, line_start: bool) -> String;
+    fn link(&self, text: &str, href: &str) -> String;
+    fn image(&self, src: &str, alt: &str) -> String;
+    fn emphasis(&self, text: &str) -> String;
+    fn strong(&self, text: &str) -> String;
+    /// Highlighted text from `<mark>`
+    fn highlight(&self, text: &str) -> String;
+    fn code(&self, text: &str) -> String;
+    fn code_block(&self, code: &str, language: Option<&str>) -> String;
+    fn quote(&self, inner: &str) -> String;
+    fn rule(&self) -> &'static str;
+    fn line_break(&self) -> &'static str;
+    /// Reference to footnote `number` where it is cited
+    fn footnote_ref(&self, number: usize) -> String;
+    /// Definition of footnote `number`, listed after the content
+    fn footnote_definition(&self, number: usize, text: &str) -> String;
+}
+
+/// CommonMark-style Markdown
+pub(crate) struct Markdown;
+
+impl MarkupSyntax for Markdown {
+    fn heading(&self, level: usize, text: &str) -> String {
+        format!("{} {text}", "#".repeat(level))
+    }
+
+    fn list_marker(&self, ordered: bool, index: usize, _depth: usize) -> String {
+        if ordered {
+            format!("{index}. ")
+        } else {
+            "- ".to_string()
+        }
+    }
+
+    fn indents_list_items(&self) -> bool {
+        true
+    }
+
+    fn escape(&self, text: &str, line_start: bool) -> String {
+        let mut escaped = String::with_capacity(text.len());
+        for c in text.chars() {
+            if matches!(c, '\\' | '`' | '*' | '_' | '[' | ']' | '<' | '>' | '&') {
+                escaped.push('\\');
+            }
+            escaped.push(c);
+        }
+        if line_start {
+            escape_markdown_block_start(&mut escaped);
+        }
+        escaped
+    }
+
+    fn link(&self, text: &str, href: &str) -> String {
+        format!("[{text}]({})", markdown_destination(href))
+    }
+
+    fn image(&self, src: &str, alt: &str) -> String {
+        format!(
+            "![{}]({})",
+            self.escape(alt, false),
+            markdown_destination(src)
+        )
+    }
+
+    fn emphasis(&self, text: &str) -> String {
+        format!("*{text}*")
+    }
+
+    fn strong(&self, text: &str) -> String {
+        format!("**{text}**")
+    }
+
+    fn highlight(&self, text: &str) -> String {
+        format!("=={text}==")
+    }
+
+    fn code(&self, text: &str) -> String {
+        let fence = "`".repeat(longest_backtick_run(text) + 1);
+        if text.starts_with('`') || text.ends_with('`') {
+            format!("{fence} {text} {fence}")
+        } else {
+            format!("{fence}{text}{fence}")
+        }
+    }
+
+    fn code_block(&self, code: &str, language: Option<&str>) -> String {
+        let fence = "`".repeat(longest_backtick_run(code).max(2) + 1);
+        format!("{fence}{}\n{code}\n{fence}", language.unwrap_or_default())
+    }
+
+    fn quote(&self, inner: &str) -> String {
+        inner
+            .lines()
+            .map(|line| {
+                if line.is_empty() {
+                    ">".to_string()
+                } else {
+                    format!("> {line}")
+                }
+            })
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+
+    fn rule(&self) -> &'static str {
+        "---"
+    }
+
+    fn line_break(&self) -> &'static str {
+        "  \n"
+    }
+
+    fn footnote_ref(&self, number: usize) -> String {
+        format!("[^{number}]")
+    }
+
+    fn footnote_definition(&self, number: usize, text: &str) -> String {
+        format!("[^{number}]: {text}")
+    }
+}
+
+/// Escape a leading `#`, `-`, `+` or `1.` that Markdown would read as a block
+///
+/// A leading `>` is already escaped with the rest of the text.
+fn escape_markdown_block_start(text: &mut String) {
+    let digits = text.bytes().take_while(u8::is_ascii_digit).count();
+    let at = if digits > 0 {
+        matches!(text.as_bytes().get(digits), Some(b'.' | b')')).then_some(digits)
+    } else {
+        match text.as_bytes().first() {
+            Some(b'#') => Some(0),
+            Some(b'-' | b'+') if text[1..].is_empty() || text[1..].starts_with(' ') => Some(0),
+            _ => None,
+        }
+    };
+    if let Some(at) = at {
+        text.insert(at, '\\');
+    }
+}
+
+/// Percent-encode the characters that would end a Markdown link destination early
+fn markdown_destination(url: &str) -> String {
+    let mut encoded = String::with_capacity(url.len());
+    for c in url.chars() {
+        match c {
+            ' ' => encoded.push_str("%20"),
+            '(' => encoded.push_str("%28"),
+            ')' => encoded.push_str("%29"),
+            '<' => encoded.push_str("%3C"),
+            '>' => encoded.push_str("%3E"),
+            _ => encoded.push(c),
+        }
+    }
+    encoded
+}
+
+/// Length of the longest run of backticks in `text`
+fn longest_backtick_run(text: &str) -> usize {
+    text.split(|c| c != '`').map(str::len).max().unwrap_or(0)
+}
+
+/// AsciiDoc, with `<h1>` as a level-1 section (`==`)
+pub(crate) struct AsciiDoc;
+
+impl MarkupSyntax for AsciiDoc {
+    fn heading(&self, level: usize, text: &str) -> String {
+        format!("{} {text}", "=".repeat(level + 1))
+    }
+
+    fn list_marker(&self, ordered: bool, _index: usize, depth: usize) -> String {
+        let marker = if ordered { "." } else { "*" };
+        format!("{} ", marker.repeat(depth + 1))
+    }
+
+    fn indents_list_items(&self) -> bool {
+        false
+    }
+
+    fn escape(&self, text: &str, _line_start: bool) -> String {
+        text.to_string()
+    }
+
+    fn link(&self, text: &str, href: &str) -> String {
+        if href.starts_with("http://") || href.starts_with("https://") {
+            format!("{href}[{text}]")
+        } else {
+            format!("link:{href}[{text}]")
+        }
+    }
+
+    fn image(&self, src: &str, alt: &str) -> String {
+        format!("image:{src}[{alt}]")
+    }
+
+    fn emphasis(&self, text: &str) -> String {
+        format!("_{text}_")
+    }
+
+    fn strong(&self, text: &str) -> String {
+        format!("*{text}*")
+    }
+
+    fn highlight(&self, text: &str) -> String {
+        format!("#{text}#")
+    }
+
+    fn code(&self, text: &str) -> String {
+        format!("`{text}`")
+    }
+
+    fn code_block(&self, code: &str, language: Option<&str>) -> String {
+        match language {
+            Some(language) => format!("[source,{language}]\n----\n{code}\n----"),
+            None => format!("----\n{code}\n----"),
+        }
+    }
+
+    fn quote(&self, inner: &str) -> String {
+        format!("____\n{inner}\n____")
+    }
+
+    fn rule(&self) -> &'static str {
+        "'''"
+    }
+
+    fn line_break(&self) -> &'static str {
+        " +\n"
+    }
+
+    fn footnote_ref(&self, number: usize) -> String {
+        format!("^[{number}]^")
+    }
+
+    fn footnote_definition(&self, number: usize, text: &str) -> String {
+        format!("[{number}] {text}")
+    }
+}
+
+/// Accumulates markup blocks while walking the content tree
+struct MarkupWriter<'s> {
+    syntax: &'s dyn MarkupSyntax,
+    footnotes: &'s [Footnote],
+    quotes: QuoteMarks,
+    quote_depth: usize,
+    list_depth: usize,
+    blocks: Vec<String>,
+    current: String,
+}
+
+impl<'s> MarkupWriter<'s> {
+    fn new(syntax: &'s dyn MarkupSyntax, footnotes: &'s [Footnote], list_depth: usize) -> Self {
+        Self {
+            syntax,
+            footnotes,
+            quotes: QuoteMarks::default(),
+            quote_depth: 0,
+            list_depth,
+            blocks: Vec::new(),
+            current: String::new(),
+        }
+    }
+
+    /// Starts an empty writer with the same syntax, footnotes, quotes and list depth
+    fn nested(&self) -> MarkupWriter<'s> {
+        self.at_list_depth(self.list_depth)
+    }
+
+    /// Starts an empty writer like [`nested`](Self::nested), at another list depth
+    fn at_list_depth(&self, list_depth: usize) -> MarkupWriter<'s> {
+        MarkupWriter {
+            quotes: self.quotes,
+            quote_depth: self.quote_depth,
+            ..MarkupWriter::new(self.syntax, self.footnotes, list_depth)
+        }
+    }
+
+    /// Footnote a link points to, if any
+    fn footnote_for(&self, href: &str) -> Option<&'s Footnote> {
+        let (_, fragment) = href.split_once('#')?;
+        self.footnotes.iter().find(|note| note.id == fragment)
+    }
+
+    fn flush(&mut self) {
+        let text = self.current.trim();
+        if !text.is_empty() {
+            self.blocks.push(text.to_string());
+        }
+        self.current.clear();
+    }
+
+    fn push_block(&mut self, block: String) {
+        self.flush();
+        if !block.trim().is_empty() {
+            self.blocks.push(block);
+        }
+    }
+
+    fn push_text(&mut self, text: &str) {
+        let collapsed = normalize_whitespace(text);
+        let line_start = self.current.is_empty() || self.current.ends_with('\n');
+        let text = if line_start {
+            collapsed.trim_start()
+        } else {
+            collapsed.as_str()
+        };
+        let escaped = self.syntax.escape(text, line_start);
+        self.current.push_str(&escaped);
+    }
+
+    fn finish(mut self) -> Vec<String> {
+        self.flush();
+        self.blocks
+    }
+}
+
+/// Render article HTML as lightweight markup in the given syntax
+///
+/// Headings, paragraphs, lists, links, images, emphasis, highlights, code and quotes
+/// are translated; other elements contribute their text. Blocks are separated by a
+/// blank line. Links to one of `footnotes` become footnote references, the notes
+/// themselves are left out of the body and their definitions close the output. `<q>`
+/// is wrapped in the quotation marks of `lang`.
+pub(crate) fn html_to_markup(
+    html: &str,
+    syntax: &dyn MarkupSyntax,
+    footnotes: &[Footnote],
+    lang: Option<&str>,
+) -> String {
+    let fragment = Html::parse_fragment(html);
+    let mut writer = MarkupWriter {
+        quotes: QuoteMarks::for_language(lang),
+        ..MarkupWriter::new(syntax, footnotes, 0)
+    };
+    walk_markup(fragment.root_element(), &mut writer);
+    let definitions: Vec<String> = footnotes
+        .iter()
+        .map(|note| syntax.footnote_definition(note.number, &note.text))
+        .collect();
+    if !definitions.is_empty() {
+        writer.push_block(definitions.join("\n"));
+    }
+    writer.finish().join("\n\n")
+}
+
+fn walk_markup(element: ElementRef, writer: &mut MarkupWriter) {
+    for child in element.children() {
+        match child.value() {
+            Node::Text(text) => writer.push_text(&text.text),
+            Node::Element(_) => {
+                if let Some(child_element) = ElementRef::wrap(child) {
+                    render_markup_element(child_element, writer);
+                }
+            }
+            _ => {}
+        }
+    }
+}
+
+fn render_markup_element(element: ElementRef, writer: &mut MarkupWriter) {
+    let syntax = writer.syntax;
+    let tag = element.value().name();
+    if !writer.footnotes.is_empty() && is_footnote_container(element, writer.footnotes) {
+        return;
+    }
+    match tag {
+        "script" | "style" | "template" | "rp" => {}
+        "rt" => {
+            let text = inline_markup(element, writer);
+            writer.current.push_str(&format!("({text})"));
+        }
+        "h1" | "h2" | "h3" | "h4" | "h5" | "h6" => {
+            let level = usize::from(tag.as_bytes()[1] - b'0');
+            let text = inline_markup(element, writer);
+            if !text.is_empty() {
+                writer.push_block(syntax.heading(level, &text));
+            }
+        }
+        "ul" | "ol" => {
+            let list = render_list(element, tag == "ol", writer);
+            writer.push_block(list);
+        }
+        "pre" => {
+            let code = element.text().collect::<String>();
+            let language = code_language(element);
+            writer.push_block(syntax.code_block(code.trim_end_matches('\n'), language));
+        }
+        "blockquote" => {
+            let mut inner = writer.nested();
+            walk_markup(element, &mut inner);
+            let inner = inner.finish().join("\n\n");
+            writer.push_block(syntax.quote(&inner));
+        }
+        "hr" => writer.push_block(syntax.rule().to_string()),
+        "br" => {
+            let trimmed = writer.current.trim_end().len();
+            writer.current.truncate(trimmed);
+            writer.current.push_str(syntax.line_break());
+        }
+        "img" => {
+            if let Some(src) = element.value().attr("src") {
+                let alt = element.value().attr("alt").unwrap_or_default();
+                writer.current.push_str(&syntax.image(src, alt));
+            }
+        }
+        "a" => {
+            if let Some(note) = element
+                .value()
+                .attr("href")
+                .and_then(|href| writer.footnote_for(href))
+            {
+                let trimmed = writer.current.trim_end().len();
+                writer.current.truncate(trimmed);
+                writer.current.push_str(&syntax.footnote_ref(note.number));
+                return;
+            }
+            let text = inline_markup(element, writer);
+            match element.value().attr("href") {
+                Some(href) if !text.is_empty() && !href.starts_with('#') => {
+                    writer.current.push_str(&syntax.link(&text, href));
+                }
+                _ => writer.current.push_str(&text),
+            }
+        }
+        "em" | "i" => {
+            let text = inline_markup(element, writer);
+            if !text.is_empty() {
+                writer.current.push_str(&syntax.emphasis(&text));
+            }
+        }
+        "strong" | "b" => {
+            let text = inline_markup(element, writer);
+            if !text.is_empty() {
+                writer.current.push_str(&syntax.strong(&text));
+            }
+        }
+        "q" => {
+            let (open, close) = writer.quotes.at_depth(writer.quote_depth);
+            writer.quote_depth += 1;
+            let text = inline_markup(element, writer);
+            writer.quote_depth -= 1;
+            writer.current.push_str(&format!("{open}{text}{close}"));
+        }
+        "mark" => {
+            let text = inline_markup(element, writer);
+            if !text.is_empty() {
+                writer.current.push_str(&syntax.highlight(&text));
+            }
+        }
+        "code" => {
+            let text = normalize_whitespace(&element.text().collect::<String>());
+            if !text.trim().is_empty() {
+                writer.current.push_str(&syntax.code(text.trim()));
+            }
+        }
+        _ if BLOCK_TAGS.contains(&tag) => {
+            writer.flush();
+            walk_markup(element, writer);
+            writer.flush();
+        }
+        _ => walk_markup(element, writer),
+    }
+}
+
+/// Render an element's children as a single line of inline markup
+fn inline_markup(element: ElementRef, writer: &MarkupWriter) -> String {
+    let mut inner = writer.nested();
+    walk_markup(element, &mut inner);
+    inner.finish().join(" ")
+}
+
+/// Render a list, one line per item; nested lists follow their item at the next depth
+///
+/// When the syntax indents list items, nested lists and continuation lines are
+/// indented to the width of their item's marker.
+fn render_list(list: ElementRef, ordered: bool, writer: &MarkupWriter) -> String {
+    let syntax = writer.syntax;
+    let depth = writer.list_depth;
+    let items = list
+        .children()
+        .filter_map(ElementRef::wrap)
+        .filter(|child| child.value().name() == "li")
+        .filter(|item| !is_footnote_container(*item, writer.footnotes));
+
+    let mut lines = Vec::new();
+    for (index, item) in items.enumerate() {
+        let mut inner = writer.nested();
+        let mut nested_lists = Vec::new();
+        for child in item.children() {
+            match child.value() {
+                Node::Text(text) => inner.push_text(&text.text),
+                Node::Element(element) if matches!(element.name(), "ul" | "ol") => {
+                    if let Some(nested) = ElementRef::wrap(child) {
+                        let nested_writer = writer.at_list_depth(depth + 1);
+                        nested_lists.push(render_list(
+                            nested,
+                            element.name() == "ol",
+                            &nested_writer,
+                        ));
+                    }
+                }
+                Node::Element(_) => {
+                    if let Some(child_element) = ElementRef::wrap(child) {
+                        render_markup_element(child_element, &mut inner);
+                    }
+                }
+                _ => {}
+            }
+        }
+
+        let marker = syntax.list_marker(ordered, index + 1, depth);
+        let indent = if syntax.indents_list_items() {
+            " ".repeat(marker.chars().count())
+        } else {
+            String::new()
+        };
+        let text = inner.finish().join(" ");
+        lines.push(format!(
+            "{marker}{}",
+            text.replace('\n', &format!("\n{indent}"))
+        ));
+        for nested in nested_lists {
+            lines.extend(nested.lines().map(|line| format!("{indent}{line}")));
+        }
+    }
+    lines.join("\n")
+}
+
+/// Whether an element holds footnote definitions and is left out of the body
+///
+/// True for a footnote's own element, a list item containing it, a list made only of
+/// footnotes, and a `role="doc-endnotes"` or `class="footnotes"` section.
+fn is_footnote_container(element: ElementRef, footnotes: &[Footnote]) -> bool {
+    if footnotes.is_empty() {
+        return false;
+    }
+    let value = element.value();
+    let is_note_id = |id: &str| footnotes.iter().any(|note| note.id == id);
+    if value.id().is_some_and(is_note_id) || value.attr("role") == Some("doc-endnotes") {
+        return true;
+    }
+    if value.classes().any(|class| class == "footnotes") {
+        return true;
+    }
+    match value.name() {
+        "li" => element
+            .descendants()
+            .filter_map(ElementRef::wrap)
+            .any(|child| child.value().id().is_some_and(is_note_id)),
+        "ol" | "ul" => {
+            let mut items = element
+                .children()
+                .filter_map(ElementRef::wrap)
+                .filter(|child| child.value().name() == "li")
+                .peekable();
+            items.peek().is_some() && items.all(|item| is_footnote_container(item, footnotes))
+        }
+        _ => false,
+    }
+}
+
+/// Collect footnotes from article HTML, numbered in order of first reference
+///
+/// A reference is a link inside `<sup>`, or with `role="doc-noteref"`, whose fragment
+/// names a list item or a `role="doc-footnote"`/`doc-endnote` element (or an anchor
+/// inside one) elsewhere in the content. Back-reference links are left out of the text.
+pub(crate) fn collect_footnotes(html: &str) -> Vec<Footnote> {
+    let fragment = Html::parse_fragment(html);
+    let Ok(selector) = Selector::parse("a[href*='#']") else {
+        return Vec::new();
+    };
+
+    let mut footnotes: Vec<Footnote> = Vec::new();
+    for anchor in fragment.select(&selector) {
+        let is_reference = anchor.value().attr("role") == Some("doc-noteref")
+            || anchor
+                .ancestors()
+                .filter_map(ElementRef::wrap)
+                .any(|ancestor| ancestor.value().name() == "sup");
+        if !is_reference {
+            continue;
+        }
+        let Some((_, id)) = anchor
+            .value()
+            .attr("href")
+            .and_then(|href| href.split_once('#'))
+        else {
+            continue;
+        };
+        if id.is_empty() || footnotes.iter().any(|note| note.id == id) {
+            continue;
+        }
+        let Some(definition) = footnote_definition(&fragment, id) else {
+            continue;
+        };
+        if definition
+            .descendants()
+            .any(|node| node.id() == anchor.id())
+        {
+            continue;
+        }
+
+        let mut text = String::new();
+        collect_note_text(definition, &mut text);
+        let text = normalize_whitespace(&text).trim().to_string();
+        if text.is_empty() {
+            continue;
+        }
+        footnotes.push(Footnote {
+            id: id.to_string(),
+            number: footnotes.len() + 1,
+            text,
+        });
+    }
+    footnotes
+}
+
+/// The list item or note element holding the footnote with the given id
+fn footnote_definition<'a>(fragment: &'a Html, id: &str) -> Option<ElementRef<'a>> {
+    let target = fragment
+        .root_element()
+        .descendants()
+        .filter_map(ElementRef::wrap)
+        .find(|element| element.value().id() == Some(id))?;
+    std::iter::once(target)
+        .chain(target.ancestors().filter_map(ElementRef::wrap))
+        .find(|element| {
+            element.value().name() == "li"
+                || matches!(
+                    element.value().attr("role"),
+                    Some("doc-footnote" | "doc-endnote")
+                )
+        })
+}
+
+/// Text of a footnote, skipping back-reference links such as `↩`
+fn collect_note_text(element: ElementRef, text: &mut String) {
+    for child in element.children() {
+        match child.value() {
+            Node::Text(t) => text.push_str(&t.text),
+            Node::Element(_) => {
+                let Some(child_element) = ElementRef::wrap(child) else {
+                    continue;
+                };
+                if child_element.value().name() == "a" && is_back_reference(child_element) {
+                    continue;
+                }
+                if BLOCK_TAGS.contains(&child_element.value().name()) {
+                    text.push(' ');
+                }
+                collect_note_text(child_element, text);
+            }
+            _ => {}
+        }
+    }
+}
+
+fn is_back_reference(anchor: ElementRef) -> bool {
+    if anchor.value().attr("role") == Some("doc-backlink") {
+        return true;
+    }
+    let label = anchor.text().collect::<String>();
+    matches!(label.trim(), "↩" | "↩\u{fe0e}" | "↩\u{fe0f}" | "↑" | "^")
+}
+
+/// Language named by a `language-*` or `lang-*` class on `<pre>` or its `<code>` child
+fn code_language(pre: ElementRef<'_>) -> Option<&str> {
+    let code = pre
+        .children()
+        .filter_map(ElementRef::wrap)
+        .find(|child| child.value().name() == "code");
+    [Some(pre), code]
+        .into_iter()
+        .flatten()
+        .filter_map(|element| element.value().attr("class"))
+        .flat_map(str::split_whitespace)
+        .find_map(|class| {
+            class
+                .strip_prefix("language-")
+                .or_else(|| class.strip_prefix("lang-"))
+        })
+        .filter(|language| !language.is_empty())
+}
+
+/// Render article HTML as a standalone EPUB 3 XHTML chapter
+///
+/// The content is cleaned by
+/// [`cleaner::prepare_epub_content`](crate::cleaner::prepare_epub_content) and written
+/// with the [`Serializer::Xhtml`] serializer under a single `<body>`, with `title` as
+/// its `<h1>`. Returns the chapter along with the image sources and the paths the
+/// chapter uses for them.
+pub(crate) fn html_to_epub_chapter(
+    html: &str,
+    title: Option<&str>,
+    lang: Option<&str>,
+    dir: Option<&str>,
+) -> (String, Vec<(String, String)>) {
+    let (content, images) = crate::cleaner::prepare_epub_content(html);
+    let body = apply_serializer(&content, Serializer::Xhtml, &[]);
+
+    let title = title.map(str::trim).filter(|title| !title.is_empty());
+    let mut html_attrs = String::from(
+        r#" xmlns="http://www.w3.org/1999/xhtml" xmlns:epub="http://www.idpf.org/2007/ops""#,
+    );
+    if let Some(lang) = lang.map(str::trim).filter(|lang| !lang.is_empty()) {
+        let lang = escape_xml(lang, true);
+        html_attrs.push_str(&format!(r#" lang="{lang}" xml:lang="{lang}""#));
+    }
+    if let Some(dir) = dir.filter(|dir| matches!(*dir, "ltr" | "rtl" | "auto")) {
+        html_attrs.push_str(&format!(r#" dir="{dir}""#));
+    }
+
+    let mut chapter = String::from("<?xml version=\"1.0\" encoding=\"utf-8\"?>\n<!DOCTYPE html>\n");
+    chapter.push_str(&format!("<html{html_attrs}>\n<head>\n"));
+    chapter.push_str(&format!(
+        "<title>{}</title>\n</head>\n<body>\n<section epub:type=\"chapter\">\n",
+        escape_xml(title.unwrap_or_default(), false)
+    ));
+    if let Some(title) = title {
+        chapter.push_str(&format!("<h1>{}</h1>\n", escape_xml(title, false)));
+    }
+    chapter.push_str(body.trim());
+    chapter.push_str("\n</section>\n</body>\n</html>\n");
+    (chapter, images)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_hr_becomes_separator() {
+        let html = r#"<article>
+            <p>The first scene opens in the kitchen.</p>
+            <p>She stares out of   the window.</p>
+            <hr>
+            <p>The second scene takes place <em>years</em> later.</p>
+        </article>"#;
+
+        assert_eq!(
+            html_to_text(html, DEFAULT_HR_SEPARATOR, None),
+            "The first scene opens in the kitchen.\n\nShe stares out of the window.\n\n---\n\nThe second scene takes place years later."
+        );
+        assert_eq!(
+            html_to_text(html, "", None),
+            "The first scene opens in the kitchen.\n\nShe stares out of the window.\n\n\nThe second scene takes place years later."
+        );
+    }
+
+    #[test]
+    fn test_pre_keeps_whitespace() {
+        let html = "<p>Run this:</p><pre>fn main() {\n    run();\n}</pre>";
+        assert_eq!(
+            html_to_text(html, DEFAULT_HR_SEPARATOR, None),
+            "Run this:\n\nfn main() {\n    run();\n}"
+        );
+
+        // Indentation at the start and end of the block is part of the code
+        let html = "<pre>    indented();\n        more();  \n</pre><p>After.</p>";
+        assert_eq!(
+            html_to_text(html, DEFAULT_HR_SEPARATOR, None),
+            "    indented();\n        more();  \n\nAfter."
+        );
+    }
+
+    const MARKUP_SAMPLE: &str = r#"<div>
+        <h2>Getting started</h2>
+        <p>Read <a href="https://example.com/guide">the guide</a> and the <em>short</em> notes.</p>
+        <ul>
+            <li>Install the <code>cli</code> tool
+                <ol><li>Download it</li><li>Unpack it</li></ol>
+            </li>
+            <li>Run <strong>setup</strong></li>
+        </ul>
+        <pre><code class="language-sh">make
+make install</code></pre>
+    </div>"#;
+
+    #[test]
+    fn test_markdown_rendering() {
+        assert_eq!(
+            html_to_markup(MARKUP_SAMPLE, &Markdown, &[], None),
+            "## Getting started\n\n\
+             Read [the guide](https://example.com/guide) and the *short* notes.\n\n\
+             - Install the `cli` tool\n  1. Download it\n  2. Unpack it\n- Run **setup**\n\n\
+             ```sh\nmake\nmake install\n```"
+        );
+    }
+
+    #[test]
+    fn test_markdown_escapes_text_and_sizes_fences() {
+        let html = r#"<p>1. Not a list, *really*.</p>
+            <p># Not a heading with a_b and [c]</p>
+            <p>Run <code>a `b` c</code> now.</p>
+            <pre>```
+fence
+```</pre>
+            <ol><li>First<ul><li>Inner</li></ul></li></ol>"#;
+        assert_eq!(
+            html_to_markup(html, &Markdown, &[], None),
+            "1\\. Not a list, \\*really\\*.\n\n\
+             \\# Not a heading with a\\_b and \\[c\\]\n\n\
+             Run ``a `b` c`` now.\n\n\
+             ````\n```\nfence\n```\n````\n\n\
+             1. First\n   - Inner"
+        );
+    }
+
+    #[test]
+    fn test_markdown_escapes_raw_html_and_link_targets() {
+        let html = r#"<p>Use &lt;script&gt; &amp; friends.</p>
+            <p>&gt; Not a quote.</p>
+            <p><a href="https://example.com/wiki/Rust_(language)">Rust</a></p>
+            <p><img src="/img/a (1).png" alt="Chart [draft]"></p>"#;
+        assert_eq!(
+            html_to_markup(html, &Markdown, &[], None),
+            "Use \\<script\\> \\& friends.\n\n\
+             \\> Not a quote.\n\n\
+             [Rust](https://example.com/wiki/Rust_%28language%29)\n\n\
+             ![Chart \\[draft\\]](/img/a%20%281%29.png)"
+        );
+    }
+
+    #[test]
+    fn test_asciidoc_rendering() {
+        assert_eq!(
+            html_to_markup(MARKUP_SAMPLE, &AsciiDoc, &[], None),
+            "=== Getting started\n\n\
+             Read https://example.com/guide[the guide] and the _short_ notes.\n\n\
+             * Install the `cli` tool\n.. Download it\n.. Unpack it\n* Run *setup*\n\n\
+             [source,sh]\n----\nmake\nmake install\n----"
+        );
+    }
+
+    #[test]
+    fn test_collect_footnotes() {
+        let html = r##"<p>First claim.<sup><a href="#note-a">a</a></sup> Second claim.<a role="doc-noteref" href="#note-b">[b]</a> Again.<sup><a href="#note-a">a</a></sup> <a href="#top">Top</a></p>
+            <aside id="note-b" role="doc-footnote">A note in an aside.</aside>
+            <ol><li><a id="note-a"></a>A note with an <em>anchor</em>. <a href="#ref-a" role="doc-backlink">back</a></li></ol>"##;
+
+        let footnotes = collect_footnotes(html);
+        assert_eq!(
+            footnotes,
+            vec![
+                Footnote {
+                    id: "note-a".to_string(),
+                    number: 1,
+                    text: "A note with an anchor.".to_string(),
+                },
+                Footnote {
+                    id: "note-b".to_string(),
+                    number: 2,
+                    text: "A note in an aside.".to_string(),
+                },
+            ]
+        );
+        assert_eq!(
+            html_to_markup(html, &Markdown, &footnotes, None),
+            "First claim.[^1] Second claim.[^2] Again.[^1] Top\n\n\
+             [^1]: A note with an anchor.\n[^2]: A note in an aside."
+        );
+    }
+
+    #[test]
+    fn test_nested_q_quotation_marks() {
+        let html = "<p>She said <q>he told me <q>never again</q> twice</q> and left.</p>";
+        assert_eq!(
+            html_to_text(html, DEFAULT_HR_SEPARATOR, None),
+            "She said \u{201c}he told me \u{2018}never again\u{2019} twice\u{201d} and left."
+        );
+        assert_eq!(
+            html_to_text(html, DEFAULT_HR_SEPARATOR, Some("de-AT")),
+            "She said \u{201e}he told me \u{201a}never again\u{2018} twice\u{201c} and left."
+        );
+        assert_eq!(
+            html_to_markup(html, &Markdown, &[], Some("fr")),
+            "She said \u{ab}he told me \u{2039}never again\u{203a} twice\u{bb} and left."
+        );
+    }
+}
@@ -1,8 +1,43 @@
 //! Content scoring algorithms for determining article quality.
 
-use crate::constants::{ParseFlags, REGEXPS};
+use crate::constants::{ParseFlags, DEFAULT_TAGS_TO_SCORE, REGEXPS};
 use crate::dom_utils;
-use scraper::ElementRef;
+use crate::options::ReadabilityOptions;
+use scraper::{ElementRef, Html, Selector};
+
+/// Check whether an element looks like non-content that extraction would prune.
+///
+/// This is the same decision the extractor makes for every candidate while unlikely
+/// candidates are being stripped (the first, strictest extraction attempt). An element
+/// is unlikely when its class or id matches patterns such as `comment`, `sidebar` or
+/// `footer`, unless it also matches a rescue pattern such as `article`, `main` or
+/// `body`.
+///
+/// Useful for testing site-specific rules without running a full extraction.
+///
+/// # Arguments
+/// * `element` - The element to check
+///
+/// # Example
+///
+/// ```rust
+/// use readabilityrs::is_unlikely_candidate;
+/// use scraper::{Html, Selector};
+///
+/// let html = Html::parse_fragment(r#"<div class="comments">Nice post!</div>"#);
+/// let selector = Selector::parse("div").unwrap();
+/// let element = html.select(&selector).next().unwrap();
+///
+/// assert!(is_unlikely_candidate(element));
+/// ```
+pub fn is_unlikely_candidate(element: ElementRef) -> bool {
+    let class = element.value().attr("class").unwrap_or("");
+    let id = element.value().attr("id").unwrap_or("");
+    let match_string = format!("{class} {id}");
+
+    REGEXPS.unlikely_candidates.is_match(&match_string)
+        && !REGEXPS.ok_maybe_its_a_candidate.is_match(&match_string)
+}
 
 /// Get an element's class/ID weight using regular expressions.
 /// Uses positive/negative patterns to determine if an element looks good or bad.
@@ -98,6 +133,35 @@ pub fn initialize_node_score(element: ElementRef, flags: ParseFlags) -> f64 {
     score
 }
 
+/// Check whether an element looks like a sponsored or native-ad block.
+///
+/// True when the class or id matches patterns such as `sponsored`, `promoted` or
+/// `paid-partner`, or when the first child element is a short label such as
+/// "Sponsored", "Promoted" or "Paid Partnership".
+///
+/// # Arguments
+/// * `element` - The element to check
+///
+/// # Returns
+/// True if the element looks like sponsored content
+pub fn looks_sponsored(element: ElementRef) -> bool {
+    const MAX_LABEL_LEN: usize = 60;
+
+    let class = element.value().attr("class").unwrap_or("");
+    let id = element.value().attr("id").unwrap_or("");
+    if REGEXPS.sponsored.is_match(&format!("{class} {id}")) {
+        return true;
+    }
+
+    element
+        .children()
+        .find_map(ElementRef::wrap)
+        .map(|label| dom_utils::get_inner_text(label, true))
+        .is_some_and(|label| {
+            label.chars().count() <= MAX_LABEL_LEN && REGEXPS.sponsored_label.is_match(&label)
+        })
+}
+
 /// Calculate content score for a paragraph or other scoreable element.
 ///
 /// The score is based on:
@@ -131,6 +195,117 @@ pub fn calculate_content_score(element: ElementRef, link_density_modifier: f64)
     score
 }
 
+/// The `paragraph_like_tags`, trimmed, falling back to `p` when none are listed.
+fn paragraph_like_tags(options: &ReadabilityOptions) -> Vec<&str> {
+    let tags: Vec<&str> = options
+        .paragraph_like_tags
+        .iter()
+        .map(|tag| tag.trim())
+        .filter(|tag| !tag.is_empty())
+        .collect();
+    if tags.is_empty() {
+        vec!["p"]
+    } else {
+        tags
+    }
+}
+
+/// Whether a `paragraph_like_tags` entry is a plain tag name usable as a selector.
+pub(crate) fn is_valid_paragraph_like_tag(tag: &str) -> bool {
+    let tag = tag.trim();
+    !tag.is_empty()
+        && tag.chars().all(|c| c.is_ascii_alphanumeric() || c == '-')
+        && Selector::parse(tag).is_ok()
+}
+
+/// Whether the element is one of the `paragraph_like_tags`, scored as a paragraph.
+pub(crate) fn is_paragraph_like(element: ElementRef, options: &ReadabilityOptions) -> bool {
+    let name = element.value().name();
+    paragraph_like_tags(options)
+        .iter()
+        .any(|tag| tag.eq_ignore_ascii_case(name))
+}
+
+/// Elements of the `paragraph_like_tags` to add as candidates, in document order.
+///
+/// Tags the extractor already scores as containers (`DEFAULT_TAGS_TO_SCORE`, such as
+/// `section`, `div` or `td`) are left out so their elements are not counted twice; `p`
+/// is always scanned, as it was before the option existed. Tags that are not valid
+/// type selectors are skipped.
+pub(crate) fn select_paragraph_like<'a>(
+    document: &'a Html,
+    options: &ReadabilityOptions,
+) -> Vec<ElementRef<'a>> {
+    let tags: Vec<&str> = paragraph_like_tags(options)
+        .into_iter()
+        .filter(|tag| {
+            tag.eq_ignore_ascii_case("p")
+                || !DEFAULT_TAGS_TO_SCORE
+                    .iter()
+                    .any(|scored| scored.eq_ignore_ascii_case(tag))
+        })
+        .filter(|tag| is_valid_paragraph_like_tag(tag))
+        .collect();
+    if tags.is_empty() {
+        return Vec::new();
+    }
+    let Ok(selector) = Selector::parse(&tags.join(", ")) else {
+        return Vec::new();
+    };
+    document.select(&selector).collect()
+}
+
+/// Estimate how confident the extraction is, from `0.0` to `1.0`.
+///
+/// Combines signals the extractor already has:
+/// - Score margin: how far the top candidate's score is ahead of the best unrelated
+///   candidate, as a fraction of the top score. Worth up to 0.35 on top of a 0.35 base.
+///   Content taken without scoring has no margin to measure and counts as a neutral
+///   0.5.
+/// - Link density of the chosen content: worth 0.15 at zero, falling to nothing at 50%.
+/// - Metadata: a byline and a published date are worth 0.075 each.
+/// - Fallback: when the strict first attempt fell short, the total is scaled by 0.6.
+///
+/// # Arguments
+/// * `scores` - Score of the chosen candidate and best score among unrelated
+///   candidates, `None` when the content was not scored
+/// * `link_density` - Link density of the extracted content
+/// * `has_byline` - Whether a byline was found
+/// * `has_date` - Whether a published date was found
+/// * `used_fallback` - Whether a looser extraction attempt was needed
+///
+/// # Returns
+/// Confidence between 0.0 and 1.0
+pub fn extraction_confidence(
+    scores: Option<(f64, f64)>,
+    link_density: f64,
+    has_byline: bool,
+    has_date: bool,
+    used_fallback: bool,
+) -> f32 {
+    let margin = match scores {
+        Some((top_score, runner_up_score)) if top_score > 0.0 => {
+            ((top_score - runner_up_score) / top_score).clamp(0.0, 1.0)
+        }
+        Some(_) => 0.0,
+        None => 0.5,
+    };
+
+    let mut confidence = 0.35 + 0.35 * margin;
+    confidence += 0.15 * (1.0 - (link_density * 2.0).min(1.0));
+    if has_byline {
+        confidence += 0.075;
+    }
+    if has_date {
+        confidence += 0.075;
+    }
+    if used_fallback {
+        confidence *= 0.6;
+    }
+
+    confidence.clamp(0.0, 1.0) as f32
+}
+
 /// Check if an element is a valid byline.
 ///
 /// A valid byline should:
@@ -160,6 +335,25 @@ mod tests {
     use scraper::Html;
     use scraper::Selector;
 
+    #[test]
+    fn test_is_unlikely_candidate() {
+        let html = Html::parse_fragment(
+            r#"
+            <div id="comments">Reader comments</div>
+            <div class="sidebar article">Rescued by article</div>
+            <div class="story">Story text</div>
+        "#,
+        );
+        let select = |css: &str| {
+            let selector = Selector::parse(css).unwrap();
+            html.select(&selector).next().unwrap()
+        };
+
+        assert!(is_unlikely_candidate(select("#comments")));
+        assert!(!is_unlikely_candidate(select(".sidebar")));
+        assert!(!is_unlikely_candidate(select(".story")));
+    }
+
     #[test]
     fn test_get_class_weight() {
         let html = Html::parse_fragment(
@@ -221,6 +415,25 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_looks_sponsored() {
+        let html = Html::parse_fragment(
+            r#"<div id="a" class="promoted-stories"><p>Text</p></div>
+            <div id="b"><span>Sponsored</span><p>Text</p></div>
+            <div id="c"><h2>Sponsored jobs are up this year</h2><p>Text</p></div>
+            <div id="d" class="story"><p>Text</p></div>"#,
+        );
+        let select = |id: &str| {
+            let selector = Selector::parse(&format!("#{id}")).unwrap();
+            html.select(&selector).next().unwrap()
+        };
+
+        assert!(looks_sponsored(select("a")));
+        assert!(looks_sponsored(select("b")));
+        assert!(!looks_sponsored(select("c")));
+        assert!(!looks_sponsored(select("d")));
+    }
+
     #[test]
     fn test_calculate_content_score() {
         let html = Html::parse_fragment(
@@ -242,4 +455,19 @@ mod tests {
         let score = calculate_content_score(elem, 0.0);
         assert_eq!(score, 0.0);
     }
+
+    #[test]
+    fn test_extraction_confidence() {
+        let clear = extraction_confidence(Some((100.0, 10.0)), 0.05, true, true, false);
+        let contested = extraction_confidence(Some((100.0, 95.0)), 0.05, true, true, false);
+        let fallback = extraction_confidence(Some((100.0, 10.0)), 0.05, true, true, true);
+        let unscored = extraction_confidence(None, 0.05, true, true, false);
+
+        assert!(clear > 0.85 && clear <= 1.0);
+        assert!(contested < clear);
+        assert!(fallback < clear);
+        assert!(unscored < clear && unscored > contested);
+        let floor = extraction_confidence(Some((0.0, 0.0)), 1.0, false, false, true);
+        assert!((floor - 0.21).abs() < 1e-6);
+    }
 }
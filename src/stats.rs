@@ -0,0 +1,113 @@
+//! Counters collected while parsing a document.
+//!
+//! Returned by [`Readability::parse_with_stats`](crate::Readability::parse_with_stats)
+//! to help explain why output size or quality varies across a corpus.
+//!
+//! ## Example
+//!
+//! ```rust
+//! use readabilityrs::Readability;
+//!
+//! let html = "<html><body><article><p>Some article text.</p></article></body></html>";
+//! let readability = Readability::new(html, Some("https://example.com/story"), None).unwrap();
+//! let (article, stats) = readability.parse_with_stats();
+//!
+//! println!("removed {} elements in {:?}", stats.elements_removed, stats.elapsed);
+//! # let _ = article;
+//! ```
+
+use serde::{Deserialize, Serialize};
+use std::time::Duration;
+
+/// Counters for a single parse.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Default)]
+pub struct ParseStats {
+    /// Elements removed from the extracted content by the cleaning passes.
+    ///
+    /// Covers navigation-like sections and blocks dropped by conditional cleaning.
+    pub elements_removed: usize,
+
+    /// Relative `href`, `src`, `poster` and `srcset` URLs resolved against the base URL.
+    ///
+    /// Always `0` when no base URL was given.
+    pub links_absolutized: usize,
+
+    /// `<img>` elements left in the final content.
+    pub images_kept: usize,
+
+    /// Share of the page's text that belongs to the extracted article.
+    ///
+    /// The length of the extracted text divided by the length of all text in the
+    /// document, both measured after scripts and styles are stripped. Values close to
+    /// 1.0 suggest a clean article page; low values suggest a portal or link farm.
+    /// `0.0` when no content was extracted. The same value as
+    /// [`Readability::content_density`](crate::Readability::content_density), measured
+    /// only by `parse_with_stats`, not by `parse`.
+    #[serde(default)]
+    pub content_density: f64,
+
+    /// Wall-clock time spent in the parse.
+    pub elapsed: Duration,
+
+    /// Phase after which the
+    /// [`ReadabilityOptions::max_duration`](crate::ReadabilityOptions::max_duration)
+    /// budget ran out, if it did: `"metadata"`, `"scoring"` or `"cleaning"`.
+    ///
+    /// When set, the article is missing or only partly cleaned.
+    #[serde(default)]
+    pub budget_exceeded: Option<String>,
+
+    /// Blocks removed as clutter, in the order they were removed.
+    ///
+    /// Only filled when
+    /// [`ReadabilityOptions::trace_removed_blocks`](crate::ReadabilityOptions::trace_removed_blocks)
+    /// is set. Each pass lists the blocks it removes, so a block removed by a later pass,
+    /// such as a `<div>` dropped by conditional cleaning, may contain blocks listed
+    /// before it; `elements_removed` counts both.
+    #[serde(default)]
+    pub removed_blocks: Vec<RemovedBlock>,
+
+    #[serde(skip)]
+    pub(crate) trace_removed_blocks: bool,
+}
+
+impl ParseStats {
+    /// Count a removed element, recording it when tracing is enabled
+    pub(crate) fn record_removed(&mut self, block: impl FnOnce() -> RemovedBlock) {
+        self.elements_removed += 1;
+        if self.trace_removed_blocks {
+            self.removed_blocks.push(block());
+        }
+    }
+}
+
+/// A block removed from the content by a cleaning pass.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct RemovedBlock {
+    /// Lowercase tag name, such as `div`.
+    pub tag: String,
+
+    /// The block's `class` attribute, if any.
+    pub class: Option<String>,
+
+    /// The block's `id` attribute, if any.
+    pub id: Option<String>,
+
+    /// Why the block was removed.
+    pub reason: RemovalReason,
+
+    /// Length of the block's text, to spot removed blocks that held real content.
+    pub text_length: usize,
+}
+
+/// Why a cleaning pass removed a block.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+pub enum RemovalReason {
+    /// A `<nav>`, or a block whose class or id mentions navigation, menus, breadcrumbs,
+    /// sidebars or widgets.
+    NavigationLike,
+
+    /// Dropped by conditional cleaning: too many links, images or inputs for the amount
+    /// of text, or a negative class weight.
+    Conditional,
+}
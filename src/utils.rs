@@ -32,6 +32,22 @@ pub fn unescape_html_entities(text: &str) -> String {
     result
 }
 
+/// Remove byte-order marks, zero-width spaces and control characters.
+///
+/// These break comparisons of titles and bylines and render as stray glyphs. Zero-width
+/// joiners and non-joiners are kept, since emoji sequences and some scripts rely on them.
+/// Tabs and line breaks are left for whitespace normalization.
+pub fn strip_invisible_chars(text: &str) -> Cow<'_, str> {
+    let is_invisible = |c: char| {
+        matches!(c, '\u{feff}' | '\u{200b}' | '\u{2060}')
+            || (c.is_control() && !matches!(c, '\t' | '\n' | '\r'))
+    };
+    if !text.contains(is_invisible) {
+        return Cow::Borrowed(text);
+    }
+    Cow::Owned(text.chars().filter(|&c| !is_invisible(c)).collect())
+}
+
 fn decode_html_entity(entity: &str) -> Option<String> {
     match entity {
         "&lt;" => Some("<".to_string()),
@@ -65,11 +81,257 @@ pub fn normalize_whitespace(text: &str) -> String {
     REGEXPS.normalize.replace_all(text, " ").to_string()
 }
 
+/// Words that form hyphenated compounds, so a line break after `well-` keeps the hyphen.
+const COMPOUND_PREFIXES: &[&str] = &[
+    "all", "anti", "best", "cross", "ex", "far", "full", "half", "high", "ill", "long", "low",
+    "mid", "much", "non", "off", "old", "one", "open", "part", "post", "pre", "pro", "quasi",
+    "right", "second", "self", "semi", "short", "so", "still", "three", "two", "well", "wide",
+];
+
+/// Join words hyphenated across a line break, as found in justified or PDF-derived text.
+///
+/// `informa-\ntion` becomes `information`. The hyphen is kept for compounds such as
+/// `well-\nknown`, which becomes `well-known`: when the left part is a common compound
+/// word, or when the hyphenated form also appears elsewhere in the text. Only breaks
+/// followed by a lowercase continuation are touched.
+pub fn merge_hyphenated_line_breaks(text: &str) -> String {
+    static LINE_BREAK_HYPHEN: Lazy<Regex> =
+        Lazy::new(|| Regex::new(r"(\p{L}+)-[ \t]*\r?\n[ \t]*(\p{Ll}+)").unwrap());
+
+    if !text.contains('-') {
+        return text.to_string();
+    }
+
+    LINE_BREAK_HYPHEN
+        .replace_all(text, |caps: &regex::Captures| {
+            let head = &caps[1];
+            let tail = &caps[2];
+            let compound = format!("{head}-{tail}");
+            let keep_hyphen = COMPOUND_PREFIXES.contains(&head.to_lowercase().as_str())
+                || text.contains(&compound);
+            if keep_hyphen {
+                compound
+            } else {
+                format!("{head}{tail}")
+            }
+        })
+        .to_string()
+}
+
+/// Split text into sentences at `.`, `!`, `?` and `…`.
+///
+/// A boundary needs whitespace after the punctuation (and any closing quotes or
+/// brackets) followed by an uppercase letter, digit or opening quote. Common
+/// abbreviations ("Dr.", "e.g.") and initials ("J. R. R.") do not end a sentence.
+/// Whitespace inside each sentence is collapsed.
+pub(crate) fn split_sentences(text: &str) -> Vec<String> {
+    const ABBREVIATIONS: &[&str] = &[
+        "mr", "mrs", "ms", "dr", "prof", "sr", "jr", "st", "mt", "vs", "etc", "e.g", "i.e", "inc",
+        "ltd", "co", "corp", "no", "fig", "jan", "feb", "mar", "apr", "jun", "jul", "aug", "sep",
+        "sept", "oct", "nov", "dec", "u.s", "u.k",
+    ];
+    const CLOSERS: &[char] = &['"', '\'', '”', '’', ')', ']', '»'];
+
+    let text = normalize_whitespace(text);
+    let chars: Vec<(usize, char)> = text.char_indices().collect();
+    let mut sentences = Vec::new();
+    let mut start = 0;
+    let mut i = 0;
+
+    while i < chars.len() {
+        let (_, ch) = chars[i];
+        if !matches!(ch, '.' | '!' | '?' | '…') {
+            i += 1;
+            continue;
+        }
+
+        let mut end = i + 1;
+        while end < chars.len() && matches!(chars[end].1, '.' | '!' | '?' | '…') {
+            end += 1;
+        }
+        while end < chars.len() && CLOSERS.contains(&chars[end].1) {
+            end += 1;
+        }
+        if end < chars.len() && !chars[end].1.is_whitespace() {
+            i = end;
+            continue;
+        }
+
+        let next = chars[end..]
+            .iter()
+            .map(|(_, c)| *c)
+            .find(|c| !c.is_whitespace());
+        let starts_sentence = match next {
+            Some(c) => c.is_uppercase() || c.is_ascii_digit() || "\"'“‘«(".contains(c),
+            None => true,
+        };
+
+        let word_before = text[start..chars[i].0]
+            .rsplit(char::is_whitespace)
+            .next()
+            .unwrap_or("")
+            .trim_start_matches(|c: char| !c.is_alphanumeric())
+            .to_lowercase();
+        let is_abbreviation = ch == '.'
+            && (ABBREVIATIONS.contains(&word_before.as_str())
+                || (word_before.chars().count() == 1
+                    && word_before.chars().all(char::is_alphabetic)));
+
+        if starts_sentence && !is_abbreviation {
+            let byte_end = chars.get(end).map_or(text.len(), |(pos, _)| *pos);
+            let sentence = text[start..byte_end].trim();
+            if !sentence.is_empty() {
+                sentences.push(sentence.to_string());
+            }
+            start = byte_end;
+        }
+        i = end;
+    }
+
+    let rest = text[start..].trim();
+    if !rest.is_empty() {
+        sentences.push(rest.to_string());
+    }
+    sentences
+}
+
+/// Longest prefix of at most `max_chars` characters that doesn't split a grapheme cluster.
+///
+/// Covers the clusters that matter for excerpts: emoji joined with a zero-width joiner,
+/// skin tone modifiers, variation selectors, combining marks, tag sequences and flag
+/// pairs. A cluster that would be cut is dropped whole.
+pub(crate) fn truncate_chars(text: &str, max_chars: usize) -> &str {
+    let chars: Vec<(usize, char)> = text.char_indices().collect();
+    if chars.len() <= max_chars {
+        return text;
+    }
+
+    let mut cut = max_chars;
+    while cut > 0 && continues_cluster(&chars, cut) {
+        cut -= 1;
+    }
+    &text[..chars[cut].0]
+}
+
+/// Whether the character at `index` belongs to the same grapheme cluster as the one before.
+fn continues_cluster(chars: &[(usize, char)], index: usize) -> bool {
+    let ch = chars[index].1;
+    let prev = chars[index - 1].1;
+
+    if prev == '\u{200d}' || is_grapheme_extender(ch) {
+        return true;
+    }
+
+    // Regional indicators pair up into flags, so an odd run before `ch` is incomplete
+    if is_regional_indicator(ch) {
+        let run = chars[..index]
+            .iter()
+            .rev()
+            .take_while(|(_, c)| is_regional_indicator(*c))
+            .count();
+        return run % 2 == 1;
+    }
+
+    false
+}
+
+fn is_grapheme_extender(ch: char) -> bool {
+    matches!(ch,
+        '\u{0300}'..='\u{036f}'
+        | '\u{1ab0}'..='\u{1aff}'
+        | '\u{1dc0}'..='\u{1dff}'
+        | '\u{200c}'..='\u{200d}'
+        | '\u{20d0}'..='\u{20ff}'
+        | '\u{fe00}'..='\u{fe0f}'
+        | '\u{fe20}'..='\u{fe2f}'
+        | '\u{1f3fb}'..='\u{1f3ff}'
+        | '\u{e0020}'..='\u{e007f}'
+        | '\u{e0100}'..='\u{e01ef}')
+}
+
+fn is_regional_indicator(ch: char) -> bool {
+    matches!(ch, '\u{1f1e6}'..='\u{1f1ff}')
+}
+
 /// Check if a string is a valid URL
 pub fn is_url(s: &str) -> bool {
     url::Url::parse(s).is_ok()
 }
 
+/// Resolve `href` against `base_url`
+///
+/// Absolute URLs are returned as is. Relative URLs are joined onto the base, or returned
+/// unchanged when there is no base. Returns `None` when the URL cannot be resolved.
+pub fn to_absolute_url(href: &str, base_url: Option<&str>) -> Option<String> {
+    let href = href.trim();
+    if href.is_empty() {
+        return None;
+    }
+
+    if let Ok(url) = url::Url::parse(href) {
+        return Some(url.to_string());
+    }
+
+    match base_url {
+        Some(base) => url::Url::parse(base)
+            .and_then(|base| base.join(href))
+            .map(|url| url.to_string())
+            .ok(),
+        None => Some(href.to_string()),
+    }
+}
+
+/// Split a `srcset` value into `(url, descriptor)` pairs
+///
+/// The descriptor is the trimmed width (`800w`) or density (`2x`) part, empty when the
+/// candidate has none. Empty candidates are skipped.
+pub(crate) fn parse_srcset(srcset: &str) -> Vec<(&str, &str)> {
+    srcset
+        .split(',')
+        .map(str::trim)
+        .filter(|candidate| !candidate.is_empty())
+        .map(|candidate| {
+            let (url, descriptor) = candidate
+                .split_once(char::is_whitespace)
+                .unwrap_or((candidate, ""));
+            (url, descriptor.trim())
+        })
+        .collect()
+}
+
+/// URL of the largest candidate in a `srcset` value
+///
+/// Width descriptors (`800w`) are compared first; without any, density descriptors
+/// (`2x`) are, with a missing descriptor counting as `1x`.
+pub(crate) fn largest_srcset_url(srcset: &str) -> Option<&str> {
+    let candidates = parse_srcset(srcset);
+    let size = |descriptor: &str, unit: char| {
+        descriptor
+            .strip_suffix(unit)
+            .and_then(|value| value.parse::<f64>().ok())
+    };
+
+    let by_width = candidates
+        .iter()
+        .filter_map(|(url, descriptor)| size(descriptor, 'w').map(|width| (*url, width)))
+        .max_by(|a, b| a.1.total_cmp(&b.1));
+    if let Some((url, _)) = by_width {
+        return Some(url);
+    }
+    candidates
+        .iter()
+        .map(|(url, descriptor)| {
+            let density = if descriptor.is_empty() {
+                Some(1.0)
+            } else {
+                size(descriptor, 'x')
+            };
+            (*url, density.unwrap_or(0.0))
+        })
+        .max_by(|a, b| a.1.total_cmp(&b.1))
+        .map(|(url, _)| url)
+}
+
 static BY_PREFIX_REGEX: Lazy<Regex> =
     Lazy::new(|| Regex::new(r"(?i)^(by|par)[\s:,\-–—]+").unwrap());
 
@@ -93,6 +355,15 @@ pub fn looks_like_byline(text: &str) -> bool {
     }
 }
 
+/// Remove a leading "By" or "Par" credit from a byline, leaving the names.
+pub fn strip_by_prefix(text: &str) -> &str {
+    let trimmed = text.trim();
+    match BY_PREFIX_REGEX.find(trimmed) {
+        Some(prefix) => trimmed[prefix.end()..].trim_start(),
+        None => trimmed,
+    }
+}
+
 /// Remove invisible space characters that frequently wrap metadata text.
 fn trim_soft_space(text: &str) -> &str {
     text.trim_matches(|c| SOFT_SPACE_CHARS.contains(&c))
@@ -131,6 +402,26 @@ fn looks_like_social_handle(text: &str) -> bool {
     false
 }
 
+/// Comparison key for a person's name, so "By Jane Q. Doe" and "jane doe" match.
+///
+/// Lowercases, drops a leading "by", periods and middle initials, and collapses
+/// whitespace. A leading initial is kept, so "J. Doe" does not match "John Doe".
+pub(crate) fn person_name_key(name: &str) -> String {
+    let lower = name.to_lowercase().replace('.', " ");
+    let mut words: Vec<&str> = lower.split_whitespace().collect();
+    if words.first() == Some(&"by") {
+        words.remove(0);
+    }
+    let last = words.len().saturating_sub(1);
+    words
+        .iter()
+        .enumerate()
+        .filter(|&(index, word)| index == 0 || index == last || word.chars().count() > 1)
+        .map(|(_, word)| *word)
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
 /// Heuristic check for anchor text that looks like a personal name.
 pub fn looks_like_author_name(text: &str) -> bool {
     let trimmed = trim_soft_space(text.trim());
@@ -188,6 +479,94 @@ pub fn looks_like_author_name(text: &str) -> bool {
         .any(|token| DISQUALIFIERS.contains(&token))
 }
 
+/// Check whether a name ends with a generational or academic suffix such as `Jr.`,
+/// `III` or `PhD`.
+fn ends_with_name_suffix(text: &str) -> bool {
+    const NAME_SUFFIXES: [&str; 7] = ["jr", "sr", "ii", "iii", "iv", "phd", "md"];
+
+    text.rsplit(|c: char| c.is_whitespace() || c == ',')
+        .find(|token| !token.is_empty())
+        .is_some_and(|token| {
+            let token = token.replace('.', "").to_lowercase();
+            NAME_SUFFIXES.contains(&token.as_str())
+        })
+}
+
+/// Check whether a lowercase token is a job title word such as `editor` or `staff`.
+pub(crate) fn looks_like_job_descriptor(token: &str) -> bool {
+    const JOB_KEYWORDS: [&str; 19] = [
+        "reporter",
+        "editor",
+        "writer",
+        "staff",
+        "senior",
+        "technologist",
+        "correspondent",
+        "columnist",
+        "analyst",
+        "producer",
+        "anchor",
+        "bureau",
+        "desk",
+        "spokesman",
+        "spokeswoman",
+        "spokesperson",
+        "contributor",
+        "team",
+        "author",
+    ];
+    JOB_KEYWORDS.contains(&token)
+}
+
+/// Pick the name out of a byline whose parts are separated by bullets, pipes or tabs.
+///
+/// Bylines such as `Jane Doe • Senior Editor • 5 min read` mix the author with other
+/// details. When at least one segment is a reading time or a bare job title, only the
+/// name-like segments are kept. Returns `None` when the text should be left as is.
+fn select_byline_name_segments(text: &str) -> Option<String> {
+    static READING_TIME: Lazy<Regex> = Lazy::new(|| {
+        Regex::new(r"(?i)^\d+(?:\s*|-)(?:min|mins|minute|minutes)\.?\s+read$").unwrap()
+    });
+
+    let segments: Vec<&str> = text
+        .split(['•', '·', '|', '\t'])
+        .map(|segment| trim_soft_space(segment.trim()))
+        .filter(|segment| !segment.is_empty())
+        .collect();
+    if segments.len() < 2 {
+        return None;
+    }
+
+    let is_metadata = |segment: &str| {
+        if READING_TIME.is_match(segment) {
+            return true;
+        }
+        let lower = segment.to_lowercase();
+        let tokens: Vec<&str> = lower
+            .split(|c: char| !c.is_alphanumeric())
+            .filter(|token| !token.is_empty())
+            .collect();
+        !tokens.is_empty()
+            && tokens
+                .iter()
+                .all(|token| looks_like_job_descriptor(token) || matches!(*token, "and" | "of"))
+    };
+
+    if !segments.iter().any(|segment| is_metadata(segment)) {
+        return None;
+    }
+
+    let names: Vec<&str> = segments
+        .into_iter()
+        .filter(|segment| !is_metadata(segment) && looks_like_author_name(segment))
+        .collect();
+    if names.is_empty() {
+        return None;
+    }
+
+    Some(names.join(", "))
+}
+
 fn contains_author_like_segment(text: &str) -> bool {
     if looks_like_author_name(text) {
         return true;
@@ -287,11 +666,12 @@ fn strip_trailing_datetime_clause<'a>(text: &'a str, allow_strip: bool) -> Cow<'
         return Cow::Borrowed(text);
     }
 
-    let lower = text.to_lowercase();
+    // Search the original text: lowercasing can change byte lengths, so offsets found
+    // in a lowercased copy are not valid in `text`
     for separator in [" | ", " - ", " – ", " — ", " · "] {
-        if let Some(idx) = lower.rfind(separator) {
-            let tail = lower[idx + separator.len()..].trim();
-            if looks_like_datetime_segment(tail) {
+        if let Some(idx) = text.rfind(separator) {
+            let tail = text[idx + separator.len()..].to_lowercase();
+            if looks_like_datetime_segment(tail.trim()) {
                 return Cow::Owned(text[..idx].trim_end().to_string());
             }
         }
@@ -398,32 +778,19 @@ fn looks_like_live_timestamp_segment(segment: &str) -> bool {
 }
 
 pub(crate) fn looks_like_org_credit(text: &str) -> bool {
-    if contains_author_like_segment(text) {
-        return false;
-    }
-
     let normalized = normalize_whitespace(text).to_lowercase();
     if normalized.is_empty() {
         return false;
     }
 
-    const EXACT_AGENCIES: [&str; 10] = [
-        "afp",
-        "ap",
-        "associated press",
-        "reuters",
-        "bloomberg",
-        "press association",
-        "kyodo",
-        "ansa",
-        "dpa",
-        "upi",
-    ];
-
-    if EXACT_AGENCIES.contains(&normalized.as_str()) {
+    if looks_like_agency_credit(&normalized) {
         return true;
     }
 
+    if contains_author_like_segment(text) {
+        return false;
+    }
+
     let keywords = [
         "staff",
         "news",
@@ -456,15 +823,226 @@ pub(crate) fn looks_like_org_credit(text: &str) -> bool {
     hits >= 2
 }
 
+/// News agencies commonly credited in place of a human author.
+const NEWS_AGENCIES: [&str; 19] = [
+    "afp",
+    "agence france-presse",
+    "agence france presse",
+    "ap",
+    "associated press",
+    "reuters",
+    "bloomberg",
+    "press association",
+    "kyodo",
+    "ansa",
+    "dpa",
+    "deutsche presse-agentur",
+    "efe",
+    "agencia efe",
+    "xinhua",
+    "xinhua news agency",
+    "pti",
+    "press trust of india",
+    "upi",
+];
+
+/// Byline prefix and month names for a language subtag such as `fr` or `en-GB`.
+fn byline_locale(language: &str) -> Option<(&'static str, &'static [&'static str])> {
+    let primary = language
+        .split(['-', '_'])
+        .next()
+        .unwrap_or("")
+        .trim()
+        .to_ascii_lowercase();
+
+    match primary.as_str() {
+        "en" => Some((
+            "by",
+            &[
+                "january",
+                "february",
+                "march",
+                "april",
+                "may",
+                "june",
+                "july",
+                "august",
+                "september",
+                "october",
+                "november",
+                "december",
+            ],
+        )),
+        "fr" => Some((
+            "par",
+            &[
+                "janvier",
+                "février",
+                "fevrier",
+                "mars",
+                "avril",
+                "mai",
+                "juin",
+                "juillet",
+                "août",
+                "aout",
+                "septembre",
+                "octobre",
+                "novembre",
+                "décembre",
+                "decembre",
+            ],
+        )),
+        "de" => Some((
+            "von",
+            &[
+                "januar",
+                "februar",
+                "märz",
+                "maerz",
+                "april",
+                "mai",
+                "juni",
+                "juli",
+                "august",
+                "september",
+                "oktober",
+                "november",
+                "dezember",
+            ],
+        )),
+        "es" => Some((
+            "por",
+            &[
+                "enero",
+                "febrero",
+                "marzo",
+                "abril",
+                "mayo",
+                "junio",
+                "julio",
+                "agosto",
+                "septiembre",
+                "octubre",
+                "noviembre",
+                "diciembre",
+            ],
+        )),
+        _ => None,
+    }
+}
+
+/// Strip the byline prefix and a trailing date written in the given language.
+///
+/// `"Par Jean Dupont, le 3 mars 2024"` with language `fr` becomes `"Jean Dupont"`.
+/// Returns the byline unchanged for unsupported languages or when nothing would remain.
+pub(crate) fn strip_localized_byline(byline: &str, language: &str) -> String {
+    let Some((prefix, months)) = byline_locale(language) else {
+        return byline.to_string();
+    };
+
+    let mut result = byline.trim();
+    if let Some(head) = result.get(..prefix.len()) {
+        let rest = &result[prefix.len()..];
+        if head.eq_ignore_ascii_case(prefix)
+            && rest.starts_with(|c: char| c.is_whitespace() || c == ':')
+        {
+            result = rest.trim_start_matches(|c: char| c.is_whitespace() || c == ':');
+        }
+    }
+
+    let date_start = result
+        .match_indices([',', '|', '–', '—'])
+        .map(|(pos, _)| pos)
+        .find(|&pos| {
+            let tail = result[pos..].to_lowercase();
+            tail.chars().any(|c| c.is_ascii_digit())
+                && tail
+                    .split(|c: char| !c.is_alphabetic())
+                    .any(|word| months.contains(&word))
+        });
+    if let Some(pos) = date_start {
+        result = result[..pos].trim_end();
+    }
+
+    if result.is_empty() {
+        byline.to_string()
+    } else {
+        result.to_string()
+    }
+}
+
+/// Detects credits made up only of agency names, in any common form.
+///
+/// Handles localized prefixes ("Par AFP", "Von dpa", "Por EFE"), the parenthetical
+/// dateline form ("(AFP)") and joint credits ("AFP/Reuters", "AP and Reuters").
+/// Expects lowercase, whitespace-normalized input.
+fn looks_like_agency_credit(normalized: &str) -> bool {
+    const PREFIXES: [&str; 7] = ["by ", "par ", "von ", "por ", "di ", "door ", "da "];
+
+    let mut remainder = normalized.trim();
+    for prefix in PREFIXES {
+        if let Some(stripped) = remainder.strip_prefix(prefix) {
+            remainder = stripped;
+            break;
+        }
+    }
+
+    let unwrapped = remainder.replace(['(', ')', '[', ']'], " ");
+    let mut parts = vec![unwrapped.as_str()];
+    for separator in ["/", ",", "&", "+", " and ", " et ", " und ", " y "] {
+        parts = parts
+            .into_iter()
+            .flat_map(|part| part.split(separator))
+            .collect();
+    }
+
+    let mut agency_count = 0;
+    for part in parts {
+        let part = part.trim_matches(|c: char| c.is_whitespace() || c == '.' || c == '-');
+        if part.is_empty() {
+            continue;
+        }
+        if !NEWS_AGENCIES.contains(&part) {
+            return false;
+        }
+        agency_count += 1;
+    }
+
+    agency_count > 0
+}
+
+/// Bidirectional control marks that wrap tokens in right-to-left pages.
+const BIDI_CONTROL_CHARS: &[char] = &[
+    '\u{200e}', '\u{200f}', '\u{061c}', '\u{202a}', '\u{202b}', '\u{202c}', '\u{202d}', '\u{202e}',
+    '\u{2066}', '\u{2067}', '\u{2068}', '\u{2069}',
+];
+
+/// Check if text looks like a navigation menu rather than prose.
+///
+/// Matches runs of bracketed links (`[Edit] [History]`), including the mirrored
+/// `]...[` order that right-to-left pages produce, and right-to-left menus built from
+/// short Arabic or Hebrew items separated by `|`, `•`, `·` or the Arabic comma.
 pub fn looks_like_bracket_menu(text: &str) -> bool {
-    let mut remainder = text.trim();
-    if !remainder.starts_with('[') {
-        return false;
+    let cleaned: String = text
+        .chars()
+        .filter(|c| !BIDI_CONTROL_CHARS.contains(c))
+        .collect();
+    let mut remainder = cleaned.trim();
+
+    if looks_like_rtl_separator_menu(remainder) {
+        return true;
     }
 
+    let (open, close) = match remainder.chars().next() {
+        Some('[') => ('[', ']'),
+        Some(']') if contains_rtl_script(remainder) => (']', '['),
+        _ => return false,
+    };
+
     let mut matched = 0;
-    while remainder.starts_with('[') {
-        if let Some(end) = remainder.find(']') {
+    while remainder.starts_with(open) {
+        if let Some(end) = remainder[1..].find(close).map(|pos| pos + 1) {
             let token = remainder[1..end].trim();
             if token.is_empty() {
                 return false;
@@ -488,6 +1066,56 @@ pub fn looks_like_bracket_menu(text: &str) -> bool {
             .all(|c| c.is_ascii_digit() || c.is_whitespace())
 }
 
+/// Whether text contains Arabic or Hebrew letters.
+fn contains_rtl_script(text: &str) -> bool {
+    text.chars().any(|c| {
+        matches!(c,
+            '\u{0590}'..='\u{05ff}'
+            | '\u{0600}'..='\u{06ff}'
+            | '\u{0750}'..='\u{077f}'
+            | '\u{08a0}'..='\u{08ff}'
+            | '\u{fb1d}'..='\u{fdff}'
+            | '\u{fe70}'..='\u{feff}')
+    })
+}
+
+/// Direction of `text` from its first strongly directional character, following the
+/// HTML `dir="auto"` rule: "rtl" for Arabic or Hebrew, "ltr" for other letters, and
+/// "auto" when the text has no letters at all.
+pub(crate) fn text_direction(text: &str) -> &'static str {
+    for c in text.chars() {
+        if contains_rtl_script(c.encode_utf8(&mut [0; 4])) {
+            return "rtl";
+        }
+        if c.is_alphabetic() {
+            return "ltr";
+        }
+    }
+    "auto"
+}
+
+/// Right-to-left menus: three or more short items split by menu separators, with no
+/// sentence punctuation.
+fn looks_like_rtl_separator_menu(text: &str) -> bool {
+    if !contains_rtl_script(text) {
+        return false;
+    }
+    if text.contains(['.', '!', '?', '؟', '۔']) {
+        return false;
+    }
+
+    let items: Vec<&str> = text
+        .split(['|', '•', '·', '،', '/'])
+        .map(str::trim)
+        .filter(|item| !item.is_empty())
+        .collect();
+
+    items.len() >= 3
+        && items
+            .iter()
+            .all(|item| item.split_whitespace().count() <= 3)
+}
+
 pub(crate) fn looks_like_dateline(text: &str) -> bool {
     let trimmed = text.trim();
     if trimmed.is_empty() || trimmed.len() > 40 {
@@ -596,8 +1224,19 @@ pub fn clean_byline_text_with_reason(text: &str) -> CleanBylineOutcome {
         return CleanBylineOutcome::Dropped;
     }
 
+    // The period of "Jr." or "Ph.D." belongs to the name
+    let cleaned = match trimmed.strip_prefix(cleaned) {
+        Some(rest) if rest.starts_with('.') && ends_with_name_suffix(cleaned) => {
+            &trimmed[..cleaned.len() + 1]
+        }
+        _ => cleaned,
+    };
+
     let mut canonical = cleaned.replace("\r\n", "\n");
     canonical = collapse_blank_lines_preserve_indent(&canonical);
+    if let Some(names) = select_byline_name_segments(&canonical) {
+        canonical = names;
+    }
 
     let has_author_segment = contains_author_like_segment(&canonical);
     canonical = strip_trailing_datetime_clause(&canonical, has_author_segment).into_owned();
@@ -731,18 +1370,154 @@ fn remove_social_handle_lines(text: &str) -> Option<String> {
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_strip_trailing_date_when_lowercase_changes_length() {
+        // The Kelvin sign lowercases to a one-byte "k", shifting byte offsets
+        assert_eq!(
+            strip_trailing_datetime_clause("By \u{212a}ateřina Nováč | Mar 5, 2024 10:00 am", true),
+            "By \u{212a}ateřina Nováč"
+        );
+        assert_eq!(
+            strip_trailing_datetime_clause(
+                "By \u{212a}ateřina Nová\u{fffd}\u{fffd} - 10:00 am",
+                true
+            ),
+            "By \u{212a}ateřina Nová\u{fffd}\u{fffd}"
+        );
+    }
+
+    #[test]
+    fn test_largest_srcset_url() {
+        assert_eq!(
+            largest_srcset_url("a.jpg 480w, b.jpg 1200w,c.jpg 800w"),
+            Some("b.jpg")
+        );
+        assert_eq!(
+            largest_srcset_url("a.jpg, b.jpg 2x, c.jpg 1.5x"),
+            Some("b.jpg")
+        );
+        assert_eq!(largest_srcset_url(" , "), None);
+    }
+
     #[test]
     fn test_unescape_html_entities() {
         assert_eq!(unescape_html_entities("&lt;div&gt;"), "<div>");
         assert_eq!(unescape_html_entities("A &amp; B"), "A & B");
     }
 
+    #[test]
+    fn test_strip_invisible_chars() {
+        assert_eq!(strip_invisible_chars("\u{feff}Title"), "Title");
+        assert_eq!(
+            strip_invisible_chars("Zero\u{200b}width\u{0007}"),
+            "Zerowidth"
+        );
+        assert_eq!(
+            strip_invisible_chars("👩\u{200d}🔬 lab"),
+            "👩\u{200d}🔬 lab"
+        );
+        assert!(matches!(
+            strip_invisible_chars("Plain"),
+            Cow::Borrowed("Plain")
+        ));
+    }
+
     #[test]
     fn test_normalize_whitespace() {
         assert_eq!(normalize_whitespace("hello   world"), "hello world");
         assert_eq!(normalize_whitespace("a  b  c"), "a b c");
     }
 
+    #[test]
+    fn test_merge_hyphenated_line_breaks_joins_split_word() {
+        assert_eq!(
+            merge_hyphenated_line_breaks("More informa-\n    tion is needed."),
+            "More information is needed."
+        );
+        // A capitalised continuation is not a split word
+        assert_eq!(
+            merge_hyphenated_line_breaks("Paris-\nBerlin"),
+            "Paris-\nBerlin"
+        );
+    }
+
+    #[test]
+    fn test_merge_hyphenated_line_breaks_keeps_compounds() {
+        assert_eq!(
+            merge_hyphenated_line_breaks("A well-\nknown author."),
+            "A well-known author."
+        );
+        assert_eq!(
+            merge_hyphenated_line_breaks("The fact-\nchecked story was fact-checked twice."),
+            "The fact-checked story was fact-checked twice."
+        );
+    }
+
+    #[test]
+    fn test_to_absolute_url() {
+        let base = Some("https://example.com/news/story.html");
+        assert_eq!(
+            to_absolute_url("../about", base).as_deref(),
+            Some("https://example.com/about")
+        );
+        assert_eq!(
+            to_absolute_url("https://other.org/x", base).as_deref(),
+            Some("https://other.org/x")
+        );
+        assert_eq!(to_absolute_url("/about", None).as_deref(), Some("/about"));
+        assert_eq!(to_absolute_url("  ", base), None);
+    }
+
+    #[test]
+    fn test_strip_localized_byline() {
+        assert_eq!(
+            strip_localized_byline("Par Jean Dupont, le 3 mars 2024", "fr-FR"),
+            "Jean Dupont"
+        );
+        assert_eq!(
+            strip_localized_byline("By Jane Doe, March 3, 2024", "en"),
+            "Jane Doe"
+        );
+        assert_eq!(
+            strip_localized_byline("Par Jean Dupont", "en"),
+            "Par Jean Dupont"
+        );
+        assert_eq!(
+            strip_localized_byline("Par Jean Dupont", "ja"),
+            "Par Jean Dupont"
+        );
+    }
+
+    #[test]
+    fn test_split_sentences() {
+        assert_eq!(
+            split_sentences("Dr. Smith arrived at 9 a.m. on Monday. \"Is it safe?\" she asked.\nNobody knew… Then J. R. Doe spoke."),
+            vec![
+                "Dr. Smith arrived at 9 a.m. on Monday.",
+                "\"Is it safe?\" she asked.",
+                "Nobody knew…",
+                "Then J. R. Doe spoke.",
+            ]
+        );
+        assert_eq!(
+            split_sentences("Version 2.5 shipped"),
+            vec!["Version 2.5 shipped"]
+        );
+    }
+
+    #[test]
+    fn test_looks_like_bracket_menu_rtl() {
+        assert!(looks_like_bracket_menu(
+            "الرئيسية | أخبار | رياضة | اقتصاد | اتصل بنا"
+        ));
+        assert!(looks_like_bracket_menu("\u{200f}]تعديل[ ]تاريخ["));
+        assert!(looks_like_bracket_menu("ראשי • חדשות • ספורט • כלכלה"));
+        assert!(!looks_like_bracket_menu(
+            "أعلنت الحكومة اليوم عن خطة جديدة لدعم المزارعين في المناطق الريفية."
+        ));
+        assert!(!looks_like_bracket_menu("Home | News | Sports"));
+    }
+
     #[test]
     fn test_looks_like_byline() {
         assert!(looks_like_byline("By Alice Smith"));
@@ -757,6 +1532,26 @@ mod tests {
         assert_eq!(cleaned, "Nicolas Perriault");
     }
 
+    #[test]
+    fn test_clean_byline_text_keeps_name_suffixes() {
+        for name in [
+            "Martin Luther King Jr.",
+            "John Smith Sr.",
+            "Henry Ford III",
+            "Jane Doe, PhD",
+            "Jane Doe, Ph.D.",
+            "Gregory House, M.D.",
+        ] {
+            assert!(looks_like_author_name(name), "{name}");
+            assert_eq!(clean_byline_text(name).as_deref(), Some(name));
+        }
+        assert_eq!(
+            clean_byline_text("By Martin Luther King Jr. —").as_deref(),
+            Some("By Martin Luther King Jr.")
+        );
+        assert_eq!(clean_byline_text("Jane Doe.").as_deref(), Some("Jane Doe"));
+    }
+
     #[test]
     fn test_clean_byline_text_rejects_social_follow() {
         assert!(clean_byline_text("Follow @example").is_none());
@@ -797,6 +1592,26 @@ mod tests {
         assert_eq!(clean_byline_text(input).unwrap(), input);
     }
 
+    #[test]
+    fn test_clean_byline_text_keeps_name_from_bullet_separated_byline() {
+        assert_eq!(
+            clean_byline_text("Jane Doe • Senior Editor • 5 min read").unwrap(),
+            "Jane Doe"
+        );
+        assert_eq!(
+            clean_byline_text("Jane Doe | Staff Writer | 12-minute read").unwrap(),
+            "Jane Doe"
+        );
+    }
+
+    #[test]
+    fn test_clean_byline_text_keeps_name_from_tab_separated_byline() {
+        assert_eq!(
+            clean_byline_text("Jane Doe\tSenior Editor\t5 min read").unwrap(),
+            "Jane Doe"
+        );
+    }
+
     #[test]
     fn test_clean_byline_text_drops_org_credit() {
         assert!(clean_byline_text("Our Foreign Staff").is_none());
@@ -804,6 +1619,29 @@ mod tests {
         assert!(clean_byline_text("Android Developers").is_none());
     }
 
+    #[test]
+    fn test_looks_like_org_credit_agencies() {
+        for credit in [
+            "AFP",
+            "Par AFP",
+            "(AFP)",
+            "Von dpa",
+            "Por EFE",
+            "Xinhua",
+            "PTI",
+            "ANSA",
+            "AFP/Reuters",
+            "AP and Reuters",
+            "Agence France-Presse",
+        ] {
+            assert!(looks_like_org_credit(credit), "{credit}");
+        }
+
+        assert!(!looks_like_org_credit("Sébastien Farcis"));
+        assert!(!looks_like_org_credit("Par Sébastien Farcis"));
+        assert!(!looks_like_org_credit("Jane Doe (AFP)"));
+    }
+
     #[test]
     fn test_looks_like_author_name() {
         assert!(looks_like_author_name("Daniel Kahn Gillmor"));
@@ -850,4 +1688,22 @@ mod tests {
         assert!(!looks_like_dateline("By Erin Cunningham"));
         assert!(!looks_like_dateline("Washington Post Staff"));
     }
+
+    #[test]
+    fn test_truncate_chars_keeps_clusters() {
+        let flags = "\u{1f1eb}\u{1f1f7}\u{1f1e9}\u{1f1ea}";
+        assert_eq!(truncate_chars(flags, 3), "\u{1f1eb}\u{1f1f7}");
+        assert_eq!(truncate_chars("thumbs \u{1f44d}\u{1f3fd}", 8), "thumbs ");
+        assert_eq!(truncate_chars("cafe\u{301}", 4), "caf");
+        assert_eq!(truncate_chars("plain text", 5), "plain");
+    }
+
+    #[test]
+    fn test_person_name_key() {
+        assert_eq!(person_name_key("Jane Q. Doe"), "jane doe");
+        assert_eq!(person_name_key("By Jane Doe"), "jane doe");
+        assert_eq!(person_name_key("J. R. R. Tolkien"), "j tolkien");
+        assert_ne!(person_name_key("Jane Doe"), person_name_key("John Doe"));
+        assert_ne!(person_name_key("J. Doe"), person_name_key("John Doe"));
+    }
 }
@@ -3,26 +3,10 @@
 //! This test harness loads test cases from readability/test/test-pages/
 //! and compares our output with Mozilla's expected results.
 
-use readabilityrs::{Readability, ReadabilityOptions};
-use serde::{Deserialize, Serialize};
+use readabilityrs::{ExpectedMetadata, Readability, ReadabilityOptions};
 use std::fs;
 use std::path::{Path, PathBuf};
 
-/// Expected metadata from Mozilla test cases
-#[derive(Debug, Deserialize, Serialize)]
-#[serde(rename_all = "camelCase")]
-struct ExpectedMetadata {
-    title: Option<String>,
-    byline: Option<String>,
-    dir: Option<String>,
-    lang: Option<String>,
-    excerpt: Option<String>,
-    site_name: Option<String>,
-    published_time: Option<String>,
-    #[serde(default)]
-    readerable: bool,
-}
-
 /// A single test case from Mozilla's test suite
 struct TestCase {
     name: String,
@@ -84,19 +68,6 @@ fn load_test_cases() -> Vec<TestCase> {
     test_cases
 }
 
-/// Compare two optional strings, allowing for minor differences
-fn strings_match(actual: &Option<String>, expected: &Option<String>) -> bool {
-    match (actual, expected) {
-        (None, None) => true,
-        (Some(a), Some(e)) => {
-            let a_normalized = a.split_whitespace().collect::<Vec<_>>().join(" ");
-            let e_normalized = e.split_whitespace().collect::<Vec<_>>().join(" ");
-            a_normalized == e_normalized
-        }
-        _ => false,
-    }
-}
-
 #[test]
 #[ignore]
 fn test_mozilla_suite_metadata() {
@@ -141,42 +112,11 @@ fn test_mozilla_suite_metadata() {
             continue;
         }
 
-        let mut metadata_matches = true;
-        let mut mismatches = Vec::new();
-
-        if let Some(ref article) = article {
-            if !strings_match(&article.title, &test_case.expected_metadata.title) {
-                metadata_matches = false;
-                mismatches.push(format!(
-                    "  - Title: expected {:?}, got {:?}",
-                    test_case.expected_metadata.title, article.title
-                ));
-            }
-
-            if !strings_match(&article.byline, &test_case.expected_metadata.byline) {
-                metadata_matches = false;
-                mismatches.push(format!(
-                    "  - Byline: expected {:?}, got {:?}",
-                    test_case.expected_metadata.byline, article.byline
-                ));
-            }
-
-            if !strings_match(&article.excerpt, &test_case.expected_metadata.excerpt) {
-                metadata_matches = false;
-                mismatches.push(format!(
-                    "  - Excerpt: expected {:?}, got {:?}",
-                    test_case.expected_metadata.excerpt, article.excerpt
-                ));
-            }
-
-            if !strings_match(&article.site_name, &test_case.expected_metadata.site_name) {
-                metadata_matches = false;
-                mismatches.push(format!(
-                    "  - Site Name: expected {:?}, got {:?}",
-                    test_case.expected_metadata.site_name, article.site_name
-                ));
-            }
-        }
+        let mismatches = article
+            .as_ref()
+            .map(|article| article.matches_expected(&test_case.expected_metadata))
+            .unwrap_or_default();
+        let metadata_matches = mismatches.is_empty();
 
         if metadata_matches {
             println!("✅ {}", test_case.name);
@@ -184,7 +124,10 @@ fn test_mozilla_suite_metadata() {
         } else {
             println!("❌ {}: Metadata mismatch", test_case.name);
             for mismatch in mismatches {
-                println!("{}", mismatch);
+                println!(
+                    "  - {}: expected {:?}, got {:?}",
+                    mismatch.field, mismatch.expected, mismatch.actual
+                );
             }
             failed += 1;
             failures.push(test_case.name.clone());